@@ -165,11 +165,13 @@ export const enum BuiltinPluginName {
   ContainerReferencePlugin = 'ContainerReferencePlugin',
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
+  SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
   SwcJsMinimizerRspackPlugin = 'SwcJsMinimizerRspackPlugin',
-  SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin'
+  SwcCssMinimizerRspackPlugin = 'SwcCssMinimizerRspackPlugin',
+  DuplicatePackageCheckerPlugin = 'DuplicatePackageCheckerPlugin'
 }
 
 export function cleanupGlobalTrace(): void
@@ -702,6 +704,8 @@ export interface RawCssModulesConfig {
 
 export interface RawCssPluginConfig {
   modules: RawCssModulesConfig
+  ignoreOrder: boolean
+  targets: Array<string>
 }
 
 export interface RawDecoratorOptions {
@@ -709,10 +713,25 @@ export interface RawDecoratorOptions {
   emitMetadata: boolean
 }
 
+export interface RawDefinePluginOptions {
+  definitions: Record<string, string>
+  /**
+   * Evaluates the definitions created by `DefinePlugin.runtimeValue`, called at the start of
+   * every compilation.
+   */
+  runtimeValues?: () => Record<string, string>
+}
+
 export interface RawDevServer {
   hot: boolean
 }
 
+export interface RawDuplicatePackageCheckerPluginOptions {
+  verbose?: boolean
+  emitError?: boolean
+  exclude?: (string | RegExp)[]
+}
+
 export interface RawEntryOptions {
   name?: string
   runtime?: string
@@ -730,10 +749,20 @@ export interface RawEntryPluginOptions {
   options: RawEntryOptions
 }
 
+export interface RawEnvironment {
+  arrowFunction: boolean
+  const: boolean
+  destructuring: boolean
+  optionalChaining: boolean
+  dynamicImport: boolean
+  module: boolean
+}
+
 export interface RawExperiments {
   lazyCompilation: boolean
   incrementalRebuild: RawIncrementalRebuild
   asyncWebAssembly: boolean
+  syncWebAssembly: boolean
   newSplitChunks: boolean
   topLevelAwait: boolean
   css: boolean
@@ -794,6 +823,13 @@ export interface RawGeneratorOptions {
   assetResource?: RawAssetResourceGeneratorOptions
 }
 
+/** The options of the `hashed` module and chunk ids. */
+export interface RawHashedIdsOptions {
+  hashFunction: string
+  hashDigest: string
+  hashDigestLength: number
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
@@ -947,6 +983,7 @@ export interface RawNodeOption {
 export interface RawOptimizationOptions {
   moduleIds: string
   chunkIds: string
+  hashedIds: RawHashedIdsOptions
   removeAvailableModules: boolean
   removeEmptyChunks: boolean
   sideEffects: string
@@ -974,6 +1011,8 @@ export interface RawOptions {
   experiments: RawExperiments
   node?: RawNodeOption
   profile: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
   builtins: RawBuiltins
 }
 
@@ -1015,6 +1054,7 @@ export interface RawOutputOptions {
   workerWasmLoading: string
   workerPublicPath: string
   scriptType: "module" | "text/javascript" | "false"
+  environment: RawEnvironment
 }
 
 export interface RawParserOptions {
@@ -1102,6 +1142,14 @@ export interface RawResolveOptions {
   fullySpecified?: boolean
   exportsFields?: Array<string>
   extensionAlias?: Record<string, Array<string>>
+  roots?: Array<string>
+  restrictions?: Array<RawResolveRestriction>
+}
+
+export interface RawResolveRestriction {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: RawRegexMatcher
 }
 
 export interface RawResolveTsconfigOptions {
@@ -1134,6 +1182,14 @@ export interface RawRuleSetLogicalConditions {
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths: Array<RawSnapshotPathMatcher>
+  managedPaths: Array<RawSnapshotPathMatcher>
+}
+
+export interface RawSnapshotPathMatcher {
+  type: "string" | "regexp"
+  stringMatcher?: string
+  regexpMatcher?: RawRegexMatcher
 }
 
 export interface RawSnapshotStrategy {
@@ -1141,6 +1197,25 @@ export interface RawSnapshotStrategy {
   timestamp: boolean
 }
 
+export interface RawSourceMapDevToolPluginOptions {
+  /** The source map is inlined as a data url when `filename` is not set */
+  filename?: string
+  append?: boolean
+  namespace?: string
+  columns?: boolean
+  noSources?: boolean
+  publicPath?: string
+  ignoreList?: string | RegExp | (string | RegExp)[]
+  debugIds?: boolean
+  moduleFilenameTemplate?: string
+  fallbackModuleFilenameTemplate?: string
+  sourceRoot?: string
+  fileContext?: string
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+}
+
 export interface RawSplitChunksOptions {
   fallbackCacheGroup?: RawFallbackCacheGroupOptions
   name?: string | false | Function
@@ -1171,6 +1246,14 @@ export interface RawStyleConfig {
   bool?: boolean
 }
 
+export interface RawSwcCssMinimizerRspackPluginOptions {
+  extractComments?: string
+  test?: string | RegExp | (string | RegExp)[]
+  include?: string | RegExp | (string | RegExp)[]
+  exclude?: string | RegExp | (string | RegExp)[]
+  targets?: Array<string>
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
   extractComments?: string
   compress: boolean | string
@@ -38,6 +38,11 @@ async function build() {
 			args.push("--target", process.env.RUST_TARGET);
 		}
 
+		// e.g. `FEATURES=plugin` to run SWC Wasm plugins in builtin:swc-loader
+		if (process.env.FEATURES) {
+			args.push("--features", process.env.FEATURES);
+		}
+
 		let cp = spawn("napi", args, {
 			stdio: "inherit",
 			shell: true
@@ -77,6 +77,8 @@ pub struct RawOptions {
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
   pub profile: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
   pub builtins: RawBuiltins,
 }
 
@@ -104,15 +106,26 @@ impl RawOptionsApply for RawOptions {
         emit_asset: self.experiments.incremental_rebuild.emit_asset,
       },
       async_web_assembly: self.experiments.async_web_assembly,
+      sync_web_assembly: self.experiments.sync_web_assembly,
       new_split_chunks: self.experiments.new_split_chunks,
       top_level_await: self.experiments.top_level_await,
       rspack_future: self.experiments.rspack_future.into(),
     };
+    if self.records_input_path.is_some() || self.records_output_path.is_some() {
+      // Notice the plugin need to be placed before the plugins assigning the ids
+      plugins.push(
+        rspack_ids::RecordIdsPlugin::new(
+          self.records_input_path.map(Into::into),
+          self.records_output_path.map(Into::into),
+        )
+        .boxed(),
+      );
+    }
     let optimization = IS_ENABLE_NEW_SPLIT_CHUNKS.set(&experiments.new_split_chunks, || {
       self.optimization.apply(plugins)
     })?;
     let stats = self.stats.into();
-    let snapshot = self.snapshot.into();
+    let snapshot = self.snapshot.try_into()?;
     let node = self.node.map(|n| n.into());
     let dev_server: DevServerOptions = self.dev_server.into();
 
@@ -137,6 +150,9 @@ impl RawOptionsApply for RawOptions {
     if experiments.async_web_assembly {
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     plugins.push(rspack_plugin_javascript::JsPlugin::new().boxed());
     plugins.push(rspack_plugin_javascript::InferAsyncModulesPlugin {}.boxed());
 
@@ -149,6 +165,16 @@ impl RawOptionsApply for RawOptions {
           columns: !devtool.cheap(),
           no_sources: devtool.no_sources(),
           public_path: None,
+          filename: None,
+          ignore_list: None,
+          debug_ids: false,
+          module_filename_template: None,
+          fallback_module_filename_template: None,
+          source_root: None,
+          file_context: None,
+          test: None,
+          include: None,
+          exclude: None,
         })
         .boxed(),
       );
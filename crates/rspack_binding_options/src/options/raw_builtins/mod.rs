@@ -1,9 +1,13 @@
 mod raw_banner;
 mod raw_copy;
+mod raw_define;
+mod raw_duplicate_package_checker;
 mod raw_html;
 mod raw_limit_chunk_count;
 mod raw_mf;
 mod raw_progress;
+mod raw_source_map_dev_tool;
+mod raw_swc_css_minimizer;
 mod raw_swc_js_minimizer;
 mod raw_to_be_deprecated;
 
@@ -12,11 +16,13 @@ use napi::{
   JsUnknown,
 };
 use napi_derive::napi;
-use rspack_core::{BoxPlugin, Define, DefinePlugin, PluginExt, Provide, ProvidePlugin};
+use rspack_core::{BoxPlugin, DefinePlugin, PluginExt, Provide, ProvidePlugin};
 use rspack_error::Result;
 use rspack_napi_shared::NapiResultExt;
 use rspack_plugin_banner::BannerPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
+use rspack_plugin_devtool::DevtoolPlugin;
+use rspack_plugin_duplicate_package_checker::DuplicatePackageCheckerPlugin;
 use rspack_plugin_entry::EntryPlugin;
 use rspack_plugin_externals::{
   electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
@@ -46,8 +52,12 @@ use self::raw_mf::{
 };
 pub use self::{
   raw_banner::RawBannerPluginOptions, raw_copy::RawCopyRspackPluginOptions,
+  raw_define::RawDefinePluginOptions,
+  raw_duplicate_package_checker::RawDuplicatePackageCheckerPluginOptions,
   raw_html::RawHtmlRspackPluginOptions, raw_limit_chunk_count::RawLimitChunkCountPluginOptions,
   raw_mf::RawContainerPluginOptions, raw_progress::RawProgressPluginOptions,
+  raw_source_map_dev_tool::RawSourceMapDevToolPluginOptions,
+  raw_swc_css_minimizer::RawSwcCssMinimizerRspackPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
 };
 use crate::{
@@ -85,6 +95,7 @@ pub enum BuiltinPluginName {
   ContainerReferencePlugin,
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
+  SourceMapDevToolPlugin,
 
   // rspack specific plugins
   HttpExternalsRspackPlugin,
@@ -92,6 +103,7 @@ pub enum BuiltinPluginName {
   HtmlRspackPlugin,
   SwcJsMinimizerRspackPlugin,
   SwcCssMinimizerRspackPlugin,
+  DuplicatePackageCheckerPlugin,
 }
 
 #[napi(object)]
@@ -111,7 +123,8 @@ impl RawOptionsApply for BuiltinPlugin {
     match self.name {
       // webpack also have these plugins
       BuiltinPluginName::DefinePlugin => {
-        let plugin = DefinePlugin::new(downcast_into::<Define>(self.options)?).boxed();
+        let plugin =
+          DefinePlugin::from(downcast_into::<RawDefinePluginOptions>(self.options)?).boxed();
         plugins.push(plugin);
       }
       BuiltinPluginName::ProvidePlugin => {
@@ -163,9 +176,7 @@ impl RawOptionsApply for BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
-        ));
+        enable_wasm_loading_plugin(wasm_loading_type.as_str().into(), plugins);
       }
       BuiltinPluginName::CommonJsChunkFormatPlugin => {
         plugins.push(CommonJsChunkFormatPlugin.boxed());
@@ -236,6 +247,13 @@ impl RawOptionsApply for BuiltinPlugin {
         )
         .boxed(),
       ),
+      BuiltinPluginName::SourceMapDevToolPlugin => {
+        let plugin = DevtoolPlugin::new(
+          downcast_into::<RawSourceMapDevToolPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -251,7 +269,11 @@ impl RawOptionsApply for BuiltinPlugin {
         plugins.push(plugin);
       }
       BuiltinPluginName::SwcCssMinimizerRspackPlugin => {
-        plugins.push(SwcCssMinimizerRspackPlugin {}.boxed())
+        let plugin = SwcCssMinimizerRspackPlugin::new(
+          downcast_into::<RawSwcCssMinimizerRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
       }
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
@@ -267,6 +289,13 @@ impl RawOptionsApply for BuiltinPlugin {
             .boxed();
         plugins.push(plugin);
       }
+      BuiltinPluginName::DuplicatePackageCheckerPlugin => {
+        let plugin = DuplicatePackageCheckerPlugin::new(
+          downcast_into::<RawDuplicatePackageCheckerPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
     }
     Ok(())
   }
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use derivative::Derivative;
+use napi::{Env, JsFunction};
+use napi_derive::napi;
+use rspack_core::{Define, DefinePlugin};
+use rspack_napi_shared::{
+  get_napi_env,
+  threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  NapiResultExt,
+};
+
+#[derive(Derivative)]
+#[derivative(Debug)]
+#[napi(object)]
+pub struct RawDefinePluginOptions {
+  pub definitions: Define,
+  /// Evaluates the definitions created by `DefinePlugin.runtimeValue`, called at the start of
+  /// every compilation.
+  #[derivative(Debug = "ignore")]
+  #[napi(ts_type = "() => Record<string, string>")]
+  pub runtime_values: Option<JsFunction>,
+}
+
+impl From<RawDefinePluginOptions> for DefinePlugin {
+  fn from(value: RawDefinePluginOptions) -> Self {
+    let Some(runtime_values) = value.runtime_values else {
+      return DefinePlugin::new(value.definitions);
+    };
+    let func: napi::Result<ThreadsafeFunction<(), Define>> = try {
+      let env = get_napi_env();
+      rspack_binding_macros::js_fn_into_threadsafe_fn!(runtime_values, &Env::from(env))
+    };
+    let func = Arc::new(func.expect("convert to threadsafe function failed"));
+    let definitions = value.definitions;
+    DefinePlugin::from_fn(move || {
+      let func = func.clone();
+      let mut definitions = definitions.clone();
+      Box::pin(async move {
+        let runtime_values = func
+          .call((), ThreadsafeFunctionCallMode::NonBlocking)
+          .into_rspack_result()?
+          .await
+          .unwrap_or_else(|err| panic!("Failed to call runtime values function: {err}"))?;
+        definitions.extend(runtime_values);
+        Ok(definitions)
+      })
+    })
+  }
+}
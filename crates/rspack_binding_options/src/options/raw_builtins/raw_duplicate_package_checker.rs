@@ -0,0 +1,42 @@
+use napi::Either;
+use napi_derive::napi;
+use rspack_napi_shared::{JsRegExp, JsRegExpExt};
+use rspack_plugin_duplicate_package_checker::{
+  DuplicatePackageCheckerPluginOptions, DuplicatePackageExcludeRule,
+};
+
+type RawDuplicatePackageExcludeRule = Either<String, JsRegExp>;
+struct RawDuplicatePackageExcludeRuleWrapper(RawDuplicatePackageExcludeRule);
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDuplicatePackageCheckerPluginOptions {
+  pub verbose: Option<bool>,
+  pub emit_error: Option<bool>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub exclude: Option<Vec<RawDuplicatePackageExcludeRule>>,
+}
+
+impl From<RawDuplicatePackageExcludeRuleWrapper> for DuplicatePackageExcludeRule {
+  fn from(x: RawDuplicatePackageExcludeRuleWrapper) -> Self {
+    match x.0 {
+      Either::A(v) => Self::String(v),
+      Either::B(v) => Self::Regexp(v.to_rspack_regex()),
+    }
+  }
+}
+
+impl From<RawDuplicatePackageCheckerPluginOptions> for DuplicatePackageCheckerPluginOptions {
+  fn from(value: RawDuplicatePackageCheckerPluginOptions) -> Self {
+    Self {
+      verbose: value.verbose.unwrap_or(false),
+      emit_error: value.emit_error.unwrap_or(false),
+      exclude: value
+        .exclude
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| RawDuplicatePackageExcludeRuleWrapper(v).into())
+        .collect(),
+    }
+  }
+}
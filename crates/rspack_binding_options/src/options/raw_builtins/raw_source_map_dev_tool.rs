@@ -0,0 +1,84 @@
+use napi::{bindgen_prelude::Either3, Either};
+use napi_derive::napi;
+use rspack_napi_shared::{JsRegExp, JsRegExpExt};
+use rspack_plugin_devtool::{DevtoolPluginOptions, DevtoolRule, DevtoolRules};
+
+type RawDevtoolRule = Either<String, JsRegExp>;
+type RawDevtoolRules = Either3<String, JsRegExp, Vec<RawDevtoolRule>>;
+struct RawDevtoolRuleWrapper(RawDevtoolRule);
+struct RawDevtoolRulesWrapper(RawDevtoolRules);
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSourceMapDevToolPluginOptions {
+  /// The source map is inlined as a data url when `filename` is not set
+  pub filename: Option<String>,
+  pub append: Option<bool>,
+  pub namespace: Option<String>,
+  pub columns: Option<bool>,
+  pub no_sources: Option<bool>,
+  pub public_path: Option<String>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub ignore_list: Option<RawDevtoolRules>,
+  pub debug_ids: Option<bool>,
+  pub module_filename_template: Option<String>,
+  pub fallback_module_filename_template: Option<String>,
+  pub source_root: Option<String>,
+  pub file_context: Option<String>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawDevtoolRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawDevtoolRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawDevtoolRules>,
+}
+
+impl From<RawSourceMapDevToolPluginOptions> for DevtoolPluginOptions {
+  fn from(value: RawSourceMapDevToolPluginOptions) -> Self {
+    fn into_condition(c: Option<RawDevtoolRules>) -> Option<DevtoolRules> {
+      c.map(|v| RawDevtoolRulesWrapper(v).into())
+    }
+
+    Self {
+      inline: value.filename.is_none(),
+      append: value.append.unwrap_or(true),
+      namespace: value.namespace.unwrap_or_default(),
+      columns: value.columns.unwrap_or(true),
+      no_sources: value.no_sources.unwrap_or(false),
+      public_path: value.public_path,
+      filename: value.filename.map(Into::into),
+      ignore_list: into_condition(value.ignore_list),
+      debug_ids: value.debug_ids.unwrap_or(false),
+      module_filename_template: value.module_filename_template,
+      fallback_module_filename_template: value.fallback_module_filename_template,
+      source_root: value.source_root,
+      file_context: value.file_context,
+      test: into_condition(value.test),
+      include: into_condition(value.include),
+      exclude: into_condition(value.exclude),
+    }
+  }
+}
+
+impl From<RawDevtoolRuleWrapper> for DevtoolRule {
+  fn from(x: RawDevtoolRuleWrapper) -> Self {
+    match x.0 {
+      Either::A(v) => Self::String(v),
+      Either::B(v) => Self::Regexp(v.to_rspack_regex()),
+    }
+  }
+}
+
+impl From<RawDevtoolRulesWrapper> for DevtoolRules {
+  fn from(value: RawDevtoolRulesWrapper) -> Self {
+    match value.0 {
+      Either3::A(v) => Self::String(v),
+      Either3::B(v) => Self::Regexp(v.to_rspack_regex()),
+      Either3::C(v) => Self::Array(
+        v.into_iter()
+          .map(|v| RawDevtoolRuleWrapper(v).into())
+          .collect(),
+      ),
+    }
+  }
+}
@@ -0,0 +1,63 @@
+use napi::{bindgen_prelude::Either3, Either};
+use napi_derive::napi;
+use rspack_napi_shared::{JsRegExp, JsRegExpExt};
+use rspack_plugin_swc_css_minimizer::{
+  SwcCssMinimizerRspackPluginOptions, SwcCssMinimizerRule, SwcCssMinimizerRules,
+};
+
+type RawSwcCssMinimizerRule = Either<String, JsRegExp>;
+type RawSwcCssMinimizerRules = Either3<String, JsRegExp, Vec<RawSwcCssMinimizerRule>>;
+struct RawSwcCssMinimizerRuleWrapper(RawSwcCssMinimizerRule);
+struct RawSwcCssMinimizerRulesWrapper(RawSwcCssMinimizerRules);
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSwcCssMinimizerRspackPluginOptions {
+  pub extract_comments: Option<String>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub test: Option<RawSwcCssMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub include: Option<RawSwcCssMinimizerRules>,
+  #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
+  pub exclude: Option<RawSwcCssMinimizerRules>,
+  pub targets: Option<Vec<String>>,
+}
+
+impl From<RawSwcCssMinimizerRspackPluginOptions> for SwcCssMinimizerRspackPluginOptions {
+  fn from(value: RawSwcCssMinimizerRspackPluginOptions) -> Self {
+    fn into_condition(c: Option<RawSwcCssMinimizerRules>) -> Option<SwcCssMinimizerRules> {
+      c.map(|test| RawSwcCssMinimizerRulesWrapper(test).into())
+    }
+
+    Self {
+      extract_comments: value.extract_comments,
+      test: into_condition(value.test),
+      include: into_condition(value.include),
+      exclude: into_condition(value.exclude),
+      targets: value.targets,
+    }
+  }
+}
+
+impl From<RawSwcCssMinimizerRuleWrapper> for SwcCssMinimizerRule {
+  fn from(x: RawSwcCssMinimizerRuleWrapper) -> Self {
+    match x.0 {
+      Either::A(v) => Self::String(v),
+      Either::B(v) => Self::Regexp(v.to_rspack_regex()),
+    }
+  }
+}
+
+impl From<RawSwcCssMinimizerRulesWrapper> for SwcCssMinimizerRules {
+  fn from(value: RawSwcCssMinimizerRulesWrapper) -> Self {
+    match value.0 {
+      Either3::A(v) => Self::String(v),
+      Either3::B(v) => Self::Regexp(v.to_rspack_regex()),
+      Either3::C(v) => Self::Array(
+        v.into_iter()
+          .map(|v| RawSwcCssMinimizerRuleWrapper(v).into())
+          .collect(),
+      ),
+    }
+  }
+}
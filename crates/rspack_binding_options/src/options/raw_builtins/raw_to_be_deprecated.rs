@@ -195,6 +195,8 @@ impl From<RawRelayConfig> for RelayOptions {
 #[napi(object)]
 pub struct RawCssPluginConfig {
   pub modules: RawCssModulesConfig,
+  pub ignore_order: bool,
+  pub targets: Vec<String>,
 }
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
@@ -245,6 +247,8 @@ impl RawOptionsApply for RawBuiltins {
     if let Some(css) = self.css {
       let options = CssConfig {
         modules: css.modules.try_into()?,
+        ignore_order: css.ignore_order,
+        targets: css.targets,
       };
       plugins.push(CssPlugin::new(options).boxed());
     }
@@ -26,6 +26,7 @@ pub struct RawExperiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: RawIncrementalRebuild,
   pub async_web_assembly: bool,
+  pub sync_web_assembly: bool,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
   pub css: bool,
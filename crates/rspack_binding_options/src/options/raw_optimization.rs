@@ -5,8 +5,10 @@ use rspack_core::{
 };
 use rspack_error::internal_error;
 use rspack_ids::{
-  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
-  NamedModuleIdsPlugin,
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedChunkIdsPlugin,
+  HashedIdsPluginOptions, HashedModuleIdsPlugin, NamedChunkIdsPlugin, NamedModuleIdsPlugin,
+  NaturalChunkIdsPlugin, NaturalModuleIdsPlugin, OccurrenceChunkIdsPlugin,
+  OccurrenceModuleIdsPlugin,
 };
 use serde::Deserialize;
 
@@ -20,6 +22,7 @@ scoped_tls!(pub(crate) static IS_ENABLE_NEW_SPLIT_CHUNKS: bool);
 pub struct RawOptimizationOptions {
   pub module_ids: String,
   pub chunk_ids: String,
+  pub hashed_ids: RawHashedIdsOptions,
   pub remove_available_modules: bool,
   pub remove_empty_chunks: bool,
   pub side_effects: String,
@@ -30,6 +33,27 @@ pub struct RawOptimizationOptions {
   pub mangle_exports: String,
 }
 
+/// The options of the `hashed` module and chunk ids.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawHashedIdsOptions {
+  pub hash_function: String,
+  pub hash_digest: String,
+  pub hash_digest_length: u32,
+}
+
+impl From<RawHashedIdsOptions> for HashedIdsPluginOptions {
+  fn from(value: RawHashedIdsOptions) -> Self {
+    Self {
+      context: None,
+      hash_function: value.hash_function.as_str().into(),
+      hash_digest: value.hash_digest.as_str().into(),
+      hash_digest_length: value.hash_digest_length as usize,
+    }
+  }
+}
+
 impl RawOptionsApply for RawOptimizationOptions {
   type Options = Optimization;
 
@@ -37,22 +61,29 @@ impl RawOptionsApply for RawOptimizationOptions {
     self,
     plugins: &mut Vec<Box<dyn rspack_core::Plugin>>,
   ) -> Result<Self::Options, rspack_error::Error> {
+    let hashed_ids = HashedIdsPluginOptions::from(self.hashed_ids);
     let chunk_ids_plugin = match self.chunk_ids.as_ref() {
+      "natural" => NaturalChunkIdsPlugin.boxed(),
       "named" => NamedChunkIdsPlugin::new(None, None).boxed(),
       "deterministic" => DeterministicChunkIdsPlugin::default().boxed(),
+      "size" => OccurrenceChunkIdsPlugin::new(true).boxed(),
+      "hashed" => HashedChunkIdsPlugin::new(hashed_ids.clone()).boxed(),
       _ => {
         return Err(internal_error!(
-          "'chunk_ids' should be 'named' or 'deterministic'."
+          "'chunk_ids' should be 'natural', 'named', 'deterministic', 'size' or 'hashed'."
         ))
       }
     };
     plugins.push(chunk_ids_plugin);
     let module_ids_plugin = match self.module_ids.as_ref() {
+      "natural" => NaturalModuleIdsPlugin.boxed(),
       "named" => NamedModuleIdsPlugin::default().boxed(),
       "deterministic" => DeterministicModuleIdsPlugin::default().boxed(),
+      "size" => OccurrenceModuleIdsPlugin::new(true).boxed(),
+      "hashed" => HashedModuleIdsPlugin::new(hashed_ids).boxed(),
       _ => {
         return Err(internal_error!(
-          "'module_ids' should be 'named' or 'deterministic'."
+          "'module_ids' should be 'natural', 'named', 'deterministic', 'size' or 'hashed'."
         ))
       }
     };
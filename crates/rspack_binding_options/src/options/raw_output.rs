@@ -3,7 +3,7 @@ use rspack_core::{
   BoxPlugin, CrossOriginLoading, LibraryCustomUmdObject, LibraryName, LibraryNonUmdObject,
   LibraryOptions,
 };
-use rspack_core::{LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
+use rspack_core::{Environment, LibraryAuxiliaryComment, OutputOptions, TrustedTypes};
 use serde::Deserialize;
 
 use crate::RawOptionsApply;
@@ -23,6 +23,31 @@ impl From<RawTrustedTypes> for TrustedTypes {
   }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawEnvironment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub optional_chaining: bool,
+  pub dynamic_import: bool,
+  pub module: bool,
+}
+
+impl From<RawEnvironment> for Environment {
+  fn from(value: RawEnvironment) -> Self {
+    Self {
+      arrow_function: value.arrow_function,
+      r#const: value.r#const,
+      destructuring: value.destructuring,
+      optional_chaining: value.optional_chaining,
+      dynamic_import: value.dynamic_import,
+      module: value.module,
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -190,6 +215,7 @@ pub struct RawOutputOptions {
   pub worker_public_path: String,
   #[napi(ts_type = r#""module" | "text/javascript" | "false""#)]
   pub script_type: String,
+  pub environment: RawEnvironment,
 }
 
 impl RawOptionsApply for RawOutputOptions {
@@ -231,6 +257,7 @@ impl RawOptionsApply for RawOutputOptions {
       worker_wasm_loading: self.worker_wasm_loading.as_str().into(),
       worker_public_path: self.worker_public_path,
       script_type: self.script_type,
+      environment: self.environment.into(),
     })
   }
 }
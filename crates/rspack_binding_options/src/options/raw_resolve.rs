@@ -1,10 +1,14 @@
 use std::{collections::HashMap, path::PathBuf};
 
 use napi_derive::napi;
-use rspack_core::{Alias, AliasMap, ByDependency, Resolve, TsconfigOptions, TsconfigReferences};
+use rspack_core::{
+  Alias, AliasMap, ByDependency, Resolve, Restriction, TsconfigOptions, TsconfigReferences,
+};
 use rspack_error::internal_error;
 use serde::Deserialize;
 
+use crate::RawRegexMatcher;
+
 pub type AliasValue = serde_json::Value;
 
 type RawAliasOption = HashMap<String, Vec<AliasValue>>;
@@ -19,6 +23,45 @@ pub struct RawResolveTsconfigOptions {
   pub references: Option<Vec<String>>,
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawResolveRestriction {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_matcher: Option<String>,
+  pub regexp_matcher: Option<RawRegexMatcher>,
+}
+
+impl TryFrom<RawResolveRestriction> for Restriction {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawResolveRestriction) -> rspack_error::Result<Self> {
+    match value.r#type.as_str() {
+      "string" => Ok(Self::Path(value.string_matcher.ok_or_else(|| {
+        internal_error!(
+          "should have a string_matcher when RawResolveRestriction.type is \"string\""
+        )
+      })?)),
+      "regexp" => {
+        let matcher = value.regexp_matcher.ok_or_else(|| {
+          internal_error!(
+            "should have a regexp_matcher when RawResolveRestriction.type is \"regexp\""
+          )
+        })?;
+        Ok(Self::Regex(rspack_regex::RspackRegex::with_flags(
+          &matcher.source,
+          &matcher.flags,
+        )?))
+      }
+      _ => Err(internal_error!(
+        "Failed to resolve the restriction type {}",
+        value.r#type
+      )),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
@@ -44,6 +87,8 @@ pub struct RawResolveOptions {
   #[serde(serialize_with = "ordered_map")]
   #[napi(ts_type = "Record<string, Array<string>>")]
   pub extension_alias: Option<HashMap<String, Vec<String>>>,
+  pub roots: Option<Vec<String>>,
+  pub restrictions: Option<Vec<RawResolveRestriction>>,
 }
 
 fn normalize_alias(alias: Option<RawAliasOption>) -> rspack_error::Result<Option<Alias>> {
@@ -106,6 +151,15 @@ impl TryFrom<RawResolveOptions> for Resolve {
       .exports_fields
       .map(|v| v.into_iter().map(|s| vec![s]).collect());
     let extension_alias = value.extension_alias.map(|v| v.into_iter().collect());
+    let roots = value.roots;
+    let restrictions = value
+      .restrictions
+      .map(|v| {
+        v.into_iter()
+          .map(Restriction::try_from)
+          .collect::<Result<_, Self::Error>>()
+      })
+      .transpose()?;
     Ok(Resolve {
       modules,
       prefer_relative,
@@ -122,6 +176,8 @@ impl TryFrom<RawResolveOptions> for Resolve {
       fully_specified,
       exports_field,
       extension_alias,
+      roots,
+      restrictions,
     })
   }
 }
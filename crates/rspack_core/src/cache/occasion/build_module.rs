@@ -8,8 +8,8 @@ use rspack_identifier::Identifier;
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
   cache::storage,
-  BoxModule, BuildExtraDataType, BuildResult, DependencyTemplate, ModuleDependency,
-  NormalModuleSource,
+  definitions_hash, BoxModule, BuildExtraDataType, BuildResult, Define, DependencyTemplate,
+  ModuleDependency, NormalModuleSource,
 };
 
 #[derive(Debug, Clone)]
@@ -55,6 +55,7 @@ impl BuildModuleOccasion {
   pub async fn use_cache<'a, G, F>(
     &self,
     module: &'a mut BoxModule,
+    define: &Define,
     generator: G,
   ) -> Result<(Result<TWithDiagnosticArray<BuildResult>>, bool)>
   where
@@ -74,7 +75,13 @@ impl BuildModuleOccasion {
       // normal module
       // TODO cache all module type
       if let Some((snapshot, data, module_data, extra_data)) = storage.get(&id) {
-        let valid = if let Some(snapshot) = snapshot {
+        // the module also needs to be rebuilt if the definitions it's parsed with have been changed
+        let valid = if let Some(snapshot) = snapshot
+          && !data
+            .inner
+            .build_info
+            .has_outdated_values(define, &definitions_hash(define))
+        {
           self
             .snapshot_manager
             .check_snapshot_valid(&snapshot)
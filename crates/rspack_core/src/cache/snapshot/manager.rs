@@ -9,7 +9,7 @@ use rspack_error::{miette::IntoDiagnostic, Result};
 use rustc_hash::{FxHashMap as HashMap, FxHasher};
 
 use super::Snapshot;
-use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
+use crate::{calc_hash, is_zip_path, SnapshotOptions, SnapshotStrategy};
 
 /// SnapshotManager is a tools to create or check snapshot
 ///
@@ -23,6 +23,8 @@ pub struct SnapshotManager {
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache package version by `package.json`, `None` if it doesn't exist or has no version
+  package_version_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
@@ -33,28 +35,96 @@ impl SnapshotManager {
       options,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      package_version_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
 
+  fn is_immutable(&self, path: &Path) -> bool {
+    // Yarn PnP packages in zip archives are content addressed
+    if is_zip_path(path) {
+      return true;
+    }
+    let path = path.to_string_lossy();
+    self
+      .options
+      .immutable_paths
+      .iter()
+      .any(|matcher| matcher.try_match(&path))
+  }
+
+  fn is_managed(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    self
+      .options
+      .managed_paths
+      .iter()
+      .any(|matcher| matcher.try_match(&path))
+  }
+
+  async fn package_version(&self, package_json: &Path) -> Option<String> {
+    if let Some(version) = self.package_version_cache.get(package_json) {
+      return version.clone();
+    }
+    let version = tokio::fs::read(package_json)
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| json.get("version")?.as_str().map(ToString::to_string));
+    self
+      .package_version_cache
+      .insert(package_json.to_owned(), version.clone());
+    version
+  }
+
+  /// Finds the closest `package.json` with a version inside the managed path containing `path`.
+  async fn find_managed_package(&self, path: &Path) -> Option<(PathBuf, String)> {
+    for dir in path.ancestors().skip(1) {
+      if !self.is_managed(dir) {
+        break;
+      }
+      let package_json = dir.join("package.json");
+      if let Some(version) = self.package_version(&package_json).await {
+        return Some((package_json, version));
+      }
+    }
+    None
+  }
+
   pub async fn create_snapshot<F>(&self, paths: &[&Path], f: F) -> Result<Snapshot>
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
+    // TODO file_paths deduplication
     let strategy = f(&self.options);
+    let mut managed_package_versions = HashMap::default();
+    let mut unmanaged_paths = Vec::with_capacity(paths.len());
+    for &path in paths {
+      if self.is_immutable(path) {
+        continue;
+      }
+      if self.is_managed(path)
+        && let Some((package_json, version)) = self.find_managed_package(path).await
+      {
+        managed_package_versions.insert(package_json, version);
+        continue;
+      }
+      unmanaged_paths.push(path);
+    }
+    let paths = unmanaged_paths;
+
     let mut file_update_times = HashMap::default();
     file_update_times.reserve(paths.len());
     let mut file_hashes = HashMap::default();
     file_hashes.reserve(paths.len());
     if strategy.timestamp {
-      for &path in paths {
+      for &path in &paths {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
     }
     if strategy.hash {
       let hash_cache = &self.hash_cache;
-      for &path in paths {
+      for &path in &paths {
         let hash = match hash_cache.get(path) {
           Some(hash) => *hash,
           None => {
@@ -80,6 +150,7 @@ impl SnapshotManager {
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      managed_package_versions,
     })
   }
 
@@ -87,7 +158,7 @@ impl SnapshotManager {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_package_versions,
     } = snapshot;
     if !file_update_times.is_empty() {
       // check update time
@@ -138,12 +209,24 @@ impl SnapshotManager {
       }
     }
 
+    // check package version of managed paths
+    for (package_json, snapshot_version) in managed_package_versions {
+      if self.modified_files.contains(package_json) {
+        return Ok(false);
+      }
+
+      if self.package_version(package_json).await.as_ref() != Some(snapshot_version) {
+        return Ok(false);
+      }
+    }
+
     Ok(true)
   }
 
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.package_version_cache.clear();
     self.modified_files.clear();
   }
 
@@ -153,3 +236,65 @@ impl SnapshotManager {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::SnapshotPathMatcher;
+
+  #[tokio::test]
+  async fn managed_and_immutable_paths() {
+    let root = std::env::temp_dir().join(format!("rspack-snapshot-{}", std::process::id()));
+    let package_dir = root.join("node_modules/pkg");
+    let immutable_dir = root.join("store/pkg@1.0.0");
+    std::fs::create_dir_all(&package_dir).expect("should create dir");
+    std::fs::create_dir_all(&immutable_dir).expect("should create dir");
+    std::fs::write(package_dir.join("package.json"), r#"{"version":"1.0.0"}"#)
+      .expect("should write file");
+    std::fs::write(package_dir.join("index.js"), "").expect("should write file");
+    std::fs::write(immutable_dir.join("index.js"), "").expect("should write file");
+
+    let manager = SnapshotManager::new(SnapshotOptions {
+      module: SnapshotStrategy {
+        hash: true,
+        timestamp: true,
+      },
+      managed_paths: vec![SnapshotPathMatcher::String(
+        root.join("node_modules/").to_string_lossy().to_string(),
+      )],
+      immutable_paths: vec![SnapshotPathMatcher::String(
+        root.join("store/").to_string_lossy().to_string(),
+      )],
+      ..Default::default()
+    });
+    let managed_file = package_dir.join("index.js");
+    let immutable_file = immutable_dir.join("index.js");
+    let snapshot = manager
+      .create_snapshot(&[&managed_file, &immutable_file], |o| &o.module)
+      .await
+      .expect("should create snapshot");
+    assert!(snapshot.file_hashes.is_empty());
+    assert!(snapshot.file_update_times.is_empty());
+    assert_eq!(
+      snapshot
+        .managed_package_versions
+        .get(&package_dir.join("package.json"))
+        .map(String::as_str),
+      Some("1.0.0")
+    );
+    assert!(manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    std::fs::write(package_dir.join("package.json"), r#"{"version":"1.0.1"}"#)
+      .expect("should write file");
+    manager.clear();
+    assert!(!manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .expect("should check snapshot"));
+
+    std::fs::remove_dir_all(&root).expect("should remove dir");
+  }
+}
@@ -10,4 +10,6 @@ pub use manager::SnapshotManager;
 pub struct Snapshot {
   pub file_update_times: HashMap<PathBuf, SystemTime>,
   pub file_hashes: HashMap<PathBuf, u64>,
+  /// versions of the packages in managed paths, keyed by their `package.json`
+  pub managed_package_versions: HashMap<PathBuf, String>,
 }
@@ -16,6 +16,7 @@ use rayon::prelude::{
   IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator,
 };
 use rspack_error::{internal_error, Diagnostic, Result, Severity, TWithDiagnosticArray};
+use rspack_fs::InputFileSystem;
 use rspack_futures::FuturesResults;
 use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, IdentifierMap, IdentifierSet};
@@ -32,14 +33,14 @@ use super::{
 use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
-  is_source_equal,
+  definitions_hash, is_source_equal,
   tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs,
   AdditionalModuleRequirementsArgs, AsyncDependenciesBlock, BoxDependency, BoxModule, BuildQueue,
   BuildTask, BuildTaskResult, CacheCount, CacheOptions, Chunk, ChunkByUkey, ChunkContentHash,
   ChunkGraph, ChunkGroupByUkey, ChunkGroupUkey, ChunkHashArgs, ChunkKind, ChunkUkey, CleanQueue,
   CleanTask, CleanTaskResult, CodeGenerationResult, CodeGenerationResults, CompilationLogger,
-  CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, DependencyId,
+  CompilationLogging, CompilerOptions, ContentHashArgs, ContextDependency, Define, DependencyId,
   DependencyParents, DependencyType, Entry, EntryData, EntryOptions, Entrypoint, ErrorSpan,
   FactorizeQueue, FactorizeTask, FactorizeTaskResult, Filename, Logger, Module, ModuleFactory,
   ModuleGraph, ModuleIdentifier, ModuleProfile, PathData, ProcessAssetsArgs,
@@ -64,6 +65,9 @@ pub struct Compilation {
   pub hot_index: u32,
   pub records: Option<CompilationRecords>,
   pub options: Arc<CompilerOptions>,
+  /// The values defined for this compilation, `builtins.define` extended by the `DefinePlugin`s
+  /// in their `this_compilation` hooks, so the values can change between the rebuilds.
+  pub definitions: Arc<Define>,
   pub entries: Entry,
   pub global_entry: EntryData,
   pub module_graph: ModuleGraph,
@@ -80,9 +84,14 @@ pub struct Compilation {
   pub async_entrypoints: Vec<ChunkGroupUkey>,
   assets: CompilationAssets,
   pub emitted_assets: DashSet<String, BuildHasherDefault<FxHasher>>,
+  /// Files other than the assets written through the output file system after the assets are
+  /// emitted, e.g. the records, same as the ones webpack writes to `intermediateFileSystem`.
+  pub intermediate_files: Vec<(PathBuf, Vec<u8>)>,
   diagnostics: Vec<Diagnostic>,
   logging: CompilationLogging,
   pub plugin_driver: SharedPluginDriver,
+  /// The file system the sources are read from, plugins should read files through it.
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub resolver_factory: Arc<ResolverFactory>,
   pub loader_resolver_factory: Arc<ResolverFactory>,
   pub named_chunks: HashMap<String, ChunkUkey>,
@@ -101,6 +110,7 @@ pub struct Compilation {
   pub hash: Option<RspackHashDigest>,
   // lazy compilation visit module
   pub lazy_visit_modules: std::collections::HashSet<String>,
+  pub used_module_ids: HashSet<String>,
   pub used_chunk_ids: HashSet<String>,
   pub include_module_ids: IdentifierSet,
 
@@ -126,6 +136,7 @@ impl Compilation {
     Self {
       hot_index: 0,
       records,
+      definitions: Arc::new(options.builtins.define.clone()),
       options,
       module_graph,
       dependency_factories: Default::default(),
@@ -143,8 +154,10 @@ impl Compilation {
       async_entrypoints: Default::default(),
       assets: Default::default(),
       emitted_assets: Default::default(),
+      intermediate_files: Default::default(),
       diagnostics: Default::default(),
       logging: Default::default(),
+      input_filesystem: plugin_driver.input_filesystem.clone(),
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
@@ -162,6 +175,7 @@ impl Compilation {
       code_splitting_cache: Default::default(),
       hash: None,
       lazy_visit_modules: Default::default(),
+      used_module_ids: Default::default(),
       used_chunk_ids: Default::default(),
 
       file_dependencies: Default::default(),
@@ -406,12 +420,35 @@ impl Compilation {
       MakeParam::ForceBuildModules(std::mem::take(&mut self.make_failed_module));
     let make_failed_dependencies =
       MakeParam::ForceBuildDeps(std::mem::take(&mut self.make_failed_dependencies));
+    let outdated_value_modules = MakeParam::ForceBuildModules(self.outdated_value_modules());
 
     self
-      .update_module_graph(vec![param, make_failed_module, make_failed_dependencies])
+      .update_module_graph(vec![
+        param,
+        make_failed_module,
+        make_failed_dependencies,
+        outdated_value_modules,
+      ])
       .await
   }
 
+  /// The modules kept from the last compilation that used a defined value which has been changed
+  /// since, or all the ones parsed with the definitions once a key is added or removed.
+  fn outdated_value_modules(&self) -> HashSet<ModuleIdentifier> {
+    let definitions_hash = definitions_hash(&self.definitions);
+    self
+      .module_graph
+      .module_graph_modules()
+      .values()
+      .filter(|mgm| {
+        mgm.build_info.as_ref().is_some_and(|build_info| {
+          build_info.has_outdated_values(&self.definitions, &definitions_hash)
+        })
+      })
+      .map(|mgm| mgm.module_identifier)
+      .collect()
+  }
+
   pub async fn rebuild_module(
     &mut self,
     module_identifiers: HashSet<ModuleIdentifier>,
@@ -739,6 +776,7 @@ impl Compilation {
                   module,
                   resolver_factory: self.resolver_factory.clone(),
                   compiler_options: self.options.clone(),
+                  definitions: self.definitions.clone(),
                   plugin_driver: self.plugin_driver.clone(),
                   cache: self.cache.clone(),
                   current_profile,
@@ -19,7 +19,6 @@ where
     changed_files: std::collections::HashSet<String>,
     removed_files: std::collections::HashSet<String>,
   ) -> Result<()> {
-    assert!(!changed_files.is_empty() || !removed_files.is_empty());
     let old = self.compilation.get_stats();
     let old_hash = self.compilation.hash.clone();
 
@@ -58,6 +57,8 @@ where
       let mut modified_files = HashSet::default();
       modified_files.extend(changed_files.iter().map(PathBuf::from));
       modified_files.extend(removed_files.iter().map(PathBuf::from));
+      // virtual files are changed in memory, no watcher reports them
+      modified_files.extend(self.resolver_factory.take_modified_virtual_files());
 
       self.cache.end_idle();
       self
@@ -12,7 +12,7 @@ pub use hmr::{collect_changed_modules, CompilationRecords};
 pub use make::MakeParam;
 pub use queue::*;
 use rspack_error::Result;
-use rspack_fs::AsyncWritableFileSystem;
+use rspack_fs::{AsyncNativeFileSystem, AsyncWritableFileSystem, InputFileSystem};
 use rspack_futures::FuturesResults;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::FxHashMap as HashMap;
@@ -36,6 +36,7 @@ where
 {
   pub options: Arc<CompilerOptions>,
   pub output_filesystem: T,
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub compilation: Compilation,
   pub plugin_driver: SharedPluginDriver,
   pub resolver_factory: Arc<ResolverFactory>,
@@ -50,8 +51,24 @@ impl<T> Compiler<T>
 where
   T: AsyncWritableFileSystem + Send + Sync,
 {
-  #[instrument(skip_all)]
   pub fn new(options: CompilerOptions, plugins: Vec<BoxPlugin>, output_filesystem: T) -> Self {
+    Self::with_input_filesystem(
+      options,
+      plugins,
+      output_filesystem,
+      Arc::new(AsyncNativeFileSystem),
+    )
+  }
+
+  /// Create a compiler reading the sources from `input_filesystem` instead of the disk,
+  /// which is used by the resolvers, `read_resource`, loaders and plugins.
+  #[instrument(skip_all)]
+  pub fn with_input_filesystem(
+    options: CompilerOptions,
+    plugins: Vec<BoxPlugin>,
+    output_filesystem: T,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
     #[cfg(debug_assertions)]
     {
       if let Ok(mut debug_info) = crate::debug_info::DEBUG_INFO.lock() {
@@ -59,12 +76,22 @@ where
       }
     }
     let new_resolver = options.experiments.rspack_future.new_resolver;
-    let resolver_factory = Arc::new(ResolverFactory::new(new_resolver, options.resolve.clone()));
+    let resolver_factory = Arc::new(ResolverFactory::new(
+      new_resolver,
+      options.resolve.clone(),
+      input_filesystem.clone(),
+    ));
     let loader_resolver_factory = Arc::new(ResolverFactory::new(
       new_resolver,
       options.resolve_loader.clone(),
+      input_filesystem.clone(),
     ));
-    let (plugin_driver, options) = PluginDriver::new(options, plugins, resolver_factory.clone());
+    let (plugin_driver, options) = PluginDriver::new(
+      options,
+      plugins,
+      resolver_factory.clone(),
+      input_filesystem.clone(),
+    );
     let cache = Arc::new(Cache::new(options.clone()));
     let is_new_treeshaking = options.is_new_tree_shaking();
     assert!(!(options.is_new_tree_shaking() && options.builtins.tree_shaking.enable()), "Can't enable builtins.tree_shaking and `experiments.rspack_future.new_treeshaking` at the same time");
@@ -80,6 +107,7 @@ where
         cache.clone(),
       ),
       output_filesystem,
+      input_filesystem,
       plugin_driver,
       resolver_factory,
       loader_resolver_factory,
@@ -99,6 +127,8 @@ where
     // TODO: clear the outdated cache entries in resolver,
     // TODO: maybe it's better to use external entries.
     self.plugin_driver.resolver_factory.clear_cache();
+    // everything is built, later rebuilds only care about the virtual files changed after this
+    self.resolver_factory.take_modified_virtual_files();
 
     fast_set(
       &mut self.compilation,
@@ -356,7 +386,15 @@ where
       item?;
     }
 
-    self.plugin_driver.after_emit(&mut self.compilation).await
+    self.plugin_driver.after_emit(&mut self.compilation).await?;
+
+    for (file_path, content) in std::mem::take(&mut self.compilation.intermediate_files) {
+      if let Some(dir) = file_path.parent() {
+        self.output_filesystem.create_dir_all(dir).await?;
+      }
+      self.output_filesystem.write(&file_path, content).await?;
+    }
+    Ok(())
   }
 
   async fn emit_asset(
@@ -4,9 +4,9 @@ use rspack_error::{Diagnostic, Result};
 
 use crate::{
   cache::Cache, BoxDependency, BuildContext, BuildResult, Compilation, CompilerContext,
-  CompilerOptions, Context, Module, ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult,
-  ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve, ResolverFactory,
-  SharedPluginDriver, WorkerQueue,
+  CompilerOptions, Context, Define, Module, ModuleFactory, ModuleFactoryCreateData,
+  ModuleFactoryResult, ModuleGraph, ModuleGraphModule, ModuleIdentifier, ModuleProfile, Resolve,
+  ResolverFactory, SharedPluginDriver, WorkerQueue,
 };
 use crate::{DependencyId, ExportInfo, ExportsInfo, UsageState};
 
@@ -217,6 +217,7 @@ pub struct BuildTask {
   pub module: Box<dyn Module>,
   pub resolver_factory: Arc<ResolverFactory>,
   pub compiler_options: Arc<CompilerOptions>,
+  pub definitions: Arc<Define>,
   pub plugin_driver: SharedPluginDriver,
   pub cache: Arc<Cache>,
   pub current_profile: Option<Box<ModuleProfile>>,
@@ -240,13 +241,14 @@ impl WorkerTask for BuildTask {
 
     let mut module = self.module;
     let compiler_options = self.compiler_options;
+    let definitions = self.definitions;
     let resolver_factory = self.resolver_factory;
     let cache = self.cache;
     let plugin_driver = self.plugin_driver;
 
     let (build_result, is_cache_valid) = match cache
       .build_module_occasion
-      .use_cache(&mut module, |module| async {
+      .use_cache(&mut module, &definitions, |module| async {
         plugin_driver
           .build_module(module.as_mut())
           .await
@@ -257,11 +259,13 @@ impl WorkerTask for BuildTask {
             compiler_context: CompilerContext {
               options: compiler_options.clone(),
               resolver_factory: resolver_factory.clone(),
+              input_filesystem: plugin_driver.input_filesystem.clone(),
               module: Some(module.identifier()),
               module_context: module.as_normal_module().and_then(|m| m.get_context()),
             },
             plugin_driver: plugin_driver.clone(),
             compiler_options: &compiler_options,
+            definitions: &definitions,
           })
           .await;
 
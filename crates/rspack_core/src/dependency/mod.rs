@@ -159,8 +159,8 @@ pub mod needs_refactor {
     common::{EqIgnoreSpan, Spanned, SyntaxContext, DUMMY_SP},
     ecma::{
       ast::{
-        Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp, MetaPropExpr,
-        MetaPropKind, ModuleExportName, NewExpr,
+        CallExpr, Callee, Expr, ExprOrSpread, Id, Ident, ImportDecl, Lit, MemberExpr, MemberProp,
+        MetaPropExpr, MetaPropKind, ModuleExportName, NewExpr,
       },
       atoms::JsWord,
       visit::Visit,
@@ -219,6 +219,7 @@ pub mod needs_refactor {
   pub struct WorkerSyntaxList {
     variables: Vec<WorkerSyntax>,
     globals: Vec<WorkerSyntax>,
+    calls: Vec<WorkerCallSyntax>,
   }
 
   impl WorkerSyntaxList {
@@ -237,6 +238,13 @@ pub mod needs_refactor {
     pub fn match_new_worker(&self, new_expr: &NewExpr) -> bool {
       matches!(&*new_expr.callee, Expr::Ident(ident) if self.find_worker_syntax(ident).is_some())
     }
+
+    pub fn match_worker_call(&self, call_expr: &CallExpr) -> Option<&WorkerCallSyntax> {
+      let Callee::Expr(callee) = &call_expr.callee else {
+        return None;
+      };
+      self.calls.iter().find(|s| s.matches(callee))
+    }
   }
 
   impl Extend<WorkerSyntax> for WorkerSyntaxList {
@@ -274,13 +282,76 @@ pub mod needs_refactor {
     }
   }
 
+  /// A call creating a worker from its first argument, e.g. `navigator.serviceWorker.register()`.
+  /// A leading `*` matches any object, e.g. `*audioWorklet.addModule()` matches
+  /// `context.audioWorklet.addModule()`.
+  #[derive(Debug, PartialEq, Eq)]
+  pub struct WorkerCallSyntax {
+    members: Vec<JsWord>,
+    any_object: bool,
+  }
+
+  impl WorkerCallSyntax {
+    fn parse(syntax: &str) -> Option<Self> {
+      let syntax = syntax.strip_suffix("()")?;
+      let (syntax, any_object) = match syntax.strip_prefix('*') {
+        Some(syntax) => (syntax, true),
+        None => (syntax, false),
+      };
+      let members = syntax.split('.').map(JsWord::from).collect::<Vec<_>>();
+      if members.iter().any(|m| m.is_empty()) {
+        return None;
+      }
+      Some(Self {
+        members,
+        any_object,
+      })
+    }
+
+    /// Worklets are always loaded as modules and can't load chunks on demand.
+    pub fn is_worklet(&self) -> bool {
+      match self.members.as_slice() {
+        [.., worklet, add_module] => worklet.ends_with("Worklet") && &**add_module == "addModule",
+        _ => false,
+      }
+    }
+
+    fn matches(&self, callee: &Expr) -> bool {
+      let mut expr = callee;
+      for (i, member) in self.members.iter().enumerate().rev() {
+        if i == 0 && !self.any_object {
+          return matches!(expr, Expr::Ident(ident) if &ident.sym == member);
+        }
+        let Expr::Member(MemberExpr {
+          obj,
+          prop: MemberProp::Ident(prop),
+          ..
+        }) = expr
+        else {
+          return false;
+        };
+        if &prop.sym != member {
+          return false;
+        }
+        expr = obj;
+      }
+      true
+    }
+  }
+
   pub struct WorkerSyntaxScanner<'a> {
     result: WorkerSyntaxList,
     caps: Vec<(&'a str, &'a str)>,
   }
 
-  pub const DEFAULT_WORKER_SYNTAX: &[&str] =
-    &["Worker", "SharedWorker", "Worker from worker_threads"];
+  pub const DEFAULT_WORKER_SYNTAX: &[&str] = &[
+    "Worker",
+    "SharedWorker",
+    "navigator.serviceWorker.register()",
+    "*audioWorklet.addModule()",
+    "*paintWorklet.addModule()",
+    "Worker from worker_threads",
+  ];
 
   impl<'a> WorkerSyntaxScanner<'a> {
     pub fn new(syntax: &'a [&'a str]) -> Self {
@@ -292,6 +363,8 @@ pub mod needs_refactor {
           && let Some(source) = captures.get(3)
         {
           caps.push((ids.as_str(), source.as_str()));
+        } else if let Some(call) = WorkerCallSyntax::parse(s) {
+          result.calls.push(call);
         } else {
           result.push(WorkerSyntax::new(JsWord::from(*s), None))
         }
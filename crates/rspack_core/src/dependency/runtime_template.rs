@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use once_cell::sync::Lazy;
+use regex::Regex;
 use swc_core::ecma::atoms::JsWord;
 
 use crate::{
   get_import_var, property_access, to_comment, to_normal_comment, AsyncDependenciesBlockIdentifier,
-  Compilation, DependenciesBlock, DependencyId, ExportsType, FakeNamespaceObjectMode,
+  Compilation, DependenciesBlock, DependencyId, Environment, ExportsType, FakeNamespaceObjectMode,
   InitFragmentExt, InitFragmentKey, InitFragmentStage, ModuleGraph, ModuleIdentifier,
   NormalInitFragment, RuntimeGlobals, TemplateContext,
 };
@@ -392,12 +394,65 @@ fn weak_error(request: &str) -> String {
   format!("var e = new Error('Module is not available (weak dependency), request is {request}'); e.code = 'MODULE_NOT_FOUND'; throw e;")
 }
 
-pub fn returning_function(return_value: &str, args: &str) -> String {
-  format!("function({args}) {{ return {return_value}; }}")
+pub fn returning_function(environment: &Environment, return_value: &str, args: &str) -> String {
+  if environment.arrow_function {
+    format!("({args}) => ({return_value})")
+  } else {
+    format!("function({args}) {{ return {return_value}; }}")
+  }
+}
+
+pub fn basic_function(environment: &Environment, args: &str, body: &str) -> String {
+  if environment.arrow_function {
+    format!("({args}) => {{\n{body}\n}}")
+  } else {
+    format!("function({args}) {{\n{body}\n}}")
+  }
+}
+
+static ANONYMOUS_FUNCTION_REG: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\bfunction\s*\(([^()]*)\)\s*\{").expect("regexp init failed"));
+
+/// Render a runtime template written in ES5 for the environment, the anonymous functions of it
+/// become arrow functions if they are supported, so they shouldn't use `this` or `arguments`.
+pub fn render_runtime_template(environment: &Environment, template: &str) -> String {
+  if environment.arrow_function {
+    ANONYMOUS_FUNCTION_REG
+      .replace_all(template, "($1) => {")
+      .into_owned()
+  } else {
+    template.to_string()
+  }
 }
 
-pub fn basic_function(args: &str, body: &str) -> String {
-  format!("function({args}) {{\n{body}\n}}")
+pub fn render_const(environment: &Environment) -> &'static str {
+  if environment.r#const {
+    "const"
+  } else {
+    "var"
+  }
+}
+
+pub fn destructure_array(environment: &Environment, items: &[&str], value: &str) -> String {
+  if environment.destructuring {
+    format!("var [{}] = {value};", items.join(", "))
+  } else {
+    items
+      .iter()
+      .enumerate()
+      .map(|(i, item)| format!("var {item} = {value}[{i}];"))
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/// Access `property` of `object` which may be nullish, evaluating to `undefined` if it is.
+pub fn optional_access(environment: &Environment, object: &str, property: &str) -> String {
+  if environment.optional_chaining {
+    format!("{object}?.{property}")
+  } else {
+    format!("{object} && {object}.{property}")
+  }
 }
 
 pub fn sync_module_factory(
@@ -406,11 +461,13 @@ pub fn sync_module_factory(
   compilation: &Compilation,
   runtime_requirements: &mut RuntimeGlobals,
 ) -> String {
+  let environment = &compilation.options.output.environment;
   let factory = returning_function(
+    environment,
     &module_raw(compilation, runtime_requirements, dep, request, false),
     "",
   );
-  returning_function(&factory, "")
+  returning_function(environment, &factory, "")
 }
 
 pub fn async_module_factory(
@@ -425,15 +482,21 @@ pub fn async_module_factory(
     .expect("should have block");
   let dep = block.get_dependencies()[0];
   let ensure_chunk = block_promise(Some(block_id), runtime_requirements, compilation);
+  let environment = &compilation.options.output.environment;
   let factory = returning_function(
+    environment,
     &module_raw(compilation, runtime_requirements, &dep, request, false),
     "",
   );
   returning_function(
+    environment,
     &if ensure_chunk.starts_with("Promise.resolve(") {
       factory
     } else {
-      format!("{ensure_chunk}.then({})", returning_function(&factory, ""))
+      format!(
+        "{ensure_chunk}.then({})",
+        returning_function(environment, &factory, "")
+      )
     },
     "",
   )
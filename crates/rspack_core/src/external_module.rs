@@ -126,12 +126,18 @@ impl ExternalModule {
     &self,
     module_and_specifiers: &ExternalRequestValue,
     compilation: &Compilation,
-  ) -> String {
-    format!(
+  ) -> Result<String> {
+    let output = &compilation.options.output;
+    if !output.environment.dynamic_import && output.import_function_name == "import" {
+      return Err(internal_error!(
+        "The target environment doesn't support 'import()' so it's not possible to use external type 'import'"
+      ));
+    }
+    Ok(format!(
       "module.exports = {}({})",
-      compilation.options.output.import_function_name,
+      output.import_function_name,
       serde_json::to_string(module_and_specifiers.primary()).expect("invalid json to_string")
-    )
+    ))
   }
 
   fn get_source_for_script_external(
@@ -225,7 +231,9 @@ module.exports = new Promise(function(resolve, reject) {{
           to_identifier(id)
         )
       }
-      "import" if let Some(request) = request => self.get_source_for_import(request, compilation),
+      "import" if let Some(request) = request => {
+        self.get_source_for_import(request, compilation)?
+      }
       "var" | "promise" | "const" | "let" | "assign" if let Some(request) = request => {
         format!(
           "module.exports = {}",
@@ -234,6 +242,11 @@ module.exports = new Promise(function(resolve, reject) {{
       }
       "module" if let Some(request) = request => {
         if compilation.options.output.module {
+          if !compilation.options.output.environment.module {
+            return Err(internal_error!(
+              "The target environment doesn't support EcmaScriptModule syntax so it's not possible to use external type 'module'"
+            ));
+          }
           let id = compilation
             .module_graph
             .module_graph_module_by_identifier(&self.identifier())
@@ -261,7 +274,7 @@ module.exports = new Promise(function(resolve, reject) {{
             RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
           )
         } else {
-          self.get_source_for_import(request, compilation)
+          self.get_source_for_import(request, compilation)?
         }
       }
       "script" if let Some(request) = request => {
@@ -149,6 +149,9 @@ impl TryFrom<&str> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Json5,
+  Yaml,
+  Toml,
   Css,
   CssModule,
   CssAuto,
@@ -246,6 +249,9 @@ impl ModuleType {
       ModuleType::CssAuto => "css/auto",
 
       ModuleType::Json => "json",
+      ModuleType::Json5 => "json5",
+      ModuleType::Yaml => "yaml",
+      ModuleType::Toml => "toml",
 
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
@@ -294,6 +300,9 @@ impl TryFrom<&str> for ModuleType {
       "css/auto" => Ok(Self::CssAuto),
 
       "json" => Ok(Self::Json),
+      "json5" => Ok(Self::Json5),
+      "yaml" => Ok(Self::Yaml),
+      "toml" => Ok(Self::Toml),
 
       "webassembly/sync" => Ok(Self::WasmSync),
       "webassembly/async" => Ok(Self::WasmAsync),
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use rspack_fs::InputFileSystem;
 pub use rspack_loader_runner::{run_loaders, Content, Loader, LoaderContext};
 
 use crate::{CompilerOptions, Context, ModuleIdentifier, ResolverFactory};
@@ -8,6 +9,8 @@ use crate::{CompilerOptions, Context, ModuleIdentifier, ResolverFactory};
 pub struct CompilerContext {
   pub options: Arc<CompilerOptions>,
   pub resolver_factory: Arc<ResolverFactory>,
+  /// The file system the sources are read from, loaders should read files through it.
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   pub module: Option<ModuleIdentifier>,     // current module
   pub module_context: Option<Box<Context>>, // current module context
 }
@@ -1,4 +1,5 @@
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
+use rspack_fs::AsyncReadableFileSystem;
 use rspack_loader_runner::{Content, LoaderRunnerPlugin, ResourceData};
 
 use crate::SharedPluginDriver;
@@ -25,6 +26,19 @@ impl LoaderRunnerPlugin for LoaderRunnerPluginProcessResource {
       return Ok(result);
     }
 
-    Ok(None)
+    let resource_path = &resource_data.resource_path;
+    let content = match self
+      .plugin_driver
+      .resolver_factory
+      .read_pnp_file(resource_path)
+    {
+      Some(content) => content,
+      None => {
+        AsyncReadableFileSystem::read(&*self.plugin_driver.input_filesystem, resource_path).await
+      }
+    };
+    let content =
+      content.map_err(|e| internal_error!("{e}, failed to read {}", resource_path.display()))?;
+    Ok(Some(Content::from(content)))
   }
 }
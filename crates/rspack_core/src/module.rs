@@ -10,21 +10,24 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_sources::Source;
 use rspack_util::ext::{AsAny, DynEq, DynHash};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::JsWord;
 
 use crate::tree_shaking::visitor::OptimizeAnalyzeResult;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkUkey, CodeGenerationResult, Compilation,
-  CompilerContext, CompilerOptions, ConnectionState, Context, ContextModule, DependenciesBlock,
-  DependencyId, DependencyTemplate, ExternalModule, ModuleDependency, ModuleGraph, ModuleType,
-  NormalModule, RawModule, Resolve, RuntimeSpec, SharedPluginDriver, SourceType,
+  CompilerContext, CompilerOptions, ConnectionState, Context, ContextModule, Define,
+  DependenciesBlock, DependencyId, DependencyTemplate, ExternalModule, ModuleDependency,
+  ModuleGraph, ModuleType, NormalModule, RawModule, Resolve, RuntimeSpec, SharedPluginDriver,
+  SourceType, VALUE_DEP_MAIN,
 };
 
 pub struct BuildContext<'a> {
   pub compiler_context: CompilerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  /// The values defined for the compilation the module is built in.
+  pub definitions: &'a Define,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -49,6 +52,29 @@ pub struct BuildInfo {
   pub all_star_exports: Vec<DependencyId>,
   pub need_create_require: bool,
   pub json_data: Option<JsonValue>,
+  /// Exports of a synchronous WebAssembly module which can't be used from JS,
+  /// mapped to the reason, e.g. `"i64"` in the signature.
+  pub js_incompatible_exports: Option<HashMap<String, String>>,
+  /// Values exported by a CSS module through ICSS `:export` or `@value`, mapped to the
+  /// literal value, or to the name and the request of the module it's imported from.
+  pub icss_values: Option<HashMap<String, (String, Option<String>)>>,
+  /// Keys of the definitions used by the module, mapped to the code they were replaced with,
+  /// and [VALUE_DEP_MAIN] mapped to the [definitions_hash] of the definitions it's parsed with.
+  pub value_dependencies: HashMap<String, String>,
+}
+
+impl BuildInfo {
+  /// Whether a definition has been added or removed, or any of the defined values used by the
+  /// module has been changed since it was built, `definitions_hash` is the one of `define`.
+  pub fn has_outdated_values(&self, define: &Define, definitions_hash: &str) -> bool {
+    self.value_dependencies.iter().any(|(key, code)| {
+      if key == VALUE_DEP_MAIN {
+        code != definitions_hash
+      } else {
+        define.get(key) != Some(code)
+      }
+    })
+  }
 }
 
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
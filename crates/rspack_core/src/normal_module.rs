@@ -406,6 +406,7 @@ impl Module for NormalModule {
         module_user_request: &self.user_request,
         resource_data: &self.resource_data,
         compiler_options: build_context.compiler_options,
+        definitions: build_context.definitions,
         additional_data: loader_result.additional_data,
         code_generation_dependencies: &mut code_generation_dependencies,
         build_info: &mut build_info,
@@ -605,7 +605,7 @@ impl NormalModuleFactory {
         .map(|side_effects| !side_effects),
     };
 
-    let resolved_parser_and_generator = self
+    let mut resolved_parser_and_generator = self
       .plugin_driver
       .registered_parser_and_generator_builder
       .get(&resolved_module_type)
@@ -623,6 +623,10 @@ impl NormalModuleFactory {
 
         internal_error!(e)
       })?();
+    self.plugin_driver.normal_module_factory_parser(
+      &resolved_module_type,
+      resolved_parser_and_generator.as_mut(),
+    )?;
 
     let create_data = NormalModuleCreateData {
       dependency_type: data.dependency.dependency_type().clone(),
@@ -1,33 +1,77 @@
 use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
 
+use futures::future::BoxFuture;
 use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 pub use rspack_swc_visitors::{Define, Provide};
 use rspack_swc_visitors::{EmotionOptions, ImportOptions, ReactOptions, RelayOptions};
 
-use crate::{ApplyContext, CompilerOptions, Plugin, PluginContext};
+use crate::{
+  ApplyContext, CompilationParams, CompilerOptions, Plugin, PluginContext,
+  PluginThisCompilationHookOutput, ThisCompilationArgs,
+};
+
+/// The key of the value dependency on the keys of the definitions, every module parsed with the
+/// definitions has it, so it's rebuilt once a definition is added or removed, e.g. the ones
+/// checking `typeof FEATURE`. Same as `VALUE_DEP_MAIN` of webpack.
+pub const VALUE_DEP_MAIN: &str = "rspack/DefinePlugin_hash";
+
+/// The value of [VALUE_DEP_MAIN], a hash of the keys of the definitions.
+pub fn definitions_hash(define: &Define) -> String {
+  let mut keys = define.keys().collect::<Vec<_>>();
+  keys.sort_unstable();
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  keys.hash(&mut hasher);
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+/// Evaluates the values defined by a [DefinePlugin].
+pub type DefineValuesFn = Box<dyn Fn() -> BoxFuture<'static, Result<Define>> + Send + Sync>;
 
-#[derive(Debug)]
 pub struct DefinePlugin {
-  options: Define,
+  values: DefineValuesFn,
+}
+
+impl Debug for DefinePlugin {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("DefinePlugin").finish_non_exhaustive()
+  }
 }
 
 impl DefinePlugin {
   pub fn new(options: Define) -> Self {
-    Self { options }
+    Self::from_fn(move || {
+      let options = options.clone();
+      Box::pin(async move { Ok(options) })
+    })
+  }
+
+  /// The values are evaluated again at the start of every compilation, so they can be changed
+  /// between the rebuilds in watch mode, only the modules using the changed values are rebuilt.
+  pub fn from_fn(
+    values: impl Fn() -> BoxFuture<'static, Result<Define>> + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      values: Box::new(values),
+    }
   }
 }
 
+#[async_trait::async_trait]
 impl Plugin for DefinePlugin {
   fn name(&self) -> &'static str {
     "rspack.DefinePlugin"
   }
 
-  fn apply(
+  async fn this_compilation(
     &self,
-    _ctx: PluginContext<&mut ApplyContext>,
-    options: &mut CompilerOptions,
-  ) -> Result<()> {
-    options.builtins.define.extend(self.options.clone());
+    args: ThisCompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginThisCompilationHookOutput {
+    let values = (self.values)().await?;
+    Arc::make_mut(&mut args.this_compilation.definitions).extend(values);
     Ok(())
   }
 }
@@ -117,7 +161,6 @@ impl From<String> for TreeShaking {
 
 #[derive(Debug, Clone, Default)]
 pub struct Builtins {
-  // TODO: refactor to string-replacement based
   pub define: Define,
   // TODO: refactor to string-replacement based
   pub provide: Provide,
@@ -33,6 +33,7 @@ pub struct Experiments {
   pub lazy_compilation: bool,
   pub incremental_rebuild: IncrementalRebuild,
   pub async_web_assembly: bool,
+  pub sync_web_assembly: bool,
   pub new_split_chunks: bool,
   pub top_level_await: bool,
   pub rspack_future: RspackFuture,
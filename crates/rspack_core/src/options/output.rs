@@ -53,6 +53,7 @@ pub struct OutputOptions {
   pub worker_wasm_loading: WasmLoading,
   pub worker_public_path: String,
   pub script_type: String,
+  pub environment: Environment,
 }
 
 impl From<&OutputOptions> for RspackHash {
@@ -61,6 +62,25 @@ impl From<&OutputOptions> for RspackHash {
   }
 }
 
+/// The syntax the environment of the output supports, consulted by the runtime template.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Environment {
+  pub arrow_function: bool,
+  pub r#const: bool,
+  pub destructuring: bool,
+  pub optional_chaining: bool,
+  pub dynamic_import: bool,
+  pub module: bool,
+}
+
+impl Environment {
+  /// Whether none of the syntax newer than ES5 is supported, in which case the runtime modules
+  /// written in ES5 are emitted as they are.
+  pub fn is_es5(&self) -> bool {
+    !(self.arrow_function || self.r#const || self.destructuring || self.optional_chaining)
+  }
+}
+
 #[derive(Debug)]
 pub struct TrustedTypes {
   pub policy_name: Option<String>,
@@ -88,7 +108,8 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// A chunk loading type provided by a plugin, see `CustomChunkLoadingPlugin`.
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -99,7 +120,7 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      custom => Self::Custom(custom.to_string()),
     }
   }
 }
@@ -3,7 +3,8 @@ use hashlink::LinkedHashMap;
 use super::value_type::{GetValueType, ValueType};
 use super::{
   Alias, BrowserField, ConditionNames, ExportsField, ExtensionAlias, Extensions, Fallback,
-  FullySpecified, MainFields, MainFiles, Modules, PreferRelative, Symlink, TsconfigOptions,
+  FullySpecified, MainFields, MainFiles, Modules, PreferRelative, Restrictions, Roots, Symlink,
+  TsconfigOptions,
 };
 use super::{ByDependency, DependencyCategoryStr, Resolve};
 
@@ -32,6 +33,8 @@ fn is_empty(resolve: &Resolve) -> bool {
     && is_none!(exports_field)
     && is_none!(extension_alias)
     && is_none!(tsconfig)
+    && is_none!(roots)
+    && is_none!(restrictions)
     && is_none!(by_dependency)
 }
 
@@ -57,6 +60,8 @@ struct ResolveWithEntry {
   fully_specified: Entry<FullySpecified>,
   exports_field: Entry<ExportsField>,
   extension_alias: Entry<ExtensionAlias>,
+  roots: Entry<Roots>,
+  restrictions: Entry<Restrictions>,
 }
 
 fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
@@ -83,6 +88,8 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
     fully_specified: entry!(fully_specified),
     exports_field: entry!(exports_field),
     extension_alias: entry!(extension_alias),
+    roots: entry!(roots),
+    restrictions: entry!(restrictions),
   };
   let Some(by_dependency) = resolve.by_dependency else {
     return res;
@@ -125,6 +132,8 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
   update_by_value!(exports_field);
   update_by_value!(extension_alias);
   update_by_value!(tsconfig);
+  update_by_value!(roots);
+  update_by_value!(restrictions);
 
   res
 }
@@ -297,6 +306,13 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     extension_alias: merge!(extension_alias, ValueType::Other, |_| false, |a, b| {
       extend_extension_alias(a, b)
     }),
+    roots: merge!(
+      roots,
+      second.roots.base.get_value_type(),
+      need_merge_base,
+      |a, b| normalize_string_array(a, b)
+    ),
+    restrictions: merge!(restrictions, ValueType::Other, |_| false, |_, b| b),
   };
 
   let mut by_dependency: LinkedHashMap<DependencyCategoryStr, Resolve> = LinkedHashMap::new();
@@ -327,6 +343,8 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   setup_by_values!(fully_specified);
   setup_by_values!(exports_field);
   setup_by_values!(extension_alias);
+  setup_by_values!(roots);
+  setup_by_values!(restrictions);
 
   macro_rules! to_resolve {
     ($ident: ident) => {
@@ -354,6 +372,8 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   to_resolve!(fully_specified);
   to_resolve!(exports_field);
   to_resolve!(extension_alias);
+  to_resolve!(roots);
+  to_resolve!(restrictions);
 
   let by_dependency = if by_dependency.iter().all(|(_, by_value)| is_empty(by_value)) {
     None
@@ -377,6 +397,8 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     fully_specified: result_entry.fully_specified.base,
     exports_field: result_entry.exports_field.base,
     extension_alias: result_entry.extension_alias.base,
+    roots: result_entry.roots.base,
+    restrictions: result_entry.restrictions.base,
   }
 }
 
@@ -409,8 +431,10 @@ fn extend_extension_alias(a: &ExtensionAlias, b: ExtensionAlias) -> ExtensionAli
 
 #[cfg(test)]
 mod test {
+  use rspack_regex::RspackRegex;
+
   use super::*;
-  use crate::AliasMap;
+  use crate::{AliasMap, Restriction};
 
   fn string_list(a: &[&str]) -> Option<Vec<String>> {
     Some(a.iter().map(|s| s.to_string()).collect())
@@ -2187,4 +2211,29 @@ mod test {
       }
     )
   }
+
+  #[test]
+  fn test_merge_roots_and_restrictions() {
+    let first = Resolve {
+      roots: string_list(&["/a"]),
+      restrictions: Some(vec![Restriction::Path("/a".to_string())]),
+      ..Default::default()
+    };
+    let second = Resolve {
+      roots: string_list(&["/b", "..."]),
+      restrictions: Some(vec![Restriction::Regex(
+        RspackRegex::new(r"\.css$").expect("should be valid regex"),
+      )]),
+      ..Default::default()
+    };
+
+    let options = merge_resolve(first, second);
+    assert_eq!(options.roots.expect("should be Ok"), vec!["/b", "/a"]);
+    assert_eq!(
+      options.restrictions.expect("should be Ok"),
+      vec![Restriction::Regex(
+        RspackRegex::new(r"\.css$").expect("should be valid regex")
+      )]
+    );
+  }
 }
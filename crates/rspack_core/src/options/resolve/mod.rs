@@ -1,9 +1,13 @@
 mod clever_merge;
 mod value_type;
 
-use std::{borrow::Cow, path::PathBuf};
+use std::{
+  borrow::Cow,
+  path::{Path, PathBuf},
+};
 
 use hashlink::LinkedHashMap;
+use rspack_regex::RspackRegex;
 
 use crate::DependencyCategory;
 
@@ -22,6 +26,8 @@ pub(super) type FullySpecified = bool;
 pub(super) type ExportsField = Vec<Vec<String>>;
 pub(super) type ExtensionAlias = Vec<(String, Vec<String>)>;
 pub(super) type Modules = Vec<String>;
+pub(super) type Roots = Vec<String>;
+pub(super) type Restrictions = Vec<Restriction>;
 
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct Resolve {
@@ -66,9 +72,34 @@ pub struct Resolve {
   /// A list map ext to another.
   /// Default is `[]`
   pub extension_alias: Option<ExtensionAlias>,
+  /// A list of directories where server-relative requests (starting with `/`)
+  /// are resolved.
+  /// Default is `[]`
+  pub roots: Option<Roots>,
+  /// A list of restrictions the resolved path must satisfy.
+  /// Default is `[]`
+  pub restrictions: Option<Restrictions>,
   pub by_dependency: Option<ByDependency>,
 }
 
+/// A restriction of `resolve.restrictions`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Restriction {
+  /// The resolved path must be inside of this directory.
+  Path(String),
+  /// The resolved path must match this regex.
+  Regex(RspackRegex),
+}
+
+impl Restriction {
+  pub fn is_match(&self, path: &Path) -> bool {
+    match self {
+      Self::Path(dir) => path.starts_with(dir),
+      Self::Regex(regex) => regex.test(&path.to_string_lossy()),
+    }
+  }
+}
+
 /// Tsconfig Options
 ///
 /// Derived from [tsconfig-paths-webpack-plugin](https://github.com/dividab/tsconfig-paths-webpack-plugin#options)
@@ -1,9 +1,27 @@
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+#[derive(Debug, Clone)]
+pub enum SnapshotPathMatcher {
+  /// Matches paths starting with the string.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPathMatcher {
+  pub fn try_match(&self, path: &str) -> bool {
+    match self {
+      Self::String(s) => path.starts_with(s),
+      Self::Regexp(r) => r.test(path),
+    }
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +32,10 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// Paths that are managed by a package manager and contain a version or a hash in their paths,
+  /// files in them are never checked again.
+  pub immutable_paths: Vec<SnapshotPathMatcher>,
+  /// Paths that are managed by a package manager, files in them are only checked by the version
+  /// in the `package.json` of their package.
+  pub managed_paths: Vec<SnapshotPathMatcher>,
 }
@@ -1,7 +1,8 @@
-use std::{fmt::Debug, path::Path};
+use std::{fmt::Debug, path::Path, sync::Arc};
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
+use rspack_fs::MemoryFileSystem;
 use rspack_hash::RspackHashDigest;
 use rspack_loader_runner::{Content, ResourceData};
 use rspack_sources::BoxSource;
@@ -28,6 +29,7 @@ pub type PluginFactorizeHookOutput = Result<Option<ModuleFactoryResult>>;
 pub type PluginNormalModuleFactoryCreateModuleHookOutput = Result<Option<BoxModule>>;
 pub type PluginNormalModuleFactoryModuleHookOutput = Result<BoxModule>;
 pub type PluginNormalModuleFactoryResolveForSchemeOutput = Result<(ResourceData, bool)>;
+pub type PluginNormalModuleFactoryParserHookOutput = Result<()>;
 pub type PluginNormalModuleFactoryBeforeResolveOutput = Result<Option<bool>>;
 pub type PluginNormalModuleFactoryAfterResolveOutput = Result<Option<bool>>;
 pub type PluginContentHashHookOutput = Result<Option<(SourceType, RspackHashDigest)>>;
@@ -158,6 +160,17 @@ pub trait Plugin: Debug + Send + Sync {
     Ok((args, false))
   }
 
+  /// Called with the parser and generator created for a module, port of `NormalModuleFactory.hooks.parser`
+  /// in webpack, e.g. downcast it to the parser and generator of JavaScript to register parser plugins.
+  fn normal_module_factory_parser(
+    &self,
+    _ctx: PluginContext,
+    _module_type: &ModuleType,
+    _parser_and_generator: &mut dyn ParserAndGenerator,
+  ) -> PluginNormalModuleFactoryParserHookOutput {
+    Ok(())
+  }
+
   async fn content_hash(
     &self,
     _ctx: PluginContext,
@@ -581,9 +594,16 @@ pub type BoxedParserAndGeneratorBuilder =
 pub struct ApplyContext {
   pub(crate) registered_parser_and_generator_builder:
     DashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  pub(crate) registered_chunk_loading_types: DashSet<String>,
+  pub(crate) virtual_files: Arc<MemoryFileSystem>,
 }
 
 impl ApplyContext {
+  /// Files which only exist in memory, see [crate::ResolverFactory::virtual_files].
+  pub fn virtual_files(&self) -> &Arc<MemoryFileSystem> {
+    &self.virtual_files
+  }
+
   pub fn register_parser_and_generator_builder(
     &self,
     module_type: ModuleType,
@@ -593,4 +613,10 @@ impl ApplyContext {
       .registered_parser_and_generator_builder
       .insert(module_type, parser_and_generator_builder);
   }
+
+  /// Declare that the plugin provides the chunk loading type `name` which is not built in,
+  /// see [crate::ChunkLoadingType::Custom].
+  pub fn register_chunk_loading_type(&self, name: impl Into<String>) {
+    self.registered_chunk_loading_types.insert(name.into());
+  }
 }
@@ -4,8 +4,9 @@ use std::{
 };
 
 use rspack_error::{Diagnostic, Result};
+use rspack_fs::InputFileSystem;
 use rspack_loader_runner::ResourceData;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use tracing::instrument;
 
 use crate::{
@@ -15,14 +16,14 @@ use crate::{
   CompilationParams, CompilerOptions, Content, ContentHashArgs, DoneArgs, FactorizeArgs,
   JsChunkHashArgs, MakeParam, Module, ModuleIdentifier, ModuleType, NormalModule,
   NormalModuleAfterResolveArgs, NormalModuleBeforeResolveArgs, NormalModuleCreateData,
-  OptimizeChunksArgs, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  OptimizeChunksArgs, ParserAndGenerator, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
   PluginAdditionalModuleRequirementsOutput, PluginBuildEndHookOutput, PluginChunkHashHookOutput,
   PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput,
   PluginJsChunkHashHookOutput, PluginMakeHookOutput, PluginNormalModuleFactoryAfterResolveOutput,
   PluginNormalModuleFactoryBeforeResolveOutput, PluginNormalModuleFactoryCreateModuleHookOutput,
-  PluginNormalModuleFactoryModuleHookOutput, PluginProcessAssetsOutput,
-  PluginRenderChunkHookOutput, PluginRenderHookOutput, PluginRenderManifestHookOutput,
-  PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
+  PluginNormalModuleFactoryModuleHookOutput, PluginNormalModuleFactoryParserHookOutput,
+  PluginProcessAssetsOutput, PluginRenderChunkHookOutput, PluginRenderHookOutput,
+  PluginRenderManifestHookOutput, PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
   PluginRuntimeRequirementsInTreeOutput, PluginThisCompilationHookOutput, ProcessAssetsArgs,
   RenderArgs, RenderChunkArgs, RenderManifestArgs, RenderModuleContentArgs, RenderStartupArgs,
   Resolver, ResolverFactory, RuntimeRequirementsInTreeArgs, Stats, ThisCompilationArgs,
@@ -32,8 +33,12 @@ pub struct PluginDriver {
   pub(crate) options: Arc<CompilerOptions>,
   pub plugins: Vec<Box<dyn Plugin>>,
   pub resolver_factory: Arc<ResolverFactory>,
+  /// The file system the sources are read from, see [Compiler::with_input_filesystem](crate::Compiler::with_input_filesystem).
+  pub input_filesystem: Arc<dyn InputFileSystem>,
   // pub registered_parser: HashMap<ModuleType, BoxedParser>,
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
+  /// The chunk loading types provided by the plugins, see [ApplyContext::register_chunk_loading_type].
+  pub registered_chunk_loading_types: HashSet<String>,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
 }
@@ -43,8 +48,13 @@ impl std::fmt::Debug for PluginDriver {
     f.debug_struct("PluginDriver")
       .field("options", &self.options)
       .field("plugins", &self.plugins)
+      .field("input_filesystem", &self.input_filesystem)
       // field("registered_parser", &self.registered_parser)
       .field("registered_parser_and_generator_builder", &"{..}")
+      .field(
+        "registered_chunk_loading_types",
+        &self.registered_chunk_loading_types,
+      )
       .field("diagnostics", &self.diagnostics)
       .finish()
   }
@@ -55,11 +65,16 @@ impl PluginDriver {
     mut options: CompilerOptions,
     plugins: Vec<Box<dyn Plugin>>,
     resolver_factory: Arc<ResolverFactory>,
+    input_filesystem: Arc<dyn InputFileSystem>,
   ) -> (Arc<Self>, Arc<CompilerOptions>) {
+    let mut registered_chunk_loading_types = HashSet::default();
     let registered_parser_and_generator_builder = plugins
       .iter()
       .map(|plugin| {
-        let mut apply_context = ApplyContext::default();
+        let mut apply_context = ApplyContext {
+          virtual_files: resolver_factory.virtual_files().clone(),
+          ..Default::default()
+        };
         plugin
           .apply(
             PluginContext::with_context(&mut apply_context),
@@ -69,6 +84,7 @@ impl PluginDriver {
         apply_context
       })
       .flat_map(|apply_context| {
+        registered_chunk_loading_types.extend(apply_context.registered_chunk_loading_types);
         apply_context
           .registered_parser_and_generator_builder
           .into_iter()
@@ -83,8 +99,10 @@ impl PluginDriver {
         options: options.clone(),
         plugins,
         resolver_factory,
+        input_filesystem,
         // registered_parser,
         registered_parser_and_generator_builder,
+        registered_chunk_loading_types,
         diagnostics: Arc::new(Mutex::new(vec![])),
       }),
       options,
@@ -351,6 +369,22 @@ impl PluginDriver {
     Ok(None)
   }
 
+  pub fn normal_module_factory_parser(
+    &self,
+    module_type: &ModuleType,
+    parser_and_generator: &mut dyn ParserAndGenerator,
+  ) -> PluginNormalModuleFactoryParserHookOutput {
+    for plugin in &self.plugins {
+      tracing::trace!("running normal_module_factory_parser:{}", plugin.name());
+      plugin.normal_module_factory_parser(
+        PluginContext::new(),
+        module_type,
+        parser_and_generator,
+      )?;
+    }
+    Ok(())
+  }
+
   pub async fn normal_module_factory_module(
     &self,
     mut module: BoxModule,
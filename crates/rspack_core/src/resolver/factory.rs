@@ -1,6 +1,11 @@
-use std::{hash::BuildHasherDefault, sync::Arc};
+use std::{
+  hash::BuildHasherDefault,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+};
 
 use dashmap::DashMap;
+use rspack_fs::{AsyncNativeFileSystem, InputFileSystem, MemoryFileSystem, MemorySnapshot};
 use rustc_hash::FxHasher;
 
 use super::resolver_impl::Resolver;
@@ -18,6 +23,10 @@ pub struct ResolveOptionsWithDependencyType {
 pub struct ResolverFactory {
   base_options: Resolve,
   resolver: Resolver,
+  /// Files which only exist in memory, e.g. virtual modules, see [ResolverFactory::virtual_files].
+  virtual_files: Arc<MemoryFileSystem>,
+  /// `virtual_files` when the modified ones were taken last time.
+  virtual_snapshot: Mutex<MemorySnapshot>,
   /// Different resolvers are used for different resolution strategies such as ESM and CJS.
   /// All resolvers share the same underlying cache.
   resolvers: DashMap<ResolveOptionsWithDependencyType, Arc<Resolver>, BuildHasherDefault<FxHasher>>,
@@ -25,7 +34,7 @@ pub struct ResolverFactory {
 
 impl Default for ResolverFactory {
   fn default() -> Self {
-    Self::new(false, Resolve::default())
+    Self::new(false, Resolve::default(), Arc::new(AsyncNativeFileSystem))
   }
 }
 
@@ -34,14 +43,50 @@ impl ResolverFactory {
     self.resolver.clear_cache();
   }
 
-  pub fn new(new_resolver: bool, options: Resolve) -> Self {
+  /// Read a file inside of a zip archive or behind a virtual path of Yarn PnP,
+  /// `None` if the path can be read from the input file system directly.
+  pub fn read_pnp_file(&self, path: &Path) -> Option<rspack_fs::Result<Vec<u8>>> {
+    self.resolver.read_pnp_file(path)
+  }
+
+  pub fn new(
+    new_resolver: bool,
+    options: Resolve,
+    input_filesystem: Arc<dyn InputFileSystem>,
+  ) -> Self {
+    let virtual_files = Arc::new(MemoryFileSystem::new());
     Self {
       base_options: options.clone(),
-      resolver: Resolver::new(new_resolver, options),
+      resolver: Resolver::new(
+        new_resolver,
+        options,
+        input_filesystem,
+        virtual_files.clone(),
+      ),
+      virtual_files,
+      virtual_snapshot: Default::default(),
       resolvers: Default::default(),
     }
   }
 
+  /// Files which only exist in memory, they shadow the files of the input file system for the
+  /// new resolver, the legacy one only reads the disk. Reading them is up to the plugin adding them.
+  pub fn virtual_files(&self) -> &Arc<MemoryFileSystem> {
+    &self.virtual_files
+  }
+
+  /// The virtual files added, modified or removed since the last call, no watcher reports them.
+  pub fn take_modified_virtual_files(&self) -> Vec<PathBuf> {
+    let snapshot = self.virtual_files.snapshot();
+    let mut last = self
+      .virtual_snapshot
+      .lock()
+      .expect("should lock the snapshot of the virtual files");
+    let diff = last.diff(&snapshot);
+    *last = snapshot;
+    [diff.added, diff.modified, diff.removed].concat()
+  }
+
   pub fn get(&self, options: ResolveOptionsWithDependencyType) -> Arc<Resolver> {
     if let Some(r) = self.resolvers.get(&options) {
       r.clone()
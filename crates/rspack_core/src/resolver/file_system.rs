@@ -0,0 +1,110 @@
+use std::{
+  io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use oxc_resolver::{FileMetadata, FileSystem};
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+
+use super::pnp::{is_pnp_path, ZipFileSystem};
+
+/// The file system of [oxc_resolver], which reads the input file system and sees through the
+/// zip archives and virtual paths of Yarn PnP, so packages located by the PnP manifest are
+/// resolved as usual.
+///
+/// The disk is read directly when it's the input file system, which keeps symlinks working.
+/// The virtual files shadow the input file system.
+#[derive(Debug, Default)]
+pub struct ResolverFileSystem {
+  /// `None` only reads the disk, it's the one created by [oxc_resolver] internally.
+  zip_fs: Option<Arc<ZipFileSystem>>,
+  /// Files which only exist in memory, see [crate::ResolverFactory::virtual_files].
+  virtual_files: Option<Arc<MemoryFileSystem>>,
+}
+
+impl ResolverFileSystem {
+  pub fn new(zip_fs: Arc<ZipFileSystem>, virtual_files: Arc<MemoryFileSystem>) -> Self {
+    Self {
+      zip_fs: Some(zip_fs),
+      virtual_files: Some(virtual_files),
+    }
+  }
+
+  /// The metadata of `path` in the virtual files, `None` if it's not one of them.
+  fn virtual_metadata(&self, path: &Path) -> Option<rspack_fs::FileMetadata> {
+    self.virtual_files.as_ref()?.stat(path).ok()
+  }
+
+  fn is_virtual_file(&self, path: &Path) -> bool {
+    self
+      .virtual_metadata(path)
+      .is_some_and(|metadata| metadata.is_file)
+  }
+
+  /// The file system to read `path` through, `None` for the disk.
+  fn input_fs(&self, path: &Path) -> Option<&ZipFileSystem> {
+    self
+      .zip_fs
+      .as_deref()
+      .filter(|fs| !fs.is_native() || is_pnp_path(path))
+  }
+}
+
+fn into_io_error(error: rspack_fs::Error) -> io::Error {
+  match error {
+    rspack_fs::Error::Io(error) => error,
+  }
+}
+
+impl FileSystem for ResolverFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    let content = match (&self.virtual_files, self.input_fs(path)) {
+      (Some(virtual_files), _) if self.is_virtual_file(path) => {
+        ReadableFileSystem::read(&**virtual_files, path)
+      }
+      (_, Some(fs)) => fs.read(path),
+      (_, None) => return std::fs::read_to_string(path),
+    }
+    .map_err(into_io_error)?;
+    String::from_utf8(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    if let Some(metadata) = self.virtual_metadata(path) {
+      return Ok(FileMetadata::new(
+        metadata.is_file,
+        metadata.is_directory,
+        false,
+      ));
+    }
+    let Some(fs) = self.input_fs(path) else {
+      return std::fs::metadata(path).map(FileMetadata::from);
+    };
+    if fs.is_file(path) {
+      Ok(FileMetadata::new(true, false, false))
+    } else if fs.is_dir(path) {
+      Ok(FileMetadata::new(false, true, false))
+    } else {
+      Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+      ))
+    }
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    // symlinks are only followed on the disk, virtual paths have to stay as they are
+    if self.is_virtual_file(path) || self.input_fs(path).is_some() {
+      return self.metadata(path);
+    }
+    std::fs::symlink_metadata(path).map(FileMetadata::from)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    if self.is_virtual_file(path) || self.input_fs(path).is_some() {
+      return Ok(path.to_path_buf());
+    }
+    dunce::canonicalize(path)
+  }
+}
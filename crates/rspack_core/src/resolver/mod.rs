@@ -1,4 +1,6 @@
 mod factory;
+mod file_system;
+mod pnp;
 mod resolver_impl;
 
 use std::{fmt, path::PathBuf};
@@ -7,6 +9,7 @@ use rspack_error::Error;
 use rspack_loader_runner::DescriptionData;
 
 pub use self::factory::{ResolveOptionsWithDependencyType, ResolverFactory};
+pub use self::pnp::{is_zip_path, PnpError};
 pub use self::resolver_impl::{ResolveInnerOptions, Resolver};
 use crate::{ResolveArgs, SharedPluginDriver};
 
@@ -0,0 +1,468 @@
+use std::{
+  fmt,
+  path::{Path, PathBuf},
+};
+
+use rspack_regex::RspackRegex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::Deserialize;
+use sugar_path::SugarPath;
+
+/// Identifies a package in the PnP registry, `(None, None)` is the top-level package.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct Locator {
+  pub name: Option<String>,
+  pub reference: Option<String>,
+}
+
+impl Locator {
+  fn is_top_level(&self) -> bool {
+    self.name.is_none()
+  }
+}
+
+impl fmt::Display for Locator {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match (&self.name, &self.reference) {
+      (Some(name), Some(reference)) => write!(f, "{name}@{reference}"),
+      (Some(name), None) => write!(f, "{name}"),
+      _ => write!(f, "the top-level package"),
+    }
+  }
+}
+
+/// A dependency of a package, either `"reference"` or `["alias", "reference"]`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum PackageDependency {
+  Reference(String),
+  Alias(String, String),
+}
+
+impl PackageDependency {
+  fn to_locator(&self, name: &str) -> Locator {
+    match self {
+      Self::Reference(reference) => Locator {
+        name: Some(name.to_string()),
+        reference: Some(reference.clone()),
+      },
+      Self::Alias(name, reference) => Locator {
+        name: Some(name.clone()),
+        reference: Some(reference.clone()),
+      },
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPackageInformation {
+  package_location: String,
+  #[serde(default)]
+  package_dependencies: Vec<(String, Option<PackageDependency>)>,
+  #[serde(default)]
+  discard_from_lookup: bool,
+}
+
+type RawPackageRegistry = Vec<(Option<String>, Vec<(Option<String>, RawPackageInformation)>)>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawManifest {
+  #[serde(default)]
+  enable_top_level_fallback: bool,
+  #[serde(default)]
+  fallback_pool: Vec<(String, Option<PackageDependency>)>,
+  #[serde(default)]
+  fallback_exclusion_list: Vec<(String, Vec<String>)>,
+  #[serde(default)]
+  ignore_pattern_data: Option<String>,
+  #[serde(default)]
+  dependency_tree_roots: Vec<Locator>,
+  package_registry_data: RawPackageRegistry,
+}
+
+#[derive(Debug)]
+struct PackageInformation {
+  location: PathBuf,
+  dependencies: HashMap<String, Option<PackageDependency>>,
+}
+
+/// Errors of the PnP resolution, the messages follow the ones of Yarn.
+#[derive(Debug)]
+pub enum PnpError {
+  /// The manifest can't be read or parsed.
+  InvalidManifest { path: PathBuf, message: String },
+  /// A package requires a dependency which isn't declared in its dependencies.
+  UndeclaredDependency {
+    request: String,
+    dependency: String,
+    issuer: Locator,
+    issuer_path: PathBuf,
+  },
+  /// A package requires a peer dependency which isn't provided by its ancestors.
+  MissingPeerDependency {
+    request: String,
+    dependency: String,
+    issuer: Locator,
+    issuer_path: PathBuf,
+  },
+  /// A dependency points to a package which is missing from the registry.
+  MissingPackage { locator: Locator },
+  /// The legacy resolver only reads the disk, it can't see into zip archives or virtual paths.
+  LegacyResolver { path: PathBuf },
+}
+
+impl fmt::Display for PnpError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidManifest { path, message } => {
+        write!(
+          f,
+          "Failed to load the PnP manifest {}: {message}",
+          path.display()
+        )
+      }
+      Self::UndeclaredDependency {
+        request,
+        dependency,
+        issuer,
+        issuer_path,
+      } => {
+        if issuer.is_top_level() {
+          write!(f, "Your application tried to access {dependency}, but it isn't declared in your dependencies; this makes the require call ambiguous and unsound.")?;
+        } else {
+          let name = issuer.name.as_deref().unwrap_or_default();
+          write!(f, "{name} tried to access {dependency}, but it isn't declared in its dependencies; this makes the require call ambiguous and unsound.")?;
+        }
+        write!(
+          f,
+          "\n\nRequired package: {dependency} (via \"{request}\")\nRequired by: {issuer} (via {})",
+          issuer_path.display()
+        )
+      }
+      Self::MissingPeerDependency {
+        request,
+        dependency,
+        issuer,
+        issuer_path,
+      } => {
+        if issuer.is_top_level() {
+          write!(f, "Your application tried to access {dependency} (a peer dependency); this isn't allowed as there is no ancestor to satisfy the requirement. Use a devDependency if needed.")?;
+        } else {
+          let name = issuer.name.as_deref().unwrap_or_default();
+          write!(f, "{name} tried to access {dependency} (a peer dependency) but it isn't provided by its ancestors; this makes the require call ambiguous and unsound.")?;
+        }
+        write!(
+          f,
+          "\n\nRequired package: {dependency} (via \"{request}\")\nRequired by: {issuer} (via {})",
+          issuer_path.display()
+        )
+      }
+      Self::MissingPackage { locator } => write!(
+        f,
+        "The PnP manifest references {locator}, which is missing from the package registry"
+      ),
+      Self::LegacyResolver { path } => write!(
+        f,
+        "{} is inside of a zip archive or a virtual path of Yarn PnP, which can only be resolved with `experiments.rspackFuture.newResolver` enabled",
+        path.display()
+      ),
+    }
+  }
+}
+
+/// A package resolved through the PnP manifest, not yet resolved to a file.
+#[derive(Debug)]
+pub struct PnpResolution {
+  /// The directory of the package, it may be inside of a zip archive or a virtual path.
+  pub package_path: PathBuf,
+  /// The request inside of the package, e.g. `lib/foo` of `pkg/lib/foo`, may be empty.
+  pub subpath: String,
+}
+
+/// The content of `.pnp.data.json`, or the state inlined in `.pnp.cjs`.
+#[derive(Debug)]
+pub struct Manifest {
+  dir: PathBuf,
+  packages: HashMap<Locator, PackageInformation>,
+  /// Package locations used to find the package owning an issuer.
+  locations: HashMap<PathBuf, Locator>,
+  dependency_tree_roots: HashSet<Locator>,
+  enable_top_level_fallback: bool,
+  fallback_pool: HashMap<String, Option<PackageDependency>>,
+  fallback_exclusions: HashMap<String, HashSet<String>>,
+  ignore_pattern: Option<RspackRegex>,
+}
+
+impl Manifest {
+  pub fn from_json(path: &Path, json: &str) -> Result<Self, PnpError> {
+    let invalid = |message: String| PnpError::InvalidManifest {
+      path: path.to_path_buf(),
+      message,
+    };
+    let raw: RawManifest = serde_json::from_str(json).map_err(|e| invalid(e.to_string()))?;
+    let dir = path.parent().unwrap_or(Path::new("/")).to_path_buf();
+
+    let mut packages = HashMap::default();
+    let mut locations = HashMap::default();
+    for (name, references) in raw.package_registry_data {
+      for (reference, information) in references {
+        let locator = Locator {
+          name: name.clone(),
+          reference,
+        };
+        let location = dir.join(&information.package_location).normalize();
+        if !information.discard_from_lookup {
+          locations.insert(location.clone(), locator.clone());
+        }
+        packages.insert(
+          locator,
+          PackageInformation {
+            location,
+            dependencies: information.package_dependencies.into_iter().collect(),
+          },
+        );
+      }
+    }
+
+    let ignore_pattern = raw
+      .ignore_pattern_data
+      .map(|pattern| RspackRegex::new(&pattern).map_err(|e| invalid(e.to_string())))
+      .transpose()?;
+
+    Ok(Self {
+      dir,
+      packages,
+      locations,
+      dependency_tree_roots: raw.dependency_tree_roots.into_iter().collect(),
+      enable_top_level_fallback: raw.enable_top_level_fallback,
+      fallback_pool: raw.fallback_pool.into_iter().collect(),
+      fallback_exclusions: raw
+        .fallback_exclusion_list
+        .into_iter()
+        .map(|(name, references)| (name, references.into_iter().collect()))
+        .collect(),
+      ignore_pattern,
+    })
+  }
+
+  /// Extract the state inlined in `.pnp.cjs`, which is a JSON string literal
+  /// assigned to `RAW_RUNTIME_STATE`.
+  pub fn from_pnp_cjs(path: &Path, source: &str) -> Result<Self, PnpError> {
+    let json = source
+      .split_once("RAW_RUNTIME_STATE =")
+      .and_then(|(_, rest)| parse_string_literal(rest.trim_start()))
+      .ok_or_else(|| PnpError::InvalidManifest {
+        path: path.to_path_buf(),
+        message: "RAW_RUNTIME_STATE is missing".to_string(),
+      })?;
+    Self::from_json(path, &json)
+  }
+
+  /// Find the package owning `path`, `None` if the path isn't controlled by this manifest.
+  fn find_locator(&self, path: &Path) -> Option<&Locator> {
+    let relative = path.strip_prefix(&self.dir).ok()?;
+    if let Some(ignore_pattern) = &self.ignore_pattern
+      && ignore_pattern.test(&relative.to_string_lossy().replace('\\', "/"))
+    {
+      return None;
+    }
+    path
+      .ancestors()
+      .take_while(|ancestor| ancestor.starts_with(&self.dir))
+      .find_map(|ancestor| self.locations.get(ancestor))
+  }
+
+  fn find_dependency(&self, issuer: &Locator, ident: &str) -> Option<Option<&PackageDependency>> {
+    let information = self.packages.get(issuer)?;
+    if let Some(dependency) = information.dependencies.get(ident) {
+      return Some(dependency.as_ref());
+    }
+    if !self.enable_top_level_fallback || self.dependency_tree_roots.contains(issuer) {
+      return None;
+    }
+    if let Some(name) = &issuer.name
+      && let Some(reference) = &issuer.reference
+      && self
+        .fallback_exclusions
+        .get(name)
+        .is_some_and(|references| references.contains(reference))
+    {
+      return None;
+    }
+    let top_level = Locator {
+      name: None,
+      reference: None,
+    };
+    self
+      .packages
+      .get(&top_level)
+      .and_then(|information| information.dependencies.get(ident))
+      .or_else(|| self.fallback_pool.get(ident))
+      .map(Option::as_ref)
+  }
+
+  /// Resolve a bare `specifier` required from `issuer_path` to the directory of its package.
+  ///
+  /// Returns `None` if the issuer isn't controlled by this manifest, the specifier should
+  /// then be resolved as usual.
+  pub fn resolve_to_unqualified(
+    &self,
+    specifier: &str,
+    issuer_path: &Path,
+  ) -> Result<Option<PnpResolution>, PnpError> {
+    let Some((ident, subpath)) = parse_bare_specifier(specifier) else {
+      return Ok(None);
+    };
+    let Some(issuer) = self.find_locator(issuer_path) else {
+      return Ok(None);
+    };
+    let dependency = match self.find_dependency(issuer, ident) {
+      Some(Some(dependency)) => dependency,
+      Some(None) => {
+        return Err(PnpError::MissingPeerDependency {
+          request: specifier.to_string(),
+          dependency: ident.to_string(),
+          issuer: issuer.clone(),
+          issuer_path: issuer_path.to_path_buf(),
+        })
+      }
+      None => {
+        return Err(PnpError::UndeclaredDependency {
+          request: specifier.to_string(),
+          dependency: ident.to_string(),
+          issuer: issuer.clone(),
+          issuer_path: issuer_path.to_path_buf(),
+        })
+      }
+    };
+    let locator = dependency.to_locator(ident);
+    let information = self
+      .packages
+      .get(&locator)
+      .ok_or(PnpError::MissingPackage { locator })?;
+    Ok(Some(PnpResolution {
+      package_path: information.location.clone(),
+      subpath: subpath.to_string(),
+    }))
+  }
+}
+
+/// Split a bare specifier into the package name and the path inside of the package,
+/// e.g. `@scope/pkg/lib/foo` into `@scope/pkg` and `lib/foo`.
+pub fn parse_bare_specifier(specifier: &str) -> Option<(&str, &str)> {
+  let name_end = if specifier.starts_with('@') {
+    let scope_end = specifier.find('/')?;
+    specifier[scope_end + 1..]
+      .find('/')
+      .map(|i| scope_end + 1 + i)
+  } else {
+    specifier.find('/')
+  };
+  let (ident, subpath) = match name_end {
+    Some(end) => (&specifier[..end], &specifier[end + 1..]),
+    None => (specifier, ""),
+  };
+  (!ident.is_empty() && !ident.ends_with('/')).then_some((ident, subpath))
+}
+
+/// Parse a single quoted JS string literal at the start of `source`.
+fn parse_string_literal(source: &str) -> Option<String> {
+  let mut chars = source.strip_prefix('\'')?.chars();
+  let mut value = String::new();
+  loop {
+    match chars.next()? {
+      '\'' => return Some(value),
+      '\\' => match chars.next()? {
+        // line continuation
+        '\n' => {}
+        '\r' => {
+          let rest = chars.as_str();
+          chars = rest.strip_prefix('\n').unwrap_or(rest).chars();
+        }
+        'n' => value.push('\n'),
+        'r' => value.push('\r'),
+        't' => value.push('\t'),
+        c => value.push(c),
+      },
+      c => value.push(c),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn manifest() -> Manifest {
+    Manifest::from_json(
+      Path::new("/project/.pnp.data.json"),
+      r#"{
+        "enableTopLevelFallback": false,
+        "ignorePatternData": null,
+        "dependencyTreeRoots": [{ "name": "app", "reference": "workspace:." }],
+        "packageRegistryData": [
+          [null, [[null, {
+            "packageLocation": "./",
+            "packageDependencies": [["lodash", "npm:4.17.21"], ["app", "workspace:."]]
+          }]]],
+          ["app", [["workspace:.", {
+            "packageLocation": "./",
+            "packageDependencies": [["lodash", "npm:4.17.21"], ["react", null]]
+          }]]],
+          ["lodash", [["npm:4.17.21", {
+            "packageLocation": "./.yarn/cache/lodash-npm-4.17.21-6382451519-eb835a2e51.zip/node_modules/lodash/",
+            "packageDependencies": [["lodash", "npm:4.17.21"]]
+          }]]]
+        ]
+      }"#,
+    )
+    .expect("should parse manifest")
+  }
+
+  #[test]
+  fn resolve_dependencies() {
+    let manifest = manifest();
+    let resolution = manifest
+      .resolve_to_unqualified("lodash/get", Path::new("/project/src"))
+      .expect("should resolve")
+      .expect("should be controlled by the manifest");
+    assert_eq!(
+      resolution.package_path,
+      PathBuf::from(
+        "/project/.yarn/cache/lodash-npm-4.17.21-6382451519-eb835a2e51.zip/node_modules/lodash"
+      )
+    );
+    assert_eq!(resolution.subpath, "get");
+
+    assert!(matches!(
+      manifest.resolve_to_unqualified("react", Path::new("/project/src")),
+      Err(PnpError::MissingPeerDependency { .. })
+    ));
+    assert!(matches!(
+      manifest.resolve_to_unqualified("vue", Path::new("/project/src")),
+      Err(PnpError::UndeclaredDependency { .. })
+    ));
+    assert!(manifest
+      .resolve_to_unqualified("lodash", Path::new("/elsewhere"))
+      .expect("should not fail")
+      .is_none());
+  }
+
+  #[test]
+  fn parse_inlined_state() {
+    let source = "#!/usr/bin/env node\n/* eslint-disable */\n\"use strict\";\n\nconst RAW_RUNTIME_STATE =\n'{\\\n  \"packageRegistryData\": [],\\\n  \"note\": \"it\\'s \\\\\"quoted\\\\\"\"\\\n}';\n";
+    assert!(Manifest::from_pnp_cjs(Path::new("/project/.pnp.cjs"), source).is_ok());
+  }
+
+  #[test]
+  fn parse_specifiers() {
+    assert_eq!(parse_bare_specifier("lodash"), Some(("lodash", "")));
+    assert_eq!(
+      parse_bare_specifier("@scope/pkg/lib/foo"),
+      Some(("@scope/pkg", "lib/foo"))
+    );
+    assert_eq!(parse_bare_specifier("@scope"), None);
+  }
+}
@@ -0,0 +1,166 @@
+//! Resolution for [Yarn Plug'n'Play](https://yarnpkg.com/features/pnp) projects,
+//! which have no `node_modules` but a manifest mapping each package to its dependencies.
+
+mod manifest;
+mod zip_fs;
+
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use dashmap::DashMap;
+use rspack_fs::InputFileSystem;
+
+pub use self::manifest::{Locator, Manifest, PnpError, PnpResolution};
+pub use self::zip_fs::{is_pnp_path, is_zip_path, ZipFileSystem};
+
+/// Manifests in the order of priority, `.pnp.data.json` exists when the state is not
+/// inlined in `.pnp.cjs`.
+const MANIFEST_FILES: [&str; 2] = [".pnp.data.json", ".pnp.cjs"];
+
+/// Caches of the PnP resolution, shared by all resolvers.
+#[derive(Debug)]
+pub struct Pnp {
+  /// The manifest controlling each looked up directory.
+  manifests: DashMap<PathBuf, Option<Arc<Manifest>>>,
+  fs: Arc<ZipFileSystem>,
+}
+
+impl Pnp {
+  pub fn new(input_filesystem: Arc<dyn InputFileSystem>) -> Self {
+    Self {
+      manifests: Default::default(),
+      fs: Arc::new(ZipFileSystem::new(input_filesystem)),
+    }
+  }
+
+  pub fn clear(&self) {
+    self.manifests.clear();
+    self.fs.clear();
+  }
+
+  /// The input file system seeing through zip archives and virtual paths.
+  pub fn file_system(&self) -> Arc<ZipFileSystem> {
+    self.fs.clone()
+  }
+
+  /// Find the manifest controlling `dir` in its ancestors.
+  pub fn find_manifest(&self, dir: &Path) -> Result<Option<Arc<Manifest>>, PnpError> {
+    if let Some(manifest) = self.manifests.get(dir) {
+      return Ok(manifest.clone());
+    }
+    let manifest = match self.load_manifest(dir)? {
+      Some(manifest) => Some(Arc::new(manifest)),
+      None => match dir.parent() {
+        Some(parent) => self.find_manifest(parent)?,
+        None => None,
+      },
+    };
+    self.manifests.insert(dir.to_path_buf(), manifest.clone());
+    Ok(manifest)
+  }
+
+  /// Read a file inside of a zip archive or behind a virtual path,
+  /// `None` if the path can be read from the input file system directly.
+  pub fn read_file(&self, path: &Path) -> Option<rspack_fs::Result<Vec<u8>>> {
+    is_pnp_path(path).then(|| self.fs.read(path))
+  }
+
+  fn load_manifest(&self, dir: &Path) -> Result<Option<Manifest>, PnpError> {
+    for file in MANIFEST_FILES {
+      let path = dir.join(file);
+      if !self.fs.is_file(&path) {
+        continue;
+      }
+      let source = self
+        .fs
+        .read(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|source| String::from_utf8(source).map_err(|e| e.to_string()))
+        .map_err(|message| PnpError::InvalidManifest {
+          path: path.clone(),
+          message,
+        })?;
+      let manifest = if file.ends_with(".json") {
+        Manifest::from_json(&path, &source)?
+      } else {
+        Manifest::from_pnp_cjs(&path, &source)?
+      };
+      return Ok(Some(manifest));
+    }
+    Ok(None)
+  }
+}
+
+/// Whether the request is a bare specifier, which is resolved through the PnP manifest.
+pub fn is_bare_request(request: &str) -> bool {
+  !request.is_empty()
+    && !request.starts_with(['.', '/', '#'])
+    && !request.contains(':')
+    && !Path::new(request).is_absolute()
+}
+
+/// Split the query and the fragment off a request, e.g. `pkg/foo?raw#top` into
+/// `pkg/foo`, `?raw` and `#top`.
+pub fn split_request(request: &str) -> (&str, &str, &str) {
+  let (rest, fragment) = match request.find('#') {
+    Some(index) if index > 0 => request.split_at(index),
+    _ => (request, ""),
+  };
+  let (path, query) = match rest.find('?') {
+    Some(index) => rest.split_at(index),
+    None => (rest, ""),
+  };
+  (path, query, fragment)
+}
+
+/// Split the directory of a package in a `node_modules` layout into the `node_modules`
+/// directory and the package name, e.g. `/cache/pkg.zip/node_modules/@scope/pkg` into
+/// `/cache/pkg.zip/node_modules` and `@scope/pkg`.
+pub fn split_node_modules_path(package_path: &Path) -> Option<(&Path, String)> {
+  let name = package_path.file_name()?.to_str()?;
+  let parent = package_path.parent()?;
+  if parent.file_name()? == "node_modules" {
+    return Some((parent, name.to_string()));
+  }
+  let scope = parent.file_name()?.to_str()?;
+  let modules_dir = parent.parent()?;
+  (scope.starts_with('@') && modules_dir.file_name()? == "node_modules")
+    .then(|| (modules_dir, format!("{scope}/{name}")))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn requests() {
+    assert!(is_bare_request("@scope/pkg/foo"));
+    assert!(!is_bare_request("./foo"));
+    assert!(!is_bare_request("node:fs"));
+    assert_eq!(
+      split_request("pkg/foo?raw#top"),
+      ("pkg/foo", "?raw", "#top")
+    );
+  }
+
+  #[test]
+  fn node_modules_paths() {
+    assert_eq!(
+      split_node_modules_path(Path::new("/cache/pkg.zip/node_modules/@scope/pkg")),
+      Some((
+        Path::new("/cache/pkg.zip/node_modules"),
+        "@scope/pkg".to_string()
+      ))
+    );
+    assert_eq!(
+      split_node_modules_path(Path::new("/cache/pkg.zip/node_modules/pkg")),
+      Some((Path::new("/cache/pkg.zip/node_modules"), "pkg".to_string()))
+    );
+    assert_eq!(
+      split_node_modules_path(Path::new("/project/packages/pkg")),
+      None
+    );
+  }
+}
@@ -0,0 +1,233 @@
+use std::{
+  borrow::Cow,
+  io::{self, Cursor, Read},
+  path::{Component, Path, PathBuf},
+  sync::{Arc, Mutex},
+};
+
+use dashmap::DashMap;
+use rspack_fs::{Error, InputFileSystem, ReadableFileSystem, Result};
+use rustc_hash::FxHashSet as HashSet;
+use zip::ZipArchive;
+
+#[derive(Debug)]
+struct Archive {
+  zip: Mutex<ZipArchive<Cursor<Vec<u8>>>>,
+  files: HashSet<String>,
+  dirs: HashSet<String>,
+}
+
+impl Archive {
+  fn open(content: Vec<u8>) -> Result<Self> {
+    let zip = ZipArchive::new(Cursor::new(content))
+      .map_err(|e| Error::Io(io::Error::new(io::ErrorKind::InvalidData, e)))?;
+    let mut files = HashSet::default();
+    let mut dirs = HashSet::default();
+    for name in zip.file_names() {
+      let mut name = match name.strip_suffix('/') {
+        Some(dir) => {
+          dirs.insert(dir.to_string());
+          dir
+        }
+        None => {
+          files.insert(name.to_string());
+          name
+        }
+      };
+      while let Some((parent, _)) = name.rsplit_once('/') {
+        dirs.insert(parent.to_string());
+        name = parent;
+      }
+    }
+    Ok(Self {
+      zip: Mutex::new(zip),
+      files,
+      dirs,
+    })
+  }
+
+  fn read(&self, name: &str) -> Result<Vec<u8>> {
+    let mut zip = self.zip.lock().expect("should lock the zip archive");
+    let mut file = zip
+      .by_name(name)
+      .map_err(|e| Error::Io(io::Error::new(io::ErrorKind::NotFound, e)))?;
+    let mut content = Vec::with_capacity(file.size() as usize);
+    file.read_to_end(&mut content)?;
+    Ok(content)
+  }
+}
+
+/// Split a path inside of a zip archive into the archive and the entry name,
+/// e.g. `/cache/pkg.zip/node_modules/pkg/index.js` into `/cache/pkg.zip` and
+/// `node_modules/pkg/index.js`.
+fn split_archive_path(path: &Path) -> Option<(PathBuf, String)> {
+  let mut archive = PathBuf::new();
+  let mut components = path.components();
+  while let Some(component) = components.next() {
+    archive.push(component);
+    if let Component::Normal(name) = component
+      && name.to_string_lossy().ends_with(".zip")
+    {
+      let name = components
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      return Some((archive, name));
+    }
+  }
+  None
+}
+
+/// Map a virtual path `<base>/__virtual__/<hash>/<depth>/<subpath>` to the real path,
+/// which is `<subpath>` relative to `<depth>` levels above `<base>`.
+///
+/// Yarn uses virtual paths to instantiate packages with peer dependencies once per set
+/// of peer dependencies, they have to stay as module paths but don't exist on the disk.
+pub fn resolve_virtual_path(path: &Path) -> Cow<'_, Path> {
+  let components = path.components().collect::<Vec<_>>();
+  let Some(index) = components
+    .iter()
+    .position(|c| c.as_os_str() == "__virtual__" || c.as_os_str() == "$$virtual")
+  else {
+    return Cow::Borrowed(path);
+  };
+  let Some(depth) = components
+    .get(index + 2)
+    .and_then(|c| c.as_os_str().to_str())
+    .and_then(|depth| depth.parse::<usize>().ok())
+  else {
+    return Cow::Borrowed(path);
+  };
+  let mut real_path = components[..index].iter().collect::<PathBuf>();
+  for _ in 0..depth {
+    real_path.pop();
+  }
+  real_path.extend(&components[index + 3..]);
+  Cow::Owned(real_path)
+}
+
+/// Whether the path is a virtual path or inside of a zip archive, which means it can only
+/// be read through [ZipFileSystem].
+pub fn is_pnp_path(path: &Path) -> bool {
+  matches!(resolve_virtual_path(path), Cow::Owned(_)) || split_archive_path(path).is_some()
+}
+
+/// Whether the path is inside of a zip archive of the Yarn cache, also through a virtual path.
+/// The archives are content addressed, so they never change.
+pub fn is_zip_path(path: &Path) -> bool {
+  split_archive_path(&resolve_virtual_path(path)).is_some()
+}
+
+/// A read-only view of the input file system which sees through the zip archives of the
+/// Yarn cache and the virtual paths, e.g.
+/// `.yarn/__virtual__/pkg-virtual-0a1b2c/0/cache/pkg-npm-1.0.0-3d4e5f.zip/node_modules/pkg/index.js`.
+#[derive(Debug)]
+pub struct ZipFileSystem {
+  input_filesystem: Arc<dyn InputFileSystem>,
+  archives: DashMap<PathBuf, Arc<Archive>>,
+}
+
+impl ZipFileSystem {
+  pub fn new(input_filesystem: Arc<dyn InputFileSystem>) -> Self {
+    Self {
+      input_filesystem,
+      archives: Default::default(),
+    }
+  }
+
+  pub fn clear(&self) {
+    self.archives.clear();
+  }
+
+  /// Whether the underlying input file system is the disk.
+  pub fn is_native(&self) -> bool {
+    self.input_filesystem.is_native()
+  }
+
+  fn archive(&self, path: &Path) -> Result<Arc<Archive>> {
+    if let Some(archive) = self.archives.get(path) {
+      return Ok(archive.clone());
+    }
+    let content = ReadableFileSystem::read(&*self.input_filesystem, path)?;
+    let archive = Arc::new(Archive::open(content)?);
+    self.archives.insert(path.to_path_buf(), archive.clone());
+    Ok(archive)
+  }
+
+  pub fn read(&self, path: &Path) -> Result<Vec<u8>> {
+    let path = resolve_virtual_path(path);
+    match split_archive_path(&path) {
+      Some((archive, name)) => self.archive(&archive)?.read(&name),
+      None => ReadableFileSystem::read(&*self.input_filesystem, &path),
+    }
+  }
+
+  pub fn is_file(&self, path: &Path) -> bool {
+    let path = resolve_virtual_path(path);
+    match split_archive_path(&path) {
+      Some((archive, name)) => self
+        .archive(&archive)
+        .is_ok_and(|archive| archive.files.contains(&name)),
+      None => ReadableFileSystem::metadata(&*self.input_filesystem, &path)
+        .is_ok_and(|metadata| metadata.is_file),
+    }
+  }
+
+  pub fn is_dir(&self, path: &Path) -> bool {
+    let path = resolve_virtual_path(path);
+    match split_archive_path(&path) {
+      Some((archive, name)) => {
+        name.is_empty()
+          || self
+            .archive(&archive)
+            .is_ok_and(|archive| archive.dirs.contains(&name))
+      }
+      None => ReadableFileSystem::metadata(&*self.input_filesystem, &path)
+        .is_ok_and(|metadata| metadata.is_directory),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn virtual_paths() {
+    assert_eq!(
+      resolve_virtual_path(Path::new(
+        "/project/.yarn/__virtual__/pkg-virtual-0a1b2c/0/cache/pkg.zip/node_modules/pkg/index.js"
+      )),
+      Path::new("/project/.yarn/cache/pkg.zip/node_modules/pkg/index.js")
+    );
+    assert_eq!(
+      resolve_virtual_path(Path::new(
+        "/project/.yarn/__virtual__/pkg-virtual-0a1b2c/2/packages/pkg/index.js"
+      )),
+      Path::new("/packages/pkg/index.js")
+    );
+    assert!(matches!(
+      resolve_virtual_path(Path::new("/project/src/index.js")),
+      Cow::Borrowed(_)
+    ));
+  }
+
+  #[test]
+  fn archive_paths() {
+    assert_eq!(
+      split_archive_path(Path::new("/cache/pkg.zip/node_modules/pkg/index.js")),
+      Some((
+        PathBuf::from("/cache/pkg.zip"),
+        "node_modules/pkg/index.js".to_string()
+      ))
+    );
+    assert!(is_pnp_path(Path::new("/cache/pkg.zip/node_modules/pkg")));
+    assert!(!is_pnp_path(Path::new("/project/src/index.js")));
+    assert!(is_zip_path(Path::new(
+      "/project/.yarn/__virtual__/pkg-virtual-0a1b2c/0/cache/pkg.zip/node_modules/pkg"
+    )));
+    assert!(!is_zip_path(Path::new(
+      "/project/.yarn/__virtual__/pkg-virtual-0a1b2c/1/packages/pkg"
+    )));
+  }
+}
@@ -7,12 +7,15 @@ use std::{
 use rspack_error::{
   internal_error, DiagnosticError, ErrorExt, InternalError, Severity, TraceableError,
 };
+use rspack_fs::{InputFileSystem, MemoryFileSystem};
 use rspack_loader_runner::DescriptionData;
-use sugar_path::SugarPath;
 
+use super::file_system::ResolverFileSystem;
+use super::pnp::{self, Pnp, PnpError, PnpResolution};
 use super::{ResolveError, ResolveResult, Resource};
 use crate::{
-  DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType, SharedPluginDriver,
+  DependencyCategory, Resolve, ResolveArgs, ResolveOptionsWithDependencyType, Restriction,
+  SharedPluginDriver,
 };
 
 /// Proxy to [nodejs_resolver::Error] or [oxc_resolver::ResolveError]
@@ -20,6 +23,9 @@ use crate::{
 pub enum ResolveInnerError {
   NodejsResolver(nodejs_resolver::Error),
   OxcResolver(oxc_resolver::ResolveError),
+  Pnp(PnpError),
+  /// The resolved path is not allowed by `resolve.restrictions`.
+  Restricted,
 }
 
 /// Proxy to [nodejs_resolver::Options] or [oxc_resolver::ResolveOptions]
@@ -83,39 +89,73 @@ impl<'a> ResolveInnerOptions<'a> {
 ///
 /// Internal caches are shared.
 #[derive(Debug)]
-pub enum Resolver {
+pub struct Resolver {
+  inner: ResolverInner,
+  /// Directories where server-relative requests are resolved, see `resolve.roots`.
+  roots: Vec<PathBuf>,
+  /// Restrictions of the resolved paths, see `resolve.restrictions`.
+  restrictions: Vec<Restriction>,
+  pnp: Arc<Pnp>,
+}
+
+#[derive(Debug)]
+enum ResolverInner {
   NodejsResolver(nodejs_resolver::Resolver, Arc<nodejs_resolver::Cache>),
-  OxcResolver(oxc_resolver::Resolver),
+  OxcResolver(oxc_resolver::ResolverGeneric<ResolverFileSystem>),
 }
 
 impl Resolver {
-  pub fn new(new_resolver: bool, options: Resolve) -> Self {
-    if new_resolver {
-      Self::new_oxc_resolver(options)
+  /// Create a resolver reading `input_filesystem`.
+  ///
+  /// Only the new resolver reads the input file system, the legacy one (`new_resolver` is false)
+  /// always reads the disk, so files only existing in a non-native input file system, the
+  /// PnP packages in zip archives, or `virtual_files`, are not found by it.
+  pub fn new(
+    new_resolver: bool,
+    options: Resolve,
+    input_filesystem: Arc<dyn InputFileSystem>,
+    virtual_files: Arc<MemoryFileSystem>,
+  ) -> Self {
+    let options = options.merge_by_dependency(DependencyCategory::Unknown);
+    let (roots, restrictions) = roots_and_restrictions(&options);
+    let pnp = Arc::new(Pnp::new(input_filesystem.clone()));
+    let inner = if new_resolver {
+      Self::new_oxc_resolver(options, &pnp, virtual_files)
     } else {
       Self::new_nodejs_resolver(options)
+    };
+    Self {
+      inner,
+      roots,
+      restrictions,
+      pnp,
     }
   }
 
-  fn new_nodejs_resolver(options: Resolve) -> Self {
+  fn new_nodejs_resolver(options: Resolve) -> ResolverInner {
     let cache = Arc::new(nodejs_resolver::Cache::default());
-    let options =
-      to_nodejs_resolver_options(cache.clone(), options, false, DependencyCategory::Unknown);
+    let options = to_nodejs_resolver_options(cache.clone(), options, false);
     let resolver = nodejs_resolver::Resolver::new(options);
-    Self::NodejsResolver(resolver, cache)
+    ResolverInner::NodejsResolver(resolver, cache)
   }
 
-  fn new_oxc_resolver(options: Resolve) -> Self {
-    let options = to_oxc_resolver_options(options, false, DependencyCategory::Unknown);
-    let resolver = oxc_resolver::Resolver::new(options);
-    Self::OxcResolver(resolver)
+  fn new_oxc_resolver(
+    options: Resolve,
+    pnp: &Pnp,
+    virtual_files: Arc<MemoryFileSystem>,
+  ) -> ResolverInner {
+    let options = to_oxc_resolver_options(options, false);
+    let file_system = ResolverFileSystem::new(pnp.file_system(), virtual_files);
+    let resolver = oxc_resolver::ResolverGeneric::new_with_file_system(file_system, options);
+    ResolverInner::OxcResolver(resolver)
   }
 
   /// Clear cache for all resolver instances
   pub fn clear_cache(&self) {
-    match self {
-      Self::NodejsResolver(_, cache) => cache.entries.clear(),
-      Self::OxcResolver(resolver) => resolver.clear_cache(),
+    self.pnp.clear();
+    match &self.inner {
+      ResolverInner::NodejsResolver(_, cache) => cache.entries.clear(),
+      ResolverInner::OxcResolver(resolver) => resolver.clear_cache(),
     }
   }
 
@@ -125,29 +165,39 @@ impl Resolver {
     options: Resolve,
     options_with_dependency_type: &ResolveOptionsWithDependencyType,
   ) -> Self {
-    match self {
-      Self::NodejsResolver(_, cache) => {
+    let options = options.merge_by_dependency(options_with_dependency_type.dependency_category);
+    let (roots, restrictions) = roots_and_restrictions(&options);
+    let inner = match &self.inner {
+      ResolverInner::NodejsResolver(_, cache) => {
         let options = to_nodejs_resolver_options(
           cache.clone(),
           options,
           options_with_dependency_type.resolve_to_context,
-          options_with_dependency_type.dependency_category,
         );
         let resolver = nodejs_resolver::Resolver::new(options);
-        Self::NodejsResolver(resolver, cache.clone())
+        ResolverInner::NodejsResolver(resolver, cache.clone())
       }
-      Self::OxcResolver(resolver) => {
-        let options = to_oxc_resolver_options(
-          options,
-          options_with_dependency_type.resolve_to_context,
-          options_with_dependency_type.dependency_category,
-        );
+      ResolverInner::OxcResolver(resolver) => {
+        let options =
+          to_oxc_resolver_options(options, options_with_dependency_type.resolve_to_context);
         let resolver = resolver.clone_with_options(options);
-        Self::OxcResolver(resolver)
+        ResolverInner::OxcResolver(resolver)
       }
+    };
+    Self {
+      inner,
+      roots,
+      restrictions,
+      pnp: self.pnp.clone(),
     }
   }
 
+  /// Read a file inside of a zip archive or behind a virtual path of Yarn PnP,
+  /// `None` if the path can be read from the input file system directly.
+  pub fn read_pnp_file(&self, path: &Path) -> Option<rspack_fs::Result<Vec<u8>>> {
+    self.pnp.read_file(path)
+  }
+
   /// Return `dependencies` from `enhanced-resolve`
   ///
   /// Implementation is currently blank.
@@ -157,16 +207,118 @@ impl Resolver {
 
   /// Return the options from the resolver
   pub fn options(&self) -> ResolveInnerOptions<'_> {
-    match self {
-      Self::NodejsResolver(resolver, _) => ResolveInnerOptions::NodejsResolver(&resolver.options),
-      Self::OxcResolver(resolver) => ResolveInnerOptions::OxcResolver(resolver.options()),
+    match &self.inner {
+      ResolverInner::NodejsResolver(resolver, _) => {
+        ResolveInnerOptions::NodejsResolver(&resolver.options)
+      }
+      ResolverInner::OxcResolver(resolver) => ResolveInnerOptions::OxcResolver(resolver.options()),
     }
   }
 
   /// Resolve a specifier to a given path.
+  ///
+  /// Server-relative requests are tried in each of the `roots` first,
+  /// and results that don't satisfy the `restrictions` are rejected.
   pub fn resolve(&self, path: &Path, request: &str) -> Result<ResolveResult, ResolveInnerError> {
-    match self {
-      Self::NodejsResolver(resolver, _) => resolver
+    if let Some(server_relative) = request.strip_prefix('/') {
+      for root in &self.roots {
+        let request = root.join(server_relative);
+        if let Ok(result) = self.resolve_with_pnp(path, &request.to_string_lossy())
+          && self.is_allowed(&result)
+        {
+          return Ok(result);
+        }
+      }
+    }
+    let result = self.resolve_with_pnp(path, request)?;
+    if !self.is_allowed(&result) {
+      return Err(ResolveInnerError::Restricted);
+    }
+    Ok(result)
+  }
+
+  /// Resolve bare specifiers through the PnP manifest controlling `path` when the resolver
+  /// can't, i.e. after `resolve.alias`, `resolve.fallback` and `node_modules` are tried.
+  fn resolve_with_pnp(
+    &self,
+    path: &Path,
+    request: &str,
+  ) -> Result<ResolveResult, ResolveInnerError> {
+    self.ensure_readable(path)?;
+    if !pnp::is_bare_request(request) {
+      return self.resolve_inner(path, request);
+    }
+    let error = match self.resolve_inner(path, request) {
+      Err(error) => error,
+      result => return result,
+    };
+    let Some(manifest) = self
+      .pnp
+      .find_manifest(path)
+      .map_err(ResolveInnerError::Pnp)?
+    else {
+      return Err(error);
+    };
+    let (specifier, query, fragment) = pnp::split_request(request);
+    match manifest.resolve_to_unqualified(specifier, path) {
+      Ok(Some(resolution)) => self.resolve_in_package(&resolution, query, fragment),
+      Ok(None) => Err(error),
+      Err(error) => Err(ResolveInnerError::Pnp(error)),
+    }
+  }
+
+  /// Resolve the request inside of a package located by the PnP manifest, the package is
+  /// handed over to the resolver, so its `exports`, main fields and the extensions apply.
+  fn resolve_in_package(
+    &self,
+    resolution: &PnpResolution,
+    query: &str,
+    fragment: &str,
+  ) -> Result<ResolveResult, ResolveInnerError> {
+    let package_path = &resolution.package_path;
+    self.ensure_readable(package_path)?;
+    let subpath = if resolution.subpath.is_empty() {
+      String::new()
+    } else {
+      format!("/{}", resolution.subpath)
+    };
+    // Packages in zip archives, virtual paths and unplugged ones are in a `node_modules` layout,
+    // they are requested by name from their `node_modules` to apply the `exports` field.
+    if let Some((modules_dir, name)) = pnp::split_node_modules_path(package_path)
+      && self
+        .options()
+        .modules()
+        .any(|module| module == "node_modules")
+    {
+      return self.resolve_inner(modules_dir, &format!("{name}{subpath}{query}{fragment}"));
+    }
+    // Workspaces and portals are linked by their directories.
+    self.resolve_inner(package_path, &format!(".{subpath}{query}{fragment}"))
+  }
+
+  /// Paths inside of zip archives and virtual paths can only be read by [oxc_resolver].
+  fn ensure_readable(&self, path: &Path) -> Result<(), ResolveInnerError> {
+    if matches!(self.inner, ResolverInner::NodejsResolver(..)) && pnp::is_pnp_path(path) {
+      return Err(ResolveInnerError::Pnp(PnpError::LegacyResolver {
+        path: path.to_path_buf(),
+      }));
+    }
+    Ok(())
+  }
+
+  fn is_allowed(&self, result: &ResolveResult) -> bool {
+    match result {
+      ResolveResult::Resource(resource) => self
+        .restrictions
+        .iter()
+        .all(|restriction| restriction.is_match(&resource.path)),
+      ResolveResult::Ignored => true,
+    }
+  }
+
+  fn resolve_inner(&self, path: &Path, request: &str) -> Result<ResolveResult, ResolveInnerError> {
+    match &self.inner {
+      ResolverInner::NodejsResolver(resolver, _) => resolver
         .resolve(path, request)
         .map(|result| match result {
           nodejs_resolver::ResolveResult::Resource(r) => ResolveResult::Resource(Resource {
@@ -180,7 +332,7 @@ impl Resolver {
           nodejs_resolver::ResolveResult::Ignored => ResolveResult::Ignored,
         })
         .map_err(ResolveInnerError::NodejsResolver),
-      Self::OxcResolver(resolver) => match resolver.resolve(path, request) {
+      ResolverInner::OxcResolver(resolver) => match resolver.resolve(path, request) {
         Ok(r) => Ok(ResolveResult::Resource(Resource {
           path: r.path().to_path_buf(),
           query: r.query().map(ToString::to_string),
@@ -196,6 +348,12 @@ impl Resolver {
   }
 }
 
+fn roots_and_restrictions(options: &Resolve) -> (Vec<PathBuf>, Vec<Restriction>) {
+  let roots = options.roots.iter().flatten().map(PathBuf::from).collect();
+  let restrictions = options.restrictions.clone().unwrap_or_default();
+  (roots, restrictions)
+}
+
 impl ResolveInnerError {
   pub fn into_resolve_error(
     self,
@@ -205,6 +363,8 @@ impl ResolveInnerError {
     match self {
       Self::NodejsResolver(error) => map_nodejs_resolver_error(error, args, plugin_driver),
       Self::OxcResolver(error) => map_oxc_resolver_error(error, args, plugin_driver),
+      Self::Pnp(error) => map_pnp_error(error, args),
+      Self::Restricted => map_resolver_error(false, args, plugin_driver),
     }
   }
 }
@@ -213,9 +373,7 @@ fn to_nodejs_resolver_options(
   cache: Arc<nodejs_resolver::Cache>,
   options: Resolve,
   resolve_to_context: bool,
-  dependency_type: DependencyCategory,
 ) -> nodejs_resolver::Options {
-  let options = options.merge_by_dependency(dependency_type);
   let tsconfig = options.tsconfig.map(|c| c.config_file);
   let enforce_extension = nodejs_resolver::EnforceExtension::Auto;
   let external_cache = Some(cache);
@@ -275,9 +433,7 @@ fn to_nodejs_resolver_options(
 fn to_oxc_resolver_options(
   options: Resolve,
   resolve_to_context: bool,
-  dependency_type: DependencyCategory,
 ) -> oxc_resolver::ResolveOptions {
-  let options = options.merge_by_dependency(dependency_type);
   let tsconfig = options.tsconfig.map(|c| c.into());
   let enforce_extension = oxc_resolver::EnforceExtension::Auto;
   let description_files = vec!["package.json".to_string()];
@@ -364,6 +520,7 @@ fn to_oxc_resolver_options(
     extension_alias,
     // not supported by rspack yet
     prefer_absolute: false,
+    // handled by [Resolver::resolve] for both resolvers
     restrictions: vec![],
     roots: vec![],
     builtin_modules: false,
@@ -497,8 +654,7 @@ fn map_resolver_error(
 ) -> ResolveError {
   let base_dir: &Path = args.context.as_ref();
   let importer = args.importer.map(|i| i.as_str());
-  if let Some(importer) = &importer {
-    let span = args.span.unwrap_or_default();
+  if let Some(importer) = importer {
     // Use relative path in runtime for stable hashing
     let (runtime_message, internal_message) = if is_recursion {
       (
@@ -524,35 +680,7 @@ fn map_resolver_error(
         format!("Failed to resolve {} in {}", args.specifier, importer),
       )
     };
-    ResolveError(
-      runtime_message,
-      TraceableError::from_real_file_path(
-        Path::new(
-          importer
-            .split_once('|')
-            .map(|(_, path)| path)
-            .unwrap_or(importer),
-        ),
-        span.start as usize,
-        span.end as usize,
-        "Resolve error".to_string(),
-        internal_message.clone(),
-      )
-      .map(|e| {
-        if args.optional {
-          e.with_severity(Severity::Warn).into()
-        } else {
-          e.into()
-        }
-      })
-      .unwrap_or_else(|_| {
-        if args.optional {
-          InternalError::new(internal_message, Severity::Warn).into()
-        } else {
-          internal_error!(internal_message)
-        }
-      }),
-    )
+    traceable_resolve_error(importer, runtime_message, internal_message, args)
   } else {
     ResolveError(
       format!("Failed to resolve {} in project root", args.specifier),
@@ -560,3 +688,200 @@ fn map_resolver_error(
     )
   }
 }
+
+/// PnP errors explain which package is missing from which dependencies, they are reported
+/// at the request like the other resolve errors.
+fn map_pnp_error(error: PnpError, args: &ResolveArgs<'_>) -> ResolveError {
+  let message = error.to_string();
+  match args.importer {
+    Some(importer) => traceable_resolve_error(importer.as_str(), message.clone(), message, args),
+    None => ResolveError(message.clone(), internal_error!(message)),
+  }
+}
+
+fn traceable_resolve_error(
+  importer: &str,
+  runtime_message: String,
+  internal_message: String,
+  args: &ResolveArgs<'_>,
+) -> ResolveError {
+  let span = args.span.unwrap_or_default();
+  ResolveError(
+    runtime_message,
+    TraceableError::from_real_file_path(
+      Path::new(
+        importer
+          .split_once('|')
+          .map(|(_, path)| path)
+          .unwrap_or(importer),
+      ),
+      span.start as usize,
+      span.end as usize,
+      "Resolve error".to_string(),
+      internal_message.clone(),
+    )
+    .map(|e| {
+      if args.optional {
+        e.with_severity(Severity::Warn).into()
+      } else {
+        e.into()
+      }
+    })
+    .unwrap_or_else(|_| {
+      if args.optional {
+        InternalError::new(internal_message, Severity::Warn).into()
+      } else {
+        internal_error!(internal_message)
+      }
+    }),
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use std::io::Write;
+
+  use rspack_fs::{AsyncNativeFileSystem, MemoryFileSystem};
+
+  use super::*;
+
+  #[test]
+  fn resolve_files_of_input_filesystem() {
+    let fs = Arc::new(MemoryFileSystem::new());
+    fs.insert("/project/src/index.js", "");
+    fs.insert("/project/src/lib/index.ts", "");
+    fs.insert(
+      "/project/node_modules/pkg/package.json",
+      r#"{ "name": "pkg", "main": "./main.js" }"#,
+    );
+    fs.insert("/project/node_modules/pkg/main.js", "");
+    let resolver = Resolver::new(true, Resolve::default(), fs, Default::default());
+    let resolve = |request| match resolver.resolve(Path::new("/project/src"), request) {
+      Ok(ResolveResult::Resource(resource)) => Some((resource.path, resource.query)),
+      _ => None,
+    };
+
+    assert_eq!(
+      resolve("./index"),
+      Some((PathBuf::from("/project/src/index.js"), None))
+    );
+    assert_eq!(
+      resolve("./lib?raw"),
+      Some((
+        PathBuf::from("/project/src/lib/index.ts"),
+        Some("?raw".to_string())
+      ))
+    );
+    assert_eq!(
+      resolve("pkg"),
+      Some((PathBuf::from("/project/node_modules/pkg/main.js"), None))
+    );
+    assert_eq!(resolve("./missing"), None);
+  }
+
+  #[test]
+  fn resolve_virtual_files() {
+    let virtual_files = Arc::new(MemoryFileSystem::new());
+    virtual_files.insert("/virtual/routes.js", "");
+    virtual_files.insert(
+      "/virtual/node_modules/icons/package.json",
+      r#"{"name":"icons","main":"lib/index.js"}"#,
+    );
+    virtual_files.insert("/virtual/node_modules/icons/lib/index.js", "");
+    let resolver = Resolver::new(
+      true,
+      Resolve::default(),
+      Arc::new(AsyncNativeFileSystem),
+      virtual_files,
+    );
+
+    let Ok(ResolveResult::Resource(resource)) = resolver.resolve(Path::new("/virtual"), "./routes")
+    else {
+      panic!("should resolve the virtual file");
+    };
+    assert_eq!(resource.path, PathBuf::from("/virtual/routes.js"));
+
+    let Ok(ResolveResult::Resource(resource)) = resolver.resolve(Path::new("/virtual"), "icons")
+    else {
+      panic!("should resolve the virtual package");
+    };
+    assert_eq!(
+      resource.path,
+      PathBuf::from("/virtual/node_modules/icons/lib/index.js")
+    );
+    assert!(resource.description_data.is_some());
+  }
+
+  #[test]
+  fn resolve_packages_of_pnp_zip_archives() {
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, content) in [
+      (
+        "node_modules/pkg/package.json",
+        r#"{ "name": "pkg", "exports": { ".": "./main.js", "./feature": "./lib/feature.js" } }"#,
+      ),
+      ("node_modules/pkg/main.js", ""),
+      ("node_modules/pkg/lib/feature.js", ""),
+    ] {
+      zip
+        .start_file(name, Default::default())
+        .expect("should add the file");
+      zip
+        .write_all(content.as_bytes())
+        .expect("should write the file");
+    }
+    let archive = zip
+      .finish()
+      .expect("should finish the archive")
+      .into_inner();
+    let fs = Arc::new(MemoryFileSystem::new());
+    fs.insert("/project/.yarn/cache/pkg.zip", archive);
+    fs.insert(
+      "/project/.pnp.data.json",
+      r#"{
+        "dependencyTreeRoots": [{ "name": "app", "reference": "workspace:." }],
+        "packageRegistryData": [
+          [null, [[null, {
+            "packageLocation": "./",
+            "packageDependencies": [["pkg", "npm:1.0.0"], ["app", "workspace:."]]
+          }]]],
+          ["app", [["workspace:.", {
+            "packageLocation": "./",
+            "packageDependencies": [["pkg", "npm:1.0.0"]]
+          }]]],
+          ["pkg", [["npm:1.0.0", {
+            "packageLocation": "./.yarn/cache/pkg.zip/node_modules/pkg/",
+            "packageDependencies": [["pkg", "npm:1.0.0"]]
+          }]]]
+        ]
+      }"#,
+    );
+    let resolver = Resolver::new(true, Resolve::default(), fs.clone(), Default::default());
+    let resolve = |request| match resolver.resolve(Path::new("/project/src"), request) {
+      Ok(ResolveResult::Resource(resource)) => Ok(resource.path),
+      Ok(ResolveResult::Ignored) => panic!("should not be ignored"),
+      Err(error) => Err(error),
+    };
+
+    assert_eq!(
+      resolve("pkg").expect("should resolve the package"),
+      PathBuf::from("/project/.yarn/cache/pkg.zip/node_modules/pkg/main.js")
+    );
+    assert_eq!(
+      resolve("pkg/feature").expect("should resolve the exported subpath"),
+      PathBuf::from("/project/.yarn/cache/pkg.zip/node_modules/pkg/lib/feature.js")
+    );
+    assert!(matches!(
+      resolve("other"),
+      Err(ResolveInnerError::Pnp(
+        PnpError::UndeclaredDependency { .. }
+      ))
+    ));
+
+    let legacy_resolver = Resolver::new(false, Resolve::default(), fs, Default::default());
+    assert!(matches!(
+      legacy_resolver.resolve(Path::new("/project/src"), "pkg"),
+      Err(ResolveInnerError::Pnp(PnpError::LegacyResolver { .. }))
+    ));
+  }
+}
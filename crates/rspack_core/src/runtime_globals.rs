@@ -236,6 +236,11 @@ bitflags! {
     const CHUNK_NAME = 1 << 56;
 
     const RUNTIME_ID = 1 << 57;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 58;
   }
 }
 
@@ -314,6 +319,7 @@ impl RuntimeGlobals {
       R::RELATIVE_URL => "__webpack_require__.U",
       R::CHUNK_NAME => "__webpack_require__.cn",
       R::RUNTIME_ID => "__webpack_require__.j",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       r => panic!(
         "Unexpected flag `{r:?}`. RuntimeGlobals should only be printed for one single flag."
       ),
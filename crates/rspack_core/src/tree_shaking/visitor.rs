@@ -1104,6 +1104,28 @@ impl<'a> Visit for ModuleRefAnalyze<'a> {
     }
   }
   fn visit_call_expr(&mut self, node: &CallExpr) {
+    if self.worker_syntax_list.match_worker_call(node).is_some()
+      && let Some(ExprOrSpread {
+        spread: None,
+        expr: box Expr::New(new_url_expr),
+      }) = node.args.first()
+      && let Some((_, _, request)) = crate::needs_refactor::match_new_url(new_url_expr)
+    {
+      node.callee.visit_with(self);
+      let src = Part::Worker(request.into());
+      match self.current_body_owner_symbol_ext {
+        Some(ref body_owner_symbol_ext) => {
+          self.add_reference(body_owner_symbol_ext.clone(), src, false);
+        }
+        None => {
+          self.used_id_set.insert(src);
+        }
+      }
+      for arg in node.args.iter().skip(1) {
+        arg.visit_with(self);
+      }
+      return;
+    }
     if let Some(require_lit) = get_require_literal(node, self.unresolved_ctxt) {
       self.module_syntax.insert(ModuleSyntax::COMMONJS);
       match self.resolve_module_identifier(&require_lit, &DependencyType::CjsRequire) {
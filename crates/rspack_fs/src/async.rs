@@ -1,8 +1,8 @@
-use std::path::Path;
+use std::{fmt::Debug, path::Path};
 
 use futures::future::BoxFuture;
 
-use crate::Result;
+use crate::{FileMetadata, ReadableFileSystem, Result};
 
 pub trait AsyncWritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -35,7 +35,12 @@ pub trait AsyncReadableFileSystem {
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
-  fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>>;
+  fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>>;
+
+  /// Query the metadata of a file or a directory.
+  ///
+  /// Error: This function will return an error if path does not already exist.
+  fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>>;
 }
 
 /// Async readable and writable file system representation.
@@ -43,3 +48,15 @@ pub trait AsyncFileSystem: AsyncReadableFileSystem + AsyncWritableFileSystem {}
 
 // Blanket implementation for all types that implement both [`AsyncReadableFileSystem`] and [`WritableFileSystem`].
 impl<T: AsyncReadableFileSystem + AsyncWritableFileSystem> AsyncFileSystem for T {}
+
+/// The file system the sources are read from, i.e. webpack's `inputFileSystem`.
+///
+/// The resolver reads it synchronously, while `read_resource`, loaders and plugins read it asynchronously.
+pub trait InputFileSystem:
+  ReadableFileSystem + AsyncReadableFileSystem + Debug + Send + Sync
+{
+  /// Whether this is the disk itself, the resolvers read the disk directly in that case.
+  fn is_native(&self) -> bool {
+    false
+  }
+}
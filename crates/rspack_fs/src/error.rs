@@ -40,4 +40,12 @@ impl Display for Error {
   }
 }
 
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::Io(err) => Some(err),
+    }
+  }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
@@ -2,7 +2,7 @@ mod macros;
 
 cfg_async! {
   pub mod r#async;
-  pub use r#async::{AsyncFileSystem, AsyncReadableFileSystem, AsyncWritableFileSystem};
+  pub use r#async::{AsyncFileSystem, AsyncReadableFileSystem, AsyncWritableFileSystem, InputFileSystem};
 }
 pub mod sync;
 pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
@@ -10,6 +10,12 @@ pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 mod error;
 pub use error::{Error, Result};
 
+mod metadata;
+pub use metadata::FileMetadata;
+
+mod memory;
+pub use memory::{MemoryFileSystem, MemorySnapshot, SnapshotDiff};
+
 cfg_native! {
   mod native;
   pub use native::{NativeFileSystem};
@@ -0,0 +1,481 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  io,
+  path::{Path, PathBuf},
+  sync::{Arc, RwLock},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{cfg_async, sync::ReadableFileSystem, Error, FileMetadata, Result};
+
+#[derive(Debug, Clone)]
+enum Entry {
+  File { content: Arc<[u8]>, mtime_ms: u64 },
+  Directory { mtime_ms: u64 },
+}
+
+impl Entry {
+  fn metadata(&self) -> FileMetadata {
+    match self {
+      Entry::File { content, mtime_ms } => FileMetadata {
+        is_file: true,
+        is_directory: false,
+        size: content.len() as u64,
+        mtime_ms: *mtime_ms,
+      },
+      Entry::Directory { mtime_ms } => FileMetadata {
+        is_file: false,
+        is_directory: true,
+        size: 0,
+        mtime_ms: *mtime_ms,
+      },
+    }
+  }
+}
+
+fn now_ms() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+/// Drop `.` components and trailing separators, so `/a/./b/` and `/a/b` are the same entry.
+fn normalize(path: &Path) -> PathBuf {
+  path.components().collect()
+}
+
+fn io_error(kind: io::ErrorKind, message: &str, path: &Path) -> Error {
+  Error::Io(io::Error::new(
+    kind,
+    format!("{message}: {}", path.display()),
+  ))
+}
+
+fn not_found(path: &Path) -> Error {
+  io_error(io::ErrorKind::NotFound, "no such file or directory", path)
+}
+
+/// A thread-safe file system kept entirely in memory, e.g. for virtual files, test fixtures
+/// and serving the emitted assets without touching the disk.
+///
+/// Paths are expected to be absolute, the root directory always exists.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+  entries: RwLock<HashMap<PathBuf, Entry>>,
+}
+
+impl MemoryFileSystem {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Create or replace a file along with its missing parent directories.
+  pub fn insert<P: AsRef<Path>, D: Into<Vec<u8>>>(&self, file: P, data: D) {
+    let file = normalize(file.as_ref());
+    let mtime_ms = now_ms();
+    let mut entries = self.entries.write().expect("should lock the entries");
+    for dir in file.ancestors().skip(1) {
+      entries
+        .entry(dir.to_path_buf())
+        .or_insert(Entry::Directory { mtime_ms });
+    }
+    entries.insert(
+      file,
+      Entry::File {
+        content: data.into().into(),
+        mtime_ms,
+      },
+    );
+  }
+
+  /// Whether there are no files or directories at all.
+  pub fn is_empty(&self) -> bool {
+    self
+      .entries
+      .read()
+      .expect("should lock the entries")
+      .is_empty()
+  }
+
+  /// Remove a file, returns whether the file existed.
+  pub fn remove<P: AsRef<Path>>(&self, file: P) -> bool {
+    self.remove_file_entry(file.as_ref()).is_ok()
+  }
+
+  /// Query the metadata of a file or a directory.
+  pub fn stat<P: AsRef<Path>>(&self, path: P) -> Result<FileMetadata> {
+    self.entry(path.as_ref()).map(|entry| entry.metadata())
+  }
+
+  /// The names of the entries in a directory, sorted.
+  pub fn read_dir<P: AsRef<Path>>(&self, dir: P) -> Result<Vec<String>> {
+    let dir = normalize(dir.as_ref());
+    if !self.entry(&dir)?.metadata().is_directory {
+      return Err(io_error(
+        io::ErrorKind::InvalidInput,
+        "not a directory",
+        &dir,
+      ));
+    }
+    let entries = self.entries.read().expect("should lock the entries");
+    let mut names = entries
+      .keys()
+      .filter(|path| path.parent() == Some(dir.as_path()))
+      .filter_map(|path| path.file_name())
+      .map(|name| name.to_string_lossy().to_string())
+      .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+  }
+
+  /// Take a snapshot of the files to compare with later states, see [MemoryFileSystem::diff].
+  pub fn snapshot(&self) -> MemorySnapshot {
+    let entries = self.entries.read().expect("should lock the entries");
+    let files = entries
+      .iter()
+      .filter_map(|(path, entry)| match entry {
+        Entry::File { content, .. } => Some((path.clone(), content.clone())),
+        Entry::Directory { .. } => None,
+      })
+      .collect();
+    MemorySnapshot { files }
+  }
+
+  /// The files added, modified and removed since `snapshot` was taken.
+  pub fn diff(&self, snapshot: &MemorySnapshot) -> SnapshotDiff {
+    snapshot.diff(&self.snapshot())
+  }
+
+  fn entry(&self, path: &Path) -> Result<Entry> {
+    let path = normalize(path);
+    if path.parent().is_none() {
+      return Ok(Entry::Directory { mtime_ms: 0 });
+    }
+    self
+      .entries
+      .read()
+      .expect("should lock the entries")
+      .get(&path)
+      .cloned()
+      .ok_or_else(|| not_found(&path))
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self
+      .entry(path)
+      .is_ok_and(|entry| matches!(entry, Entry::Directory { .. }))
+  }
+
+  fn read_file(&self, file: &Path) -> Result<Vec<u8>> {
+    match self.entry(file)? {
+      Entry::File { content, .. } => Ok(content.to_vec()),
+      Entry::Directory { .. } => Err(io_error(
+        io::ErrorKind::InvalidInput,
+        "is a directory",
+        file,
+      )),
+    }
+  }
+
+  fn make_dir(&self, dir: &Path, recursive: bool) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.write().expect("should lock the entries");
+    let dirs = if recursive {
+      dir.ancestors().collect::<Vec<_>>()
+    } else {
+      vec![dir.as_path()]
+    };
+    let mut missing = vec![];
+    for dir in dirs {
+      match entries.get(dir) {
+        Some(Entry::Directory { .. }) if recursive => {}
+        Some(_) => return Err(io_error(io::ErrorKind::AlreadyExists, "file exists", dir)),
+        None if dir.parent().is_none() => {}
+        None => missing.push(dir.to_path_buf()),
+      }
+    }
+    if !recursive {
+      let parent = dir.parent().filter(|parent| parent.parent().is_some());
+      if let Some(parent) = parent {
+        if !matches!(entries.get(parent), Some(Entry::Directory { .. })) {
+          return Err(not_found(parent));
+        }
+      }
+    }
+    let mtime_ms = now_ms();
+    for dir in missing {
+      entries.insert(dir, Entry::Directory { mtime_ms });
+    }
+    Ok(())
+  }
+
+  fn write_file(&self, file: &Path, data: Vec<u8>) -> Result<()> {
+    let file = normalize(file);
+    if let Some(parent) = file.parent() {
+      if !self.is_dir(parent) {
+        return Err(not_found(parent));
+      }
+    }
+    let mut entries = self.entries.write().expect("should lock the entries");
+    if let Some(Entry::Directory { .. }) = entries.get(&file) {
+      return Err(io_error(
+        io::ErrorKind::InvalidInput,
+        "is a directory",
+        &file,
+      ));
+    }
+    entries.insert(
+      file,
+      Entry::File {
+        content: data.into(),
+        mtime_ms: now_ms(),
+      },
+    );
+    Ok(())
+  }
+
+  fn remove_file_entry(&self, file: &Path) -> Result<()> {
+    let file = normalize(file);
+    let mut entries = self.entries.write().expect("should lock the entries");
+    match entries.get(&file) {
+      Some(Entry::File { .. }) => {
+        entries.remove(&file);
+        Ok(())
+      }
+      Some(Entry::Directory { .. }) => Err(io_error(
+        io::ErrorKind::InvalidInput,
+        "is a directory",
+        &file,
+      )),
+      None => Err(not_found(&file)),
+    }
+  }
+
+  /// Unlike [std::fs::remove_dir_all], a missing directory is not an error, so `output.clean`
+  /// works for the first build.
+  fn remove_dir_entries(&self, dir: &Path) -> Result<()> {
+    let dir = normalize(dir);
+    let mut entries = self.entries.write().expect("should lock the entries");
+    if let Some(Entry::File { .. }) = entries.get(&dir) {
+      return Err(io_error(
+        io::ErrorKind::InvalidInput,
+        "not a directory",
+        &dir,
+      ));
+    }
+    entries.retain(|path, _| !path.starts_with(&dir));
+    Ok(())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    self.read_file(file)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    self.stat(path)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    MemoryFileSystem::read_dir(self, dir)
+  }
+}
+
+cfg_async! {
+  use futures::future::BoxFuture;
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem, InputFileSystem};
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let result = self.read_file(file);
+      Box::pin(async move { result })
+    }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let result = self.stat(path);
+      Box::pin(async move { result })
+    }
+  }
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.make_dir(dir.as_ref(), false);
+      Box::pin(async move { result })
+    }
+
+    fn create_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.make_dir(dir.as_ref(), true);
+      Box::pin(async move { result })
+    }
+
+    fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> BoxFuture<'_, Result<()>> {
+      let result = self.write_file(file.as_ref(), data.as_ref().to_vec());
+      Box::pin(async move { result })
+    }
+
+    fn remove_file<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.remove_file_entry(file.as_ref());
+      Box::pin(async move { result })
+    }
+
+    fn remove_dir_all<P: AsRef<Path>>(&self, dir: P) -> BoxFuture<'_, Result<()>> {
+      let result = self.remove_dir_entries(dir.as_ref());
+      Box::pin(async move { result })
+    }
+  }
+
+  impl InputFileSystem for MemoryFileSystem {}
+}
+
+/// The files of a [MemoryFileSystem] at some point, see [MemoryFileSystem::snapshot].
+#[derive(Debug, Clone, Default)]
+pub struct MemorySnapshot {
+  files: BTreeMap<PathBuf, Arc<[u8]>>,
+}
+
+impl MemorySnapshot {
+  /// The files and their contents, sorted by path.
+  pub fn files(&self) -> impl Iterator<Item = (&Path, &[u8])> {
+    self
+      .files
+      .iter()
+      .map(|(path, content)| (path.as_path(), content.as_ref()))
+  }
+
+  pub fn get<P: AsRef<Path>>(&self, file: P) -> Option<&[u8]> {
+    self
+      .files
+      .get(&normalize(file.as_ref()))
+      .map(|content| content.as_ref())
+  }
+
+  /// The changes from this snapshot to `other`.
+  pub fn diff(&self, other: &MemorySnapshot) -> SnapshotDiff {
+    let mut diff = SnapshotDiff::default();
+    for (path, content) in &other.files {
+      match self.files.get(path) {
+        None => diff.added.push(path.clone()),
+        Some(old) if !Arc::ptr_eq(old, content) && old != content => {
+          diff.modified.push(path.clone())
+        }
+        Some(_) => {}
+      }
+    }
+    diff.removed = self
+      .files
+      .keys()
+      .filter(|path| !other.files.contains_key(*path))
+      .cloned()
+      .collect();
+    diff
+  }
+}
+
+/// Changes between two [MemorySnapshot]s, each list is sorted by path.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SnapshotDiff {
+  pub added: Vec<PathBuf>,
+  pub modified: Vec<PathBuf>,
+  pub removed: Vec<PathBuf>,
+}
+
+impl SnapshotDiff {
+  pub fn is_empty(&self) -> bool {
+    self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn should_read_inserted_files() {
+    let fs = MemoryFileSystem::new();
+    fs.insert("/project/src/index.js", "export default 1;");
+
+    assert_eq!(
+      ReadableFileSystem::read(&fs, Path::new("/project/src/./index.js"))
+        .expect("should read the file"),
+      b"export default 1;"
+    );
+    let metadata = fs
+      .stat("/project/src/")
+      .expect("should create the parent directories");
+    assert!(metadata.is_directory);
+    assert!(ReadableFileSystem::read(&fs, Path::new("/project/src")).is_err());
+
+    assert!(fs.remove("/project/src/index.js"));
+    assert!(fs.stat("/project/src/index.js").is_err());
+  }
+
+  #[test]
+  fn should_list_directories() {
+    let fs = MemoryFileSystem::new();
+    fs.insert("/dist/main.js", "");
+    fs.insert("/dist/assets/logo.png", "");
+
+    assert_eq!(
+      fs.read_dir("/dist").expect("should list the directory"),
+      vec!["assets".to_string(), "main.js".to_string()]
+    );
+    assert_eq!(
+      fs.read_dir("/").expect("should list the root"),
+      vec!["dist".to_string()]
+    );
+    assert!(fs.read_dir("/dist/main.js").is_err());
+    assert!(fs.read_dir("/missing").is_err());
+  }
+
+  #[test]
+  fn should_diff_snapshots() {
+    let fs = MemoryFileSystem::new();
+    fs.insert("/dist/main.js", "1");
+    fs.insert("/dist/a.js", "a");
+    fs.insert("/dist/b.js", "b");
+    let snapshot = fs.snapshot();
+    assert!(fs.diff(&snapshot).is_empty());
+
+    fs.insert("/dist/main.js", "2");
+    fs.insert("/dist/a.js", "a");
+    fs.insert("/dist/c.js", "c");
+    fs.remove("/dist/b.js");
+    assert_eq!(
+      fs.diff(&snapshot),
+      SnapshotDiff {
+        added: vec![PathBuf::from("/dist/c.js")],
+        modified: vec![PathBuf::from("/dist/main.js")],
+        removed: vec![PathBuf::from("/dist/b.js")],
+      }
+    );
+    assert_eq!(snapshot.get("/dist/main.js"), Some(&b"1"[..]));
+  }
+
+  #[cfg(feature = "async")]
+  #[test]
+  fn should_write_like_the_disk() {
+    use futures::executor::block_on;
+
+    use crate::AsyncWritableFileSystem;
+
+    let fs = MemoryFileSystem::new();
+    assert!(block_on(fs.write("/dist/main.js", "")).is_err());
+    assert!(block_on(fs.create_dir("/dist/js")).is_err());
+
+    block_on(fs.create_dir_all("/dist/js")).expect("should create the directories");
+    block_on(fs.write("/dist/js/main.js", "main")).expect("should write the file");
+    assert_eq!(fs.stat("/dist/js/main.js").map(|m| m.size).ok(), Some(4));
+    assert!(block_on(fs.create_dir("/dist/js")).is_err());
+    assert!(block_on(fs.write("/dist/js", "")).is_err());
+
+    block_on(fs.remove_file("/dist/js/main.js")).expect("should remove the file");
+    assert!(block_on(fs.remove_file("/dist/js/main.js")).is_err());
+
+    block_on(fs.remove_dir_all("/dist")).expect("should remove the directory");
+    assert!(fs.stat("/dist/js").is_err());
+    block_on(fs.remove_dir_all("/dist")).expect("should ignore missing directories");
+  }
+}
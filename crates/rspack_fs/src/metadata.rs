@@ -0,0 +1,29 @@
+use std::{fs::Metadata, time::UNIX_EPOCH};
+
+/// Metadata of a file or a directory, symlinks are followed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileMetadata {
+  pub is_file: bool,
+  pub is_directory: bool,
+  /// Size of the file in bytes, `0` for directories.
+  pub size: u64,
+  /// Last modification time in milliseconds since the unix epoch.
+  pub mtime_ms: u64,
+}
+
+impl From<Metadata> for FileMetadata {
+  fn from(metadata: Metadata) -> Self {
+    let mtime_ms = metadata
+      .modified()
+      .ok()
+      .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+      .map(|duration| duration.as_millis() as u64)
+      .unwrap_or_default();
+    Self {
+      is_file: metadata.is_file(),
+      is_directory: metadata.is_dir(),
+      size: metadata.len(),
+      mtime_ms,
+    }
+  }
+}
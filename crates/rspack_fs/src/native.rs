@@ -3,9 +3,10 @@ use std::{fs, path::Path};
 use super::{
   cfg_async,
   sync::{ReadableFileSystem, WritableFileSystem},
-  Error, Result,
+  Error, FileMetadata, Result,
 };
 
+#[derive(Debug)]
 pub struct NativeFileSystem;
 
 impl WritableFileSystem for NativeFileSystem {
@@ -23,15 +24,31 @@ impl WritableFileSystem for NativeFileSystem {
 }
 
 impl ReadableFileSystem for NativeFileSystem {
-  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>> {
-    fs::read(file.as_ref()).map_err(Error::from)
+  fn read(&self, file: &Path) -> Result<Vec<u8>> {
+    fs::read(file).map_err(Error::from)
+  }
+
+  fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+    fs::metadata(path)
+      .map(FileMetadata::from)
+      .map_err(Error::from)
+  }
+
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+    let mut names = fs::read_dir(dir)?
+      .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().to_string()))
+      .collect::<std::io::Result<Vec<_>>>()?;
+    names.sort();
+    Ok(names)
   }
 }
 
 cfg_async! {
   use futures::future::BoxFuture;
 
-  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem, InputFileSystem};
+
+  #[derive(Debug)]
   pub struct AsyncNativeFileSystem;
 
   impl AsyncWritableFileSystem for AsyncNativeFileSystem {
@@ -72,10 +89,43 @@ cfg_async! {
   }
 
   impl AsyncReadableFileSystem for AsyncNativeFileSystem {
-    fn read<P: AsRef<Path>>(&self, file: P) -> BoxFuture<'_, Result<Vec<u8>>> {
-      let file = file.as_ref().to_string_lossy().to_string();
+    fn read(&self, file: &Path) -> BoxFuture<'_, Result<Vec<u8>>> {
+      let file = file.to_path_buf();
       let fut = async move { tokio::fs::read(file).await.map_err(Error::from) };
       Box::pin(fut)
     }
+
+    fn metadata(&self, path: &Path) -> BoxFuture<'_, Result<FileMetadata>> {
+      let path = path.to_path_buf();
+      let fut = async move {
+        tokio::fs::metadata(path)
+          .await
+          .map(FileMetadata::from)
+          .map_err(Error::from)
+      };
+      Box::pin(fut)
+    }
+  }
+
+  /// The resolver reads synchronously, so the native input file system reads the disk
+  /// synchronously as well.
+  impl ReadableFileSystem for AsyncNativeFileSystem {
+    fn read(&self, file: &Path) -> Result<Vec<u8>> {
+      NativeFileSystem.read(file)
+    }
+
+    fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+      NativeFileSystem.metadata(path)
+    }
+
+    fn read_dir(&self, dir: &Path) -> Result<Vec<String>> {
+      NativeFileSystem.read_dir(dir)
+    }
+  }
+
+  impl InputFileSystem for AsyncNativeFileSystem {
+    fn is_native(&self) -> bool {
+      true
+    }
   }
 }
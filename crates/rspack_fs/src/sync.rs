@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use super::Result;
+use super::{FileMetadata, Result};
 
 pub trait WritableFileSystem {
   /// Creates a new, empty directory at the provided path.
@@ -23,11 +23,25 @@ pub trait WritableFileSystem {
   fn write<P: AsRef<Path>, D: AsRef<[u8]>>(&self, file: P, data: D) -> Result<()>;
 }
 
+/// Unlike [WritableFileSystem], the methods take `&Path` instead of `P: AsRef<Path>`, which
+/// keeps the trait object safe for `dyn InputFileSystem`. This is a breaking change of `read`,
+/// which used to take `P: AsRef<Path>`, callers with a `String` or a `PathBuf` have to borrow
+/// it as a `&Path` now.
 pub trait ReadableFileSystem {
   /// Read the entire contents of a file into a bytes vector.
   ///
   /// Error: This function will return an error if path does not already exist.
-  fn read<P: AsRef<Path>>(&self, file: P) -> Result<Vec<u8>>;
+  fn read(&self, file: &Path) -> Result<Vec<u8>>;
+
+  /// Query the metadata of a file or a directory.
+  ///
+  /// Error: This function will return an error if path does not already exist.
+  fn metadata(&self, path: &Path) -> Result<FileMetadata>;
+
+  /// The names of the entries in a directory, sorted.
+  ///
+  /// Error: This function will return an error if path is not a directory.
+  fn read_dir(&self, dir: &Path) -> Result<Vec<String>>;
 }
 
 /// Readable and writable file system representation.
@@ -3,7 +3,7 @@ use std::{
   hash::{Hash, Hasher},
 };
 
-use data_encoding::HEXLOWER_PERMISSIVE;
+use data_encoding::{BASE64, HEXLOWER_PERMISSIVE};
 use md4::Digest;
 use smol_str::SmolStr;
 use xxhash_rust::xxh3;
@@ -27,12 +27,14 @@ impl From<&str> for HashFunction {
 #[derive(Debug, Clone, Copy)]
 pub enum HashDigest {
   Hex,
+  Base64,
 }
 
 impl From<&str> for HashDigest {
   fn from(value: &str) -> Self {
     match value {
       "hex" => HashDigest::Hex,
+      "base64" => HashDigest::Base64,
       _ => unimplemented!(),
     }
   }
@@ -128,6 +130,7 @@ impl RspackHashDigest {
   pub fn new(inner: Vec<u8>, digest: &HashDigest) -> Self {
     let encoded = match digest {
       HashDigest::Hex => HEXLOWER_PERMISSIVE.encode(&inner).into(),
+      HashDigest::Base64 => BASE64.encode(&inner).into(),
     };
     Self { encoded }
   }
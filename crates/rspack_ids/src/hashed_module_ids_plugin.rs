@@ -0,0 +1,161 @@
+use std::{collections::HashSet, hash::Hasher};
+
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+use crate::id_helpers::{
+  compare_chunks_natural, compare_modules_by_pre_order_index_or_identifier, get_full_chunk_name,
+  get_full_module_name, get_used_chunk_ids, get_used_module_ids_and_modules,
+};
+
+#[derive(Debug, Clone)]
+pub struct HashedIdsPluginOptions {
+  /// The directory the names of the modules and the chunks are relative to, defaults to the
+  /// context of the compilation.
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  /// The minimum length of the ids, they get longer on conflicts.
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::MD4,
+      hash_digest: HashDigest::Base64,
+      hash_digest_length: 4,
+    }
+  }
+}
+
+impl HashedIdsPluginOptions {
+  fn hash(&self, name: &str) -> String {
+    let mut hasher = RspackHash::new(&self.hash_function);
+    hasher.write(name.as_bytes());
+    hasher.digest(&self.hash_digest).encoded().to_string()
+  }
+
+  /// The shortest prefix of `hash` not in `used_ids`.
+  fn unused_id(&self, hash: String, used_ids: &HashSet<String>) -> String {
+    let mut len = self.hash_digest_length.min(hash.len());
+    while len < hash.len() && used_ids.contains(&hash[..len]) {
+      len += 1;
+    }
+    hash[..len].to_string()
+  }
+}
+
+/// Assigns the hash of the relative path of a module as its id, which stays the same as long as
+/// the module is not moved.
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedIdsPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedModuleIdsPlugin"
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/HashedModuleIdsPlugin.js
+    let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &mut compilation.chunk_graph;
+    let context = self
+      .options
+      .context
+      .as_deref()
+      .unwrap_or_else(|| compilation.options.context.as_str());
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      compare_modules_by_pre_order_index_or_identifier(module_graph, a, b)
+    });
+    for module in modules {
+      let hash = self.options.hash(&get_full_module_name(module, context));
+      let id = self.options.unused_id(hash, &used_ids);
+      chunk_graph.set_module_id(module.identifier(), id.clone());
+      used_ids.insert(id);
+    }
+    Ok(())
+  }
+}
+
+/// Assigns the hash of the name of a chunk, or the relative paths of its modules, as its id, in
+/// the URL and filename safe alphabet of base64.
+#[derive(Debug, Default)]
+pub struct HashedChunkIdsPlugin {
+  options: HashedIdsPluginOptions,
+}
+
+impl HashedChunkIdsPlugin {
+  pub fn new(options: HashedIdsPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl Plugin for HashedChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HashedChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    let mut used_ids = get_used_chunk_ids(compilation);
+
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let context = self
+      .options
+      .context
+      .as_deref()
+      .unwrap_or_else(|| compilation.options.context.as_str());
+
+    let mut chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.id.is_none())
+      .collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| compare_chunks_natural(chunk_graph, module_graph, a, b));
+    let chunk_ids = chunks
+      .into_iter()
+      .map(|chunk| {
+        let hash = self.options.hash(&get_full_chunk_name(
+          chunk,
+          chunk_graph,
+          module_graph,
+          context,
+        ));
+        // the chunk ids are used in the filenames, where the `/` of base64 would be a directory
+        let id = self
+          .options
+          .unused_id(hash.replace('+', "-").replace('/', "_"), &used_ids);
+        used_ids.insert(id.clone());
+        (chunk.ukey, id)
+      })
+      .collect::<Vec<_>>();
+
+    for (chunk_ukey, id) in chunk_ids {
+      let chunk = compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("Chunk should exists");
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id];
+    }
+    Ok(())
+  }
+}
@@ -30,14 +30,11 @@ pub fn get_used_module_ids_and_modules(
 ) -> (HashSet<String>, Vec<ModuleIdentifier>) {
   let chunk_graph = &compilation.chunk_graph;
   let mut modules = vec![];
-  let mut used_ids = HashSet::new();
-
-  // TODO: currently we don't have logic of compilation.usedModuleIds
-  //   if (compilation.usedModuleIds) {
-  //     for (const id of compilation.usedModuleIds) {
-  //         usedIds.add(id + "");
-  //     }
-  //   }
+  let mut used_ids = compilation
+    .used_module_ids
+    .iter()
+    .cloned()
+    .collect::<HashSet<_>>();
 
   compilation
     .module_graph
@@ -10,3 +10,17 @@ mod stable_named_chunk_ids_plugin;
 pub use stable_named_chunk_ids_plugin::StableNamedChunkIdsPlugin;
 mod deterministic_chunk_ids_plugin;
 pub use deterministic_chunk_ids_plugin::DeterministicChunkIdsPlugin;
+mod natural_module_ids_plugin;
+pub use natural_module_ids_plugin::NaturalModuleIdsPlugin;
+mod natural_chunk_ids_plugin;
+pub use natural_chunk_ids_plugin::NaturalChunkIdsPlugin;
+mod occurrence_module_ids_plugin;
+pub use occurrence_module_ids_plugin::OccurrenceModuleIdsPlugin;
+mod occurrence_chunk_ids_plugin;
+pub use occurrence_chunk_ids_plugin::OccurrenceChunkIdsPlugin;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::{
+  HashedChunkIdsPlugin, HashedIdsPluginOptions, HashedModuleIdsPlugin,
+};
+mod record_ids_plugin;
+pub use record_ids_plugin::{ChunkRecords, ModuleRecords, RecordIdsPlugin, Records};
@@ -0,0 +1,28 @@
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+#[derive(Debug, Default)]
+pub struct NaturalChunkIdsPlugin;
+
+impl Plugin for NaturalChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.NaturalChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/NaturalChunkIdsPlugin.js
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| compare_chunks_natural(chunk_graph, module_graph, a, b));
+    let chunks = chunks
+      .into_iter()
+      .map(|chunk| chunk.ukey)
+      .collect::<Vec<_>>();
+
+    assign_ascending_chunk_ids(&chunks, compilation);
+    Ok(())
+  }
+}
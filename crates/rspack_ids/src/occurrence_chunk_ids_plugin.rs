@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+/// Assigns short ascending chunk ids, the chunks in the most chunk groups get the shortest ids.
+/// Used by `optimization.chunkIds: "size"`.
+#[derive(Debug, Default)]
+pub struct OccurrenceChunkIdsPlugin {
+  /// Order the chunks loaded by the initial chunk groups first.
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceChunkIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/OccurrenceChunkIdsPlugin.js
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+    let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
+
+    let occurs_in_initial_chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .map(|chunk| {
+        let occurs = chunk
+          .groups
+          .iter()
+          .filter_map(|group| chunk_group_by_ukey.get(group))
+          .flat_map(|group| group.parents_iterable())
+          .filter_map(|parent| chunk_group_by_ukey.get(parent))
+          .filter(|parent| parent.is_initial())
+          .count();
+        (chunk.ukey, occurs)
+      })
+      .collect::<HashMap<_, _>>();
+
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| {
+      let ordering = if self.prioritise_initial {
+        occurs_in_initial_chunks[&b.ukey].cmp(&occurs_in_initial_chunks[&a.ukey])
+      } else {
+        Ordering::Equal
+      };
+      ordering
+        .then_with(|| b.groups.len().cmp(&a.groups.len()))
+        .then_with(|| compare_chunks_natural(chunk_graph, module_graph, a, b))
+    });
+    let chunks = chunks
+      .into_iter()
+      .map(|chunk| chunk.ukey)
+      .collect::<Vec<_>>();
+
+    assign_ascending_chunk_ids(&chunks, compilation);
+    Ok(())
+  }
+}
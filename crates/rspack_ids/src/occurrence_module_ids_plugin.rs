@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+
+use rspack_core::{ChunkGraph, Compilation, ModuleGraph, ModuleIdentifier, Plugin};
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules,
+};
+
+/// Assigns short ascending module ids, the modules occurring in the most chunks get the shortest
+/// ids. Used by `optimization.moduleIds: "size"`.
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPlugin {
+  /// Order the modules occurring in the initial chunks first.
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceModuleIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.OccurrenceModuleIdsPlugin"
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/OccurrenceModuleIdsPlugin.js
+    let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+
+    let mut initial_chunk_counts: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    let mut entry_counts: HashMap<ModuleIdentifier, usize> = HashMap::default();
+    for module in module_graph.modules().keys() {
+      let mut initial = 0;
+      let mut entry = 0;
+      for chunk_ukey in chunk_graph.get_module_chunks(*module) {
+        let Some(chunk) = compilation.chunk_by_ukey.get(chunk_ukey) else {
+          continue;
+        };
+        if chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+          initial += 1;
+        }
+        if chunk_graph
+          .get_chunk_entry_modules(chunk_ukey)
+          .contains(module)
+        {
+          entry += 1;
+        }
+      }
+      initial_chunk_counts.insert(*module, initial);
+      entry_counts.insert(*module, entry);
+    }
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+
+    let mut occurs_in_initial_chunks = HashMap::default();
+    let mut occurs_in_all_chunks = HashMap::default();
+    for module in &modules {
+      let identifier = module.identifier();
+      let entry = entry_counts.get(&identifier).copied().unwrap_or_default();
+      let origins = active_origin_modules(module_graph, &identifier);
+      let occurs_in_entry = origins
+        .iter()
+        .map(|(origin, _)| {
+          initial_chunk_counts
+            .get(origin)
+            .copied()
+            .unwrap_or_default()
+        })
+        .sum::<usize>();
+      occurs_in_initial_chunks.insert(
+        identifier,
+        initial_chunk_counts
+          .get(&identifier)
+          .copied()
+          .unwrap_or_default()
+          + entry
+          + occurs_in_entry,
+      );
+      occurs_in_all_chunks.insert(
+        identifier,
+        chunk_graph.get_number_of_module_chunks(identifier)
+          + entry
+          + count_occurs(chunk_graph, &origins),
+      );
+    }
+
+    modules.sort_unstable_by(|a, b| {
+      let (a_identifier, b_identifier) = (a.identifier(), b.identifier());
+      let ordering = if self.prioritise_initial {
+        occurs_in_initial_chunks[&b_identifier].cmp(&occurs_in_initial_chunks[&a_identifier])
+      } else {
+        Ordering::Equal
+      };
+      ordering
+        .then_with(|| occurs_in_all_chunks[&b_identifier].cmp(&occurs_in_all_chunks[&a_identifier]))
+        .then_with(|| compare_modules_by_pre_order_index_or_identifier(module_graph, a, b))
+    });
+
+    assign_ascending_module_ids(&used_ids, modules, &mut compilation.chunk_graph);
+    Ok(())
+  }
+}
+
+/// The modules referencing `module` by active connections, with the number of the connections.
+fn active_origin_modules(
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+) -> Vec<(ModuleIdentifier, usize)> {
+  let Some(module) = module_graph.module_by_identifier(module) else {
+    return Vec::new();
+  };
+  let mut origins: HashMap<ModuleIdentifier, usize> = HashMap::default();
+  for connection in module_graph.get_incoming_connections(module) {
+    let Some(origin) = connection.original_module_identifier else {
+      continue;
+    };
+    if connection.is_target_active(module_graph, None) {
+      *origins.entry(origin).or_default() += 1;
+    }
+  }
+  origins.into_iter().collect()
+}
+
+fn count_occurs(chunk_graph: &ChunkGraph, origins: &[(ModuleIdentifier, usize)]) -> usize {
+  origins
+    .iter()
+    .map(|(origin, connections)| connections * chunk_graph.get_number_of_module_chunks(*origin))
+    .sum()
+}
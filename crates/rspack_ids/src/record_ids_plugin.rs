@@ -0,0 +1,256 @@
+use std::{
+  collections::{BTreeMap, BTreeSet, HashSet},
+  io::ErrorKind,
+  path::{Path, PathBuf},
+  sync::Mutex,
+};
+
+use rspack_core::{
+  Compilation, CompilationParams, Plugin, PluginThisCompilationHookOutput, ThisCompilationArgs,
+};
+use rspack_error::{internal_error, Result};
+use rspack_fs::AsyncReadableFileSystem;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::id_helpers::get_full_module_name;
+
+/// The module and chunk ids of a build, keyed by the relative identifiers of the modules and the
+/// names of the chunks.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Records {
+  #[serde(default)]
+  pub modules: ModuleRecords,
+  #[serde(default)]
+  pub chunks: ChunkRecords,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  #[serde(default, deserialize_with = "deserialize_id_map")]
+  pub by_identifier: BTreeMap<String, String>,
+  #[serde(default, deserialize_with = "deserialize_id_set")]
+  pub used_ids: BTreeSet<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  #[serde(default, deserialize_with = "deserialize_id_map")]
+  pub by_name: BTreeMap<String, String>,
+  #[serde(default, deserialize_with = "deserialize_id_set")]
+  pub used_ids: BTreeSet<String>,
+}
+
+/// An id in the records, which are numbers in the ones written by webpack.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RecordId {
+  String(String),
+  Number(serde_json::Number),
+}
+
+impl From<RecordId> for String {
+  fn from(value: RecordId) -> Self {
+    match value {
+      RecordId::String(id) => id,
+      RecordId::Number(id) => id.to_string(),
+    }
+  }
+}
+
+fn deserialize_id_map<'de, D>(
+  deserializer: D,
+) -> std::result::Result<BTreeMap<String, String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let ids = BTreeMap::<String, RecordId>::deserialize(deserializer)?;
+  Ok(ids.into_iter().map(|(key, id)| (key, id.into())).collect())
+}
+
+fn deserialize_id_set<'de, D>(deserializer: D) -> std::result::Result<BTreeSet<String>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let ids = Vec::<RecordId>::deserialize(deserializer)?;
+  Ok(ids.into_iter().map(Into::into).collect())
+}
+
+/// Reuses the module and chunk ids recorded by the previous build in `records_input_path`, and
+/// records the ids of this build to `records_output_path`, so that adding a module does not
+/// change the ids of the existing ones.
+///
+/// It needs to be applied before the plugins assigning the ids.
+#[derive(Debug, Default)]
+pub struct RecordIdsPlugin {
+  pub records_input_path: Option<PathBuf>,
+  pub records_output_path: Option<PathBuf>,
+  /// The records read for the current compilation.
+  records: Mutex<Records>,
+}
+
+impl RecordIdsPlugin {
+  pub fn new(records_input_path: Option<PathBuf>, records_output_path: Option<PathBuf>) -> Self {
+    Self {
+      records_input_path,
+      records_output_path,
+      records: Default::default(),
+    }
+  }
+
+  async fn read_records(&self, compilation: &Compilation) -> Result<Records> {
+    let Some(path) = &self.records_input_path else {
+      return Ok(Records::default());
+    };
+    let content = match AsyncReadableFileSystem::read(&*compilation.input_filesystem, path).await {
+      Ok(content) => content,
+      Err(rspack_fs::Error::Io(error)) if error.kind() == ErrorKind::NotFound => {
+        return Ok(Records::default())
+      }
+      Err(error) => {
+        return Err(internal_error!(
+          "Failed to read records from {}: {error}",
+          path.display()
+        ))
+      }
+    };
+    serde_json::from_slice(&content)
+      .map_err(|error| internal_error!("Failed to parse records {}: {error}", path.display()))
+  }
+
+  /// Queue the records to be written through the output file system once the assets are emitted.
+  fn write_records(&self, path: &Path, compilation: &mut Compilation) -> Result<()> {
+    let context = compilation.options.context.as_str();
+    let chunk_graph = &compilation.chunk_graph;
+    let module_graph = &compilation.module_graph;
+
+    let mut records = Records::default();
+    for module in module_graph.modules().values() {
+      if let Some(id) = chunk_graph.get_module_id(module.identifier()) {
+        records
+          .modules
+          .by_identifier
+          .insert(get_full_module_name(module, context), id.clone());
+        records.modules.used_ids.insert(id.clone());
+      }
+    }
+    for chunk in compilation.chunk_by_ukey.values() {
+      if let Some(id) = &chunk.id {
+        if let Some(name) = &chunk.name {
+          records.chunks.by_name.insert(name.clone(), id.clone());
+        }
+        records.chunks.used_ids.insert(id.clone());
+      }
+    }
+
+    let content = serde_json::to_vec_pretty(&records)
+      .map_err(|error| internal_error!("Failed to serialize records: {error}"))?;
+    compilation
+      .intermediate_files
+      .push((path.to_path_buf(), content));
+    Ok(())
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.RecordIdsPlugin"
+  }
+
+  async fn this_compilation(
+    &self,
+    args: ThisCompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginThisCompilationHookOutput {
+    let records = self.read_records(args.this_compilation).await?;
+    *self.records.lock().expect("should lock records") = records;
+    Ok(())
+  }
+
+  fn module_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    let records = self.records.lock().expect("should lock records");
+    let context = compilation.options.context.as_str();
+    let chunk_graph = &mut compilation.chunk_graph;
+
+    let mut used_ids = HashSet::new();
+    let mut modules = Vec::new();
+    for module in compilation.module_graph.modules().values() {
+      match chunk_graph.get_module_id(module.identifier()) {
+        Some(id) => {
+          used_ids.insert(id.clone());
+        }
+        None if chunk_graph.get_number_of_module_chunks(module.identifier()) != 0 => {
+          modules.push(module)
+        }
+        None => {}
+      }
+    }
+    for module in modules {
+      let Some(id) = records
+        .modules
+        .by_identifier
+        .get(&get_full_module_name(module, context))
+      else {
+        continue;
+      };
+      if used_ids.insert(id.clone()) {
+        chunk_graph.set_module_id(module.identifier(), id.clone());
+      }
+    }
+
+    compilation
+      .used_module_ids
+      .extend(records.modules.used_ids.iter().cloned());
+    Ok(())
+  }
+
+  fn chunk_ids(&self, compilation: &mut Compilation) -> Result<()> {
+    let records = self.records.lock().expect("should lock records");
+
+    let mut used_ids = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.id.clone())
+      .collect::<HashSet<_>>();
+    let mut chunk_ids = Vec::new();
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.id.is_some() {
+        continue;
+      }
+      let Some(id) = chunk
+        .name
+        .as_ref()
+        .and_then(|name| records.chunks.by_name.get(name))
+      else {
+        continue;
+      };
+      if used_ids.insert(id.clone()) {
+        chunk_ids.push((chunk.ukey, id.clone()));
+      }
+    }
+
+    for (chunk_ukey, id) in chunk_ids {
+      let chunk = compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("Chunk should exist");
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id];
+    }
+
+    compilation
+      .used_chunk_ids
+      .extend(records.chunks.used_ids.iter().cloned());
+    Ok(())
+  }
+
+  async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+    if let Some(path) = &self.records_output_path {
+      self.write_records(path, compilation)?;
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use rspack_core::PluginExt;
+use rspack_ids::{RecordIdsPlugin, Records};
+use rspack_testing::MemoryCompiler;
+
+fn records_path(name: &str) -> PathBuf {
+  Path::new(env!("CARGO_TARGET_TMPDIR"))
+    .join("record_ids")
+    .join(name)
+}
+
+/// Build the fixture from `imports` with natural ids, which shift when a module is added, the
+/// records written to the output file system are copied to `records_output_path` on the disk.
+async fn build(
+  imports: &[&str],
+  records_input_path: Option<PathBuf>,
+  records_output_path: PathBuf,
+) -> Records {
+  let mut compiler = MemoryCompiler::from_config(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/records"),
+    serde_json::json!({
+      "entry": { "main": { "import": imports } },
+      "optimization": { "moduleIds": "natural", "chunkIds": "natural" }
+    }),
+    |_, plugins| {
+      // the records need to be consulted before the plugins assigning the ids
+      plugins.insert(
+        0,
+        RecordIdsPlugin::new(records_input_path, Some(records_output_path.clone())).boxed(),
+      );
+    },
+  );
+  compiler.build().await.expect("should build");
+  compiler.assert_no_errors();
+
+  let content = compiler.output_file(&records_output_path);
+  std::fs::create_dir_all(records_output_path.parent().expect("should have a parent"))
+    .expect("should create the records directory");
+  std::fs::write(&records_output_path, &content).expect("should write records");
+  serde_json::from_slice(&content).expect("should be valid records")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn keep_ids_when_adding_a_module() {
+  let first_path = records_path("first.json");
+  let first = build(&["./index"], None, first_path.clone()).await;
+  let second = build(
+    &["./new", "./index"],
+    Some(first_path),
+    records_path("second.json"),
+  )
+  .await;
+
+  for (identifier, id) in &first.modules.by_identifier {
+    assert_eq!(
+      second.modules.by_identifier.get(identifier),
+      Some(id),
+      "the id of {identifier} should be kept"
+    );
+  }
+  assert_eq!(
+    second.chunks.by_name.get("async"),
+    first.chunks.by_name.get("async")
+  );
+  let new_ids = second
+    .modules
+    .by_identifier
+    .iter()
+    .filter(|(identifier, _)| !first.modules.by_identifier.contains_key(*identifier))
+    .map(|(_, id)| id)
+    .collect::<Vec<_>>();
+  assert!(!new_ids.is_empty());
+  assert!(new_ids
+    .iter()
+    .all(|id| !first.modules.used_ids.contains(*id)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn read_numeric_ids() {
+  let identifier = build(&["./index"], None, records_path("recorded.json"))
+    .await
+    .modules
+    .by_identifier
+    .into_keys()
+    .find(|identifier| identifier.ends_with("a.js"))
+    .expect("should record a.js");
+
+  // the ids are numbers in the records written by webpack
+  let mut by_identifier = serde_json::Map::new();
+  by_identifier.insert(identifier.clone(), 123.into());
+  let input = records_path("numeric.json");
+  std::fs::write(
+    &input,
+    serde_json::json!({
+      "modules": { "byIdentifier": by_identifier, "usedIds": [123] },
+      "chunks": { "byName": { "async": 456 }, "usedIds": [456] }
+    })
+    .to_string(),
+  )
+  .expect("should write records");
+  let records = build(
+    &["./index"],
+    Some(input),
+    records_path("numeric-output.json"),
+  )
+  .await;
+
+  assert_eq!(
+    records
+      .modules
+      .by_identifier
+      .get(&identifier)
+      .map(String::as_str),
+    Some("123")
+  );
+  assert_eq!(
+    records.chunks.by_name.get("async").map(String::as_str),
+    Some("456")
+  );
+}
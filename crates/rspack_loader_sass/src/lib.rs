@@ -1,10 +1,11 @@
 #![feature(let_chains)]
 
 use std::{
+  collections::HashMap,
   env,
   iter::Peekable,
   path::{Path, PathBuf},
-  sync::{mpsc, Arc},
+  sync::{mpsc, Arc, Mutex},
 };
 
 use itertools::Itertools;
@@ -12,19 +13,21 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
   rspack_sources::SourceMap, DependencyCategory, LoaderRunnerContext, Resolve,
-  ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory,
+  ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, Restriction,
 };
 use rspack_error::{
   internal_error, Diagnostic, DiagnosticKind, Error, InternalError, Result, Severity,
   TraceableError,
 };
 use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use rspack_regex::RspackRegex;
 use sass_embedded::{
   legacy::{
     IndentType, LegacyImporter, LegacyImporterResult, LegacyImporterThis, LegacyOptions,
     LegacyOptionsBuilder, LineFeed, OutputStyle,
   },
-  Exception, Logger, Sass, SourceSpan, Url,
+  Exception, FileImporter, ImporterOptions, Logger, Sass, SourceSpan, StringOptions,
+  StringOptionsBuilder, Syntax, Url,
 };
 use serde::Deserialize;
 use str_indices::utf16;
@@ -38,47 +41,102 @@ static IS_MODULE_IMPORT: Lazy<Regex> = Lazy::new(|| {
   Regex::new(r"^~([^/]+|[^/]+/|@[^/]+[/][^/]+|@[^/]+/?|@[^/]+[/][^/]+/)$").expect("TODO:")
 });
 
-fn get_os() -> &'static str {
+/// Idle embedded Sass compilers by the path of their executables. They are kept alive and
+/// reused by the loader runs, since spawning the process takes much longer than compiling a file.
+/// Each run checks out a compiler of its own, so files are compiled in parallel.
+static COMPILERS: Lazy<Mutex<HashMap<PathBuf, Vec<Sass>>>> = Lazy::new(Default::default);
+
+fn get_os() -> Option<&'static str> {
   match env::consts::OS {
-    "linux" => "linux",
-    "macos" => "darwin",
-    "windows" => "win32",
-    os => panic!("dart-sass-embedded is not supported for {os}"),
+    "linux" => Some("linux"),
+    "macos" => Some("darwin"),
+    "windows" => Some("win32"),
+    _ => None,
   }
 }
 
-fn get_arch() -> &'static str {
+fn get_arch() -> Option<&'static str> {
   match env::consts::ARCH {
-    "x86" => "ia32",
-    "x86_64" => "x64",
-    "aarch64" => "arm64",
-    arch => panic!("dart-sass-embedded is not supported for {arch}"),
+    "x86" => Some("ia32"),
+    "x86_64" => Some("x64"),
+    "aarch64" => Some("arm64"),
+    _ => None,
   }
 }
 
-fn dev_exe_path() -> PathBuf {
-  let os = get_os();
-  let arch = get_arch();
-  PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR")))
-    .join(format!("../../node_modules/sass-embedded-{os}-{arch}"))
-    .join("dart-sass-embedded/dart-sass-embedded")
+fn dev_exe_path() -> Option<PathBuf> {
+  let os = get_os()?;
+  let arch = get_arch()?;
+  Some(
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR")))
+      .join(format!("../../node_modules/sass-embedded-{os}-{arch}"))
+      .join("dart-sass-embedded/dart-sass-embedded"),
+  )
+}
+
+/// Take an idle compiler, or spawn a new one if all of them are in use.
+fn checkout_compiler(exe_path: &Path) -> Result<Sass> {
+  let idle = COMPILERS
+    .lock()
+    .expect("should lock the sass compilers")
+    .get_mut(exe_path)
+    .and_then(Vec::pop);
+  if let Some(compiler) = idle {
+    return Ok(compiler);
+  }
+  Sass::new(exe_path).map_err(|e| {
+    InternalError::new(
+      format!(
+        "{}: The dart-sass-embedded path is {}, your OS is {}, your Arch is {}",
+        e.message(),
+        exe_path.display(),
+        env::consts::OS,
+        env::consts::ARCH,
+      ),
+      Severity::Error,
+    )
+    .into()
+  })
+}
+
+/// Return a compiler to the idle ones once its compilation is done.
+fn checkin_compiler(exe_path: PathBuf, compiler: Sass) {
+  COMPILERS
+    .lock()
+    .expect("should lock the sass compilers")
+    .entry(exe_path)
+    .or_default()
+    .push(compiler);
+}
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SassApi {
+  /// `render` with the `importer` functions.
+  #[default]
+  Legacy,
+  /// `compileString` with the `importers`.
+  Modern,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct SassLoaderOptions {
+  api: SassApi,
   sass_options: SassOptions,
   // `None` means open or close source map depends on whether in production mode.
   source_map: Option<bool>,
   additional_data: Option<String>,
   rspack_importer: bool,
+  // `None` if dart-sass-embedded isn't available for the platform.
   #[serde(rename = "__exePath")]
-  __exe_path: PathBuf,
+  __exe_path: Option<PathBuf>,
 }
 
 impl Default for SassLoaderOptions {
   fn default() -> Self {
     Self {
+      api: Default::default(),
       rspack_importer: true,
       source_map: Default::default(),
       additional_data: Default::default(),
@@ -102,6 +160,13 @@ pub struct SassOptions {
   verbose: Option<bool>,
 }
 
+/// Only style files can be imported by sass.
+fn style_restriction() -> Restriction {
+  Restriction::Regex(
+    RspackRegex::with_flags(r"\.((sa|sc|c)ss)$", "i").expect("should be a valid regex"),
+  )
+}
+
 #[derive(Debug)]
 struct RspackImporter {
   include_paths: Vec<PathBuf>,
@@ -124,7 +189,7 @@ impl RspackImporter {
         prefer_relative: Some(true),
         main_files: Some(vec!["_index".to_owned(), "index".to_owned()]),
         main_fields: Some(Vec::new()),
-        // TODO: add restrictions field when resolver supports it.
+        restrictions: Some(vec![style_restriction()]),
         ..Default::default()
       })),
       resolve_to_context: false,
@@ -146,6 +211,7 @@ impl RspackImporter {
           "index".to_owned(),
         ]),
         main_fields: Some(Vec::new()),
+        restrictions: Some(vec![style_restriction()]),
         ..Default::default()
       })),
       resolve_to_context: false,
@@ -172,6 +238,7 @@ impl RspackImporter {
           ".css".to_owned(),
         ]),
         prefer_relative: Some(true),
+        restrictions: Some(vec![style_restriction()]),
         ..Default::default()
       })),
       resolve_to_context: false,
@@ -199,6 +266,7 @@ impl RspackImporter {
           ".css".to_owned(),
         ]),
         prefer_relative: Some(true),
+        restrictions: Some(vec![style_restriction()]),
         ..Default::default()
       })),
       resolve_to_context: false,
@@ -300,23 +368,23 @@ fn start_resolving<'r, 'c, I: Iterator<Item = String>>(
   }
 }
 
-impl LegacyImporter for RspackImporter {
-  fn call(
+impl RspackImporter {
+  #[allow(clippy::result_large_err)]
+  fn resolve(
     &self,
-    options: &LegacyImporterThis,
     request: &str,
-    context: &str,
-  ) -> sass_embedded::Result<Option<LegacyImporterResult>> {
-    let from_importer = options.from_import;
+    context: &Path,
+    from_import: bool,
+  ) -> sass_embedded::Result<Option<PathBuf>> {
     let need_emulate_sass_resolver = !IS_SPECIAL_MODULE_IMPORT.is_match(request)
       && !request.starts_with('/')
       && !IS_NATIVE_WIN32_PATH.is_match(request);
 
     let mut resolutions = Vec::new();
     if !self.include_paths.is_empty() && need_emulate_sass_resolver {
-      let sass_possible_requests = get_possible_requests(request, false, from_importer)?;
+      let sass_possible_requests = get_possible_requests(request, false, from_import)?;
       resolutions.extend(self.include_paths.iter().map(|context| Resolution {
-        resolve: if from_importer {
+        resolve: if from_import {
           &self.sass_import_resolve
         } else {
           &self.sass_module_resolve
@@ -326,26 +394,69 @@ impl LegacyImporter for RspackImporter {
       }));
     }
 
-    let rspack_possible_requests = get_possible_requests(request, true, from_importer)?;
+    let rspack_possible_requests = get_possible_requests(request, true, from_import)?;
     resolutions.push(Resolution {
-      resolve: if from_importer {
+      resolve: if from_import {
         &self.rspack_import_resolve
       } else {
         &self.rspack_module_resolve
       },
-      context: Path::new(context)
-        .parent()
-        .ok_or_else(|| Exception::new(format!("dirname of {context} is `None`")))?,
+      context,
       possible_requests: rspack_possible_requests.into_iter(),
     });
-    Ok(start_resolving(resolutions.into_iter().peekable()).map(LegacyImporterResult::file))
+    Ok(start_resolving(resolutions.into_iter().peekable()))
+  }
+}
+
+impl LegacyImporter for RspackImporter {
+  fn call(
+    &self,
+    options: &LegacyImporterThis,
+    request: &str,
+    context: &str,
+  ) -> sass_embedded::Result<Option<LegacyImporterResult>> {
+    let context = Path::new(context)
+      .parent()
+      .ok_or_else(|| Exception::new(format!("dirname of {context} is `None`")))?;
+    Ok(
+      self
+        .resolve(request, context, options.from_import)?
+        .map(LegacyImporterResult::file),
+    )
+  }
+}
+
+/// The importer of the modern API. Sass doesn't tell which file is importing, so requests
+/// which aren't relative to it are resolved from the directory of the resource, like sass-loader.
+#[derive(Debug)]
+struct RspackFileImporter {
+  importer: RspackImporter,
+  context: PathBuf,
+}
+
+impl FileImporter for RspackFileImporter {
+  fn find_file_url(
+    &self,
+    url: &str,
+    options: &ImporterOptions,
+  ) -> sass_embedded::Result<Option<Url>> {
+    // Files are loaded by sass itself.
+    if url.starts_with("file:") {
+      return Ok(None);
+    }
+    Ok(
+      self
+        .importer
+        .resolve(url, &self.context, options.from_import)?
+        .and_then(|path| Url::from_file_path(path).ok()),
+    )
   }
 }
 
 #[derive(Debug)]
 struct RspackLogger {
   // `Sync` is required by the `Logger` trait
-  tx: mpsc::SyncSender<Vec<Diagnostic>>,
+  tx: mpsc::Sender<Vec<Diagnostic>>,
 }
 
 impl Logger for RspackLogger {
@@ -361,6 +472,45 @@ impl Logger for RspackLogger {
   }
 }
 
+/// A compilation of either API, it's sent to the thread running the compiler.
+enum SassCompilation {
+  Legacy(LegacyOptions),
+  /// The source and its options.
+  Modern(String, StringOptions),
+}
+
+struct SassOutput {
+  css: Vec<u8>,
+  map: Option<Vec<u8>>,
+  /// Files loaded by the modern API, the legacy API has them in its stats only.
+  loaded_urls: Vec<Url>,
+}
+
+impl SassCompilation {
+  fn run(self, compiler: &mut Sass) -> Result<SassOutput> {
+    match self {
+      SassCompilation::Legacy(options) => {
+        let result = compiler.render(options).map_err(sass_exception_to_error)?;
+        Ok(SassOutput {
+          css: result.css,
+          map: result.map,
+          loaded_urls: Vec::new(),
+        })
+      }
+      SassCompilation::Modern(source, options) => {
+        let result = compiler
+          .compile_string(source, options)
+          .map_err(sass_exception_to_error)?;
+        Ok(SassOutput {
+          css: result.css.into_bytes(),
+          map: result.source_map.map(String::into_bytes),
+          loaded_urls: result.loaded_urls,
+        })
+      }
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct SassLoader {
   options: SassLoaderOptions,
@@ -373,20 +523,93 @@ impl SassLoader {
     Self { options }
   }
 
-  fn get_sass_options(
+  fn get_include_paths(&self) -> Result<Vec<PathBuf>> {
+    let cwd = env::current_dir()
+      .map_err(|e| internal_error!("sass-loader: Failed to get the current directory: {e}"))?;
+    let mut include_paths = vec![cwd.clone()];
+    include_paths.extend(self.options.sass_options.include_paths.iter().map(|path| {
+      if path.is_absolute() {
+        path.to_owned()
+      } else {
+        cwd.join(path)
+      }
+    }));
+    Ok(include_paths)
+  }
+
+  fn get_data(&self, content: String) -> String {
+    if let Some(additional_data) = &self.options.additional_data {
+      format!("{additional_data}\n{content}")
+    } else {
+      content
+    }
+  }
+
+  fn get_modern_sass_options(
+    &self,
+    loader_context: &LoaderContext<'_, LoaderRunnerContext>,
+    logger: RspackLogger,
+  ) -> Result<StringOptions> {
+    let resource_path = loader_context.resource_path;
+    let syntax = match self.options.sass_options.indented_syntax {
+      Some(true) => Syntax::Indented,
+      Some(false) => Syntax::Scss,
+      None => match resource_path.extension().and_then(|ext| ext.to_str()) {
+        Some("sass") => Syntax::Indented,
+        Some("css") => Syntax::Css,
+        _ => Syntax::Scss,
+      },
+    };
+    let include_paths = self.get_include_paths()?;
+    let mut builder = StringOptionsBuilder::default()
+      .syntax(syntax)
+      .logger(logger)
+      .load_paths(&include_paths)
+      .source_map(
+        self
+          .options
+          .source_map
+          .unwrap_or_else(|| loader_context.context.options.devtool.enabled()),
+      )
+      .source_map_include_sources(true);
+    if let Ok(url) = Url::from_file_path(resource_path) {
+      builder = builder.url(url);
+    }
+
+    if self.options.rspack_importer {
+      builder = builder.file_importer(RspackFileImporter {
+        importer: RspackImporter::new(
+          include_paths,
+          Arc::clone(&loader_context.context.resolver_factory),
+        ),
+        context: resource_path
+          .parent()
+          .map(Path::to_path_buf)
+          .unwrap_or_default(),
+      });
+    }
+
+    if let Some(charset) = &self.options.sass_options.charset {
+      builder = builder.charset(*charset);
+    }
+    if let Some(quiet_deps) = &self.options.sass_options.quiet_deps {
+      builder = builder.quiet_deps(*quiet_deps);
+    }
+    if let Some(verbose) = &self.options.sass_options.verbose {
+      builder = builder.verbose(*verbose);
+    }
+
+    Ok(builder.build())
+  }
+
+  fn get_legacy_sass_options(
     &self,
     loader_context: &LoaderContext<'_, LoaderRunnerContext>,
     content: String,
     logger: RspackLogger,
-  ) -> LegacyOptions {
+  ) -> Result<LegacyOptions> {
     let mut builder = LegacyOptionsBuilder::default()
-      .data(
-        if let Some(additional_data) = &self.options.additional_data {
-          format!("{additional_data}\n{content}")
-        } else {
-          content
-        },
-      )
+      .data(self.get_data(content))
       .logger(logger)
       .file(loader_context.resource_path)
       .source_map(
@@ -417,14 +640,7 @@ impl SassLoader {
           }),
       );
 
-    let mut include_paths = vec![env::current_dir().expect("TODO:")];
-    include_paths.extend(self.options.sass_options.include_paths.iter().map(|path| {
-      if path.is_absolute() {
-        path.to_owned()
-      } else {
-        env::current_dir().expect("TODO:").join(path)
-      }
-    }));
+    let include_paths = self.get_include_paths()?;
     builder = builder.include_paths(&include_paths);
 
     if self.options.rspack_importer {
@@ -453,7 +669,7 @@ impl SassLoader {
       builder = builder.verbose(*verbose);
     }
 
-    builder.build()
+    Ok(builder.build())
   }
 }
 
@@ -464,42 +680,81 @@ impl Loader<LoaderRunnerContext> for SassLoader {
       .content
       .to_owned()
       .expect("content should available");
-    let (tx, rx) = mpsc::sync_channel(8);
+    let Some(exe_path) = &self.options.__exe_path else {
+      return Err(internal_error!(
+        "dart-sass-embedded is not supported for your OS {} and your Arch {}",
+        env::consts::OS,
+        env::consts::ARCH,
+      ));
+    };
+    // Unbounded, since the warnings are received after the compilation.
+    let (tx, rx) = mpsc::channel();
     let logger = RspackLogger { tx };
-    let sass_options = self.get_sass_options(loader_context, content.try_into_string()?, logger);
-    let result = Sass::new(&self.options.__exe_path)
-      .map_err(|e| {
-        InternalError::new(
-          format!(
-            "{}: The dart-sass-embedded path is {}, your OS is {}, your Arch is {}",
-            e.message(),
-            &self.options.__exe_path.display(),
-            get_os(),
-            get_arch(),
-          ),
-          Severity::Error,
-        )
-      })?
-      .render(sass_options)
-      .map_err(sass_exception_to_error)?;
-    let source_map = result.map.map(|map| {
-      let mut map = SourceMap::from_slice(&map).expect("should be able to generate source-map");
-      for source in map.sources_mut() {
-        if source.starts_with("file:") {
-          *source = Url::parse(source)
-            .expect("TODO:")
-            .to_file_path()
-            .expect("TODO:")
-            .display()
-            .to_string();
+    let content = content.try_into_string()?;
+    let compilation = match self.options.api {
+      SassApi::Legacy => {
+        SassCompilation::Legacy(self.get_legacy_sass_options(loader_context, content, logger)?)
+      }
+      SassApi::Modern => {
+        let SassOptions {
+          indent_type,
+          indent_width,
+          linefeed,
+          ..
+        } = &self.options.sass_options;
+        if indent_type.is_some() || indent_width.is_some() || linefeed.is_some() {
+          loader_context.emit_diagnostic(Diagnostic::warn(
+            "sass-loader: Sass Warning".to_string(),
+            "`indentType`, `indentWidth` and `linefeed` are only supported by the legacy API, \
+             set `api: \"legacy\"` to use them"
+              .to_string(),
+          ));
         }
+        SassCompilation::Modern(
+          self.get_data(content),
+          self.get_modern_sass_options(loader_context, logger)?,
+        )
       }
-      map
-    });
+    };
+    let exe_path = exe_path.to_owned();
+    // The compilation blocks until the compiler responds, keep it off the async runtime.
+    let SassOutput {
+      css,
+      map,
+      loaded_urls,
+    } = tokio::task::spawn_blocking(move || {
+      let mut compiler = checkout_compiler(&exe_path)?;
+      let output = compilation.run(&mut compiler)?;
+      // a compiler failed with an error may be in an unknown state, don't reuse it
+      checkin_compiler(exe_path, compiler);
+      Ok(output)
+    })
+    .await
+    .map_err(|e| internal_error!("sass-loader: {e}"))??;
+    loader_context
+      .file_dependencies
+      .extend(loaded_urls.iter().filter_map(|url| url.to_file_path().ok()));
+    let source_map = map
+      .map(|map| -> Result<SourceMap> {
+        let mut map = SourceMap::from_slice(&map).expect("should be able to generate source-map");
+        for source in map.sources_mut() {
+          if source.starts_with("file:") {
+            let path = Url::parse(source)
+              .ok()
+              .and_then(|url| url.to_file_path().ok())
+              .ok_or_else(|| {
+                internal_error!("sass-loader: invalid source {source} in source-map")
+              })?;
+            *source = path.display().to_string();
+          }
+        }
+        Ok(map)
+      })
+      .transpose()?;
 
-    loader_context.content = Some(result.css.into());
+    loader_context.content = Some(css.into());
     loader_context.source_map = source_map;
-    rx.into_iter().flatten().for_each(|d| {
+    rx.try_iter().flatten().for_each(|d| {
       loader_context.emit_diagnostic(d);
     });
     Ok(())
@@ -550,13 +805,8 @@ fn make_traceable_error(title: &str, message: &str, span: &SourceSpan) -> Option
   span
     .url
     .as_ref()
-    .map(|url| {
-      url
-        .to_file_path()
-        .expect("TODO:")
-        .to_string_lossy()
-        .to_string()
-    })
+    .and_then(|url| url.to_file_path().ok())
+    .map(|path| path.to_string_lossy().to_string())
     .and_then(|path| {
       std::fs::read_to_string(&path)
         .ok()
@@ -8,20 +8,35 @@ use std::{
 use rspack_core::{
   run_loaders, CompilerContext, CompilerOptions, Loader, LoaderRunnerContext, ResourceData,
 };
+use rspack_error::Result;
 use rspack_loader_sass::{SassLoader, SassLoaderOptions};
 use rspack_testing::{fixture, test_fixture_css};
 use sass_embedded::Url;
+use serde_json::json;
 
 // UPDATE_SASS_LOADER_TEST=1 cargo test --package rspack_loader_sass test_fn_name -- --exact --nocapture
 async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
   let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
   let expected_path = tests_path.join(expected);
+  let result = run_sass_loader(SassLoaderOptions::default(), actual)
+    .await
+    .expect("TODO:");
+
+  if env::var("UPDATE_SASS_LOADER_TEST").is_ok() {
+    fs::write(expected_path, result).expect("TODO:");
+  } else {
+    let expected = fs::read_to_string(expected_path).expect("TODO:");
+    assert_eq!(result, expected);
+  }
+}
+
+async fn run_sass_loader(options: SassLoaderOptions, actual: impl AsRef<Path>) -> Result<String> {
+  let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
   let actual_path = tests_path.join(actual);
 
   let url = Url::from_file_path(actual_path.to_string_lossy().to_string()).expect("TODO:");
   let (result, _) = run_loaders(
-    &[Arc::new(SassLoader::new(SassLoaderOptions::default()))
-      as Arc<dyn Loader<LoaderRunnerContext>>],
+    &[Arc::new(SassLoader::new(options)) as Arc<dyn Loader<LoaderRunnerContext>>],
     &ResourceData::new(
       actual_path.to_string_lossy().to_string(),
       url.to_file_path().expect("bad url file path"),
@@ -73,6 +88,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           worker_wasm_loading: rspack_core::WasmLoading::Disable,
           worker_public_path: String::new(),
           script_type: String::from("false"),
+          environment: Default::default(),
         },
         target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
         resolve: rspack_core::Resolve::default(),
@@ -96,21 +112,18 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         profile: false,
       }),
       resolver_factory: Default::default(),
+      input_filesystem: std::sync::Arc::new(rspack_fs::AsyncNativeFileSystem),
       module: None,
       module_context: None,
     },
   )
-  .await
-  .expect("TODO:")
+  .await?
   .split_into_parts();
-  let result = result.content.try_into_string().expect("TODO:");
+  result.content.try_into_string()
+}
 
-  if env::var("UPDATE_SASS_LOADER_TEST").is_ok() {
-    fs::write(expected_path, result).expect("TODO:");
-  } else {
-    let expected = fs::read_to_string(expected_path).expect("TODO:");
-    assert_eq!(result, expected);
-  }
+fn loader_options(options: serde_json::Value) -> SassLoaderOptions {
+  serde_json::from_value(options).expect("should be valid sass-loader options")
 }
 
 #[tokio::test]
@@ -118,6 +131,51 @@ async fn rspack_importer() {
   loader_test("scss/language.scss", "expected/rspack_importer.css").await;
 }
 
+#[tokio::test]
+async fn modern_api() {
+  let css = run_sass_loader(
+    loader_options(json!({ "api": "modern" })),
+    "scss/modern.scss",
+  )
+  .await
+  .expect("should compile with the modern api");
+  assert!(css.contains("color: #333;"), "{css}");
+  assert!(css.contains("content: \"∑\";"), "{css}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compile_in_parallel() {
+  let (legacy, modern) = tokio::join!(
+    run_sass_loader(SassLoaderOptions::default(), "scss/language.scss"),
+    run_sass_loader(
+      loader_options(json!({ "api": "modern" })),
+      "scss/modern.scss"
+    ),
+  );
+  assert!(legacy
+    .expect("should compile with the legacy api")
+    .contains("nav ul"));
+  assert!(modern
+    .expect("should compile with the modern api")
+    .contains(".bar:before"));
+}
+
+#[tokio::test]
+async fn missing_executable() {
+  let error = run_sass_loader(
+    loader_options(json!({ "__exePath": "/path/to/missing/dart-sass-embedded" })),
+    "scss/language.scss",
+  )
+  .await
+  .expect_err("should report the missing executable");
+  assert!(
+    error
+      .to_string()
+      .contains("The dart-sass-embedded path is /path/to/missing/dart-sass-embedded"),
+    "{error}"
+  );
+}
+
 #[fixture("tests/fixtures/*")]
 fn sass(fixture_path: PathBuf) {
   test_fixture_css(&fixture_path);
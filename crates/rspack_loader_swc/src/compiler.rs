@@ -383,7 +383,7 @@ impl SwcCompiler {
         try_with_handler(self.cm.clone(), Default::default(), |handler| {
           HANDLER.set(handler, || {
             // Fold module
-            Ok(program.fold_with(&mut pass))
+            fold_with_plugins(program, &mut pass)
           })
         })
       })
@@ -408,6 +408,20 @@ impl SwcCompiler {
   }
 }
 
+/// The message swc panics with when a Wasm plugin fails, see `swc::plugin::RustPlugins`.
+pub(crate) const PLUGIN_FAILURE: &str = "failed to invoke plugin";
+
+/// Fold the program, the panic of a failed Wasm plugin is turned into an error so it's reported
+/// as an error of the module rather than crashing the compiler.
+fn fold_with_plugins(program: Program, pass: &mut impl Fold) -> Result<Program, Error> {
+  std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| program.fold_with(pass))).or_else(
+    |cause| match cause.downcast_ref::<String>() {
+      Some(message) if message.starts_with(PLUGIN_FAILURE) => Err(anyhow!(message.clone())),
+      _ => std::panic::resume_unwind(cause),
+    },
+  )
+}
+
 pub(crate) trait IntoJsAst {
   fn into_js_ast(self, program: Program) -> JsAst;
 }
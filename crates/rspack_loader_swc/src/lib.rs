@@ -6,7 +6,7 @@ mod transformer;
 
 use std::default::Default;
 
-use compiler::{IntoJsAst, SwcCompiler};
+use compiler::{IntoJsAst, SwcCompiler, PLUGIN_FAILURE};
 use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 use rspack_ast::RspackAst;
@@ -67,13 +67,25 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
         }
       }
 
+      #[cfg(not(feature = "plugin"))]
       if swc_options.config.jsc.experimental.plugins.is_some() {
         loader_context.emit_diagnostic(Diagnostic::warn(
           SWC_LOADER_IDENTIFIER.to_string(),
-          "Experimental plugins are not currently supported.".to_string(),
+          "Experimental plugins are not supported by this build of rspack.".to_string(),
         ));
       }
 
+      // Wasm plugins are compiled once and cached on the file system for the next builds
+      #[cfg(feature = "plugin")]
+      if swc_options.config.jsc.experimental.plugins.is_some()
+        && swc_options.config.jsc.experimental.cache_root.is_none()
+      {
+        let cache_root = std::path::Path::new(loader_context.context.options.context.as_str())
+          .join("node_modules/.cache/swc");
+        swc_options.config.jsc.experimental.cache_root =
+          Some(cache_root.to_string_lossy().into_owned());
+      }
+
       if swc_options.config.jsc.target.is_some() && swc_options.config.env.is_some() {
         loader_context.emit_diagnostic(Diagnostic::warn(
           SWC_LOADER_IDENTIFIER.to_string(),
@@ -83,6 +95,15 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       swc_options
     };
 
+    let plugins = swc_options
+      .config
+      .jsc
+      .experimental
+      .plugins
+      .iter()
+      .flatten()
+      .map(|plugin| plugin.0.clone())
+      .collect::<Vec<_>>();
     let devtool = &loader_context.context.options.devtool;
     let source = content.try_into_string()?;
     let c = SwcCompiler::new(resource_path.clone(), source.clone(), swc_options)
@@ -129,7 +150,9 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
       inline_script: Some(false),
       keep_comments: Some(true),
     };
-    let program = c.transform(built).map_err(AnyhowError::from)?;
+    let program = c
+      .transform(built)
+      .map_err(|e| AnyhowError::from(with_plugins(e, &plugins)))?;
     let ast = c.into_js_ast(program);
 
     // If swc-loader is the latest loader available,
@@ -159,8 +182,59 @@ impl Loader<LoaderRunnerContext> for SwcLoader {
   }
 }
 
+/// Name the Wasm plugins in a transform error caused by running them, keeping the whole chain
+/// of causes, as the most common failure is a plugin built against an incompatible `swc_core`.
+fn with_plugins(error: anyhow::Error, plugins: &[String]) -> anyhow::Error {
+  if plugins.is_empty() || !is_plugin_runner_error(&error) {
+    return error;
+  }
+  anyhow::anyhow!(
+    "{error:#}\n\nFailed to run SWC Wasm plugins: {}.\n\
+    Plugins must be built with a `swc_core` version whose AST schema is compatible with \
+    the one used by rspack, see https://swc.rs/docs/plugin/selecting-swc-core",
+    plugins.join(", ")
+  )
+}
+
+/// Whether the error is raised by the plugin runner, e.g. `failed to invoke plugin` and
+/// ``failed to invoke `plugin.wasm` as js transform plugin``.
+fn is_plugin_runner_error(error: &anyhow::Error) -> bool {
+  error
+    .chain()
+    .any(|cause| cause.to_string().starts_with("failed to invoke"))
+}
+
 impl Identifiable for SwcLoader {
   fn identifier(&self) -> Identifier {
     self.identifier
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use anyhow::anyhow;
+
+  use super::*;
+
+  #[test]
+  fn hint_plugin_runner_errors_only() {
+    let plugins = vec!["my_first_plugin.wasm".to_string()];
+
+    let error = with_plugins(anyhow!("Expected ';', got 'b'"), &plugins);
+    assert!(!format!("{error:#}").contains("Failed to run SWC Wasm plugins"));
+
+    let error = with_plugins(
+      anyhow!("RuntimeError: unreachable").context(
+        "failed to invoke `my_first_plugin.wasm` as js transform plugin at my_first_plugin",
+      ),
+      &plugins,
+    );
+    assert!(format!("{error:#}").contains("Failed to run SWC Wasm plugins: my_first_plugin.wasm"));
+
+    let error = with_plugins(
+      anyhow!("{PLUGIN_FAILURE}: RuntimeError: unreachable"),
+      &plugins,
+    );
+    assert!(format!("{error:#}").contains("Failed to run SWC Wasm plugins"));
+  }
+}
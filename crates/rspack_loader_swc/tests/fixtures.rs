@@ -9,6 +9,7 @@ use rspack_core::{
   run_loaders, CompilerContext, CompilerOptions, Loader, LoaderRunnerContext, ResourceData,
   SideEffectOption,
 };
+use rspack_error::Result;
 use rspack_loader_swc::{SwcLoader, SwcLoaderJsOptions};
 use rspack_testing::{fixture, test_fixture};
 use serde_json::json;
@@ -19,20 +20,46 @@ use swc_core::base::config::PluginConfig;
 async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
   let tests_path = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests");
   let expected_path = tests_path.join(expected);
-  let actual_path = tests_path.join(actual);
-  let plugin_path = tests_path.join("my_first_plugin.wasm");
+  let result = run_swc_loader(
+    plugin_options(),
+    tests_path.join(actual),
+    rspack_core::Context::default(),
+  )
+  .await
+  .expect("TODO:");
+
+  if env::var("UPDATE").is_ok() {
+    fs::write(expected_path, result).expect("TODO:");
+  } else {
+    let expected = fs::read_to_string(expected_path).expect("TODO:");
+    assert_eq!(result, expected);
+  }
+}
+
+/// Options running `tests/my_first_plugin.wasm`.
+fn plugin_options() -> SwcLoaderJsOptions {
+  let plugin_path =
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests/my_first_plugin.wasm");
   let mut options = SwcLoaderJsOptions::default();
   options.jsc.experimental.plugins = Some(vec![PluginConfig(
     plugin_path.to_string_lossy().to_string(),
     json!(null),
   )]);
+  options
+}
+
+async fn run_swc_loader(
+  options: SwcLoaderJsOptions,
+  actual_path: PathBuf,
+  context: rspack_core::Context,
+) -> Result<String> {
   let (result, _) = run_loaders(
     &[Arc::new(SwcLoader::new(options)) as Arc<dyn Loader<LoaderRunnerContext>>],
     &ResourceData::new(actual_path.to_string_lossy().to_string(), actual_path),
     &[],
     CompilerContext {
       options: std::sync::Arc::new(CompilerOptions {
-        context: rspack_core::Context::default(),
+        context,
         dev_server: rspack_core::DevServerOptions::default(),
         devtool: rspack_core::Devtool::from("source-map".to_string()),
         mode: rspack_core::Mode::None,
@@ -74,6 +101,7 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           worker_wasm_loading: rspack_core::WasmLoading::Disable,
           worker_public_path: String::new(),
           script_type: String::from("false"),
+          environment: Default::default(),
         },
         target: rspack_core::Target::new(&vec![String::from("web")]).expect("TODO:"),
         resolve: rspack_core::Resolve::default(),
@@ -97,21 +125,14 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
         profile: false,
       }),
       resolver_factory: Default::default(),
+      input_filesystem: std::sync::Arc::new(rspack_fs::AsyncNativeFileSystem),
       module: None,
       module_context: None,
     },
   )
-  .await
-  .expect("TODO:")
+  .await?
   .split_into_parts();
-  let result = result.content.try_into_string().expect("TODO:");
-
-  if env::var("UPDATE").is_ok() {
-    fs::write(expected_path, result).expect("TODO:");
-  } else {
-    let expected = fs::read_to_string(expected_path).expect("TODO:");
-    assert_eq!(result, expected);
-  }
+  result.content.try_into_string()
 }
 
 // #[tokio::test]
@@ -119,6 +140,35 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
 //   loader_test("swc-plugin/index.js", "swc-plugin/expected/index.js").await;
 // }
 
+#[cfg(feature = "plugin")]
+#[tokio::test]
+async fn wasm_plugin_cache_root() {
+  let context = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"))).join("tests/swc-plugin");
+  let cache_root = context.join("node_modules/.cache/swc");
+  if cache_root.exists() {
+    fs::remove_dir_all(&cache_root).expect("should remove the cache");
+  }
+  let result = run_swc_loader(
+    plugin_options(),
+    context.join("index.js"),
+    rspack_core::Context::from(context.as_path()),
+  )
+  .await;
+  // the plugin may be built against an incompatible `swc_core`, which has to be explained
+  if let Err(error) = result {
+    assert!(
+      error.to_string().contains("Failed to run SWC Wasm plugins"),
+      "{error}"
+    );
+  }
+  assert!(
+    cache_root.is_dir(),
+    "compiled plugins should be cached in {}",
+    cache_root.display()
+  );
+  fs::remove_dir_all(context.join("node_modules")).expect("should remove the cache");
+}
+
 #[fixture("tests/fixtures/*")]
 fn swc(fixture_path: PathBuf) {
   test_fixture(&fixture_path, Box::new(|_, _| {}), None);
@@ -1,7 +1,6 @@
 #![feature(let_chains)]
 use std::{
   fmt::Display,
-  fs,
   hash::Hash,
   path::{Path, PathBuf, MAIN_SEPARATOR},
   sync::{Arc, Mutex},
@@ -9,13 +8,14 @@ use std::{
 
 use async_trait::async_trait;
 use dashmap::DashSet;
-use glob::{MatchOptions, Pattern as GlobPattern};
+use glob::{MatchOptions, Pattern as GlobPattern, PatternError};
 use regex::Regex;
 use rspack_core::{
   rspack_sources::RawSource, AssetInfo, AssetInfoRelated, Compilation, CompilationAsset,
   CompilationLogger, Filename, Logger, PathData, Plugin,
 };
-use rspack_error::{Diagnostic, DiagnosticError, Error, ErrorExt};
+use rspack_error::{Diagnostic, Error};
+use rspack_fs::{AsyncReadableFileSystem, InputFileSystem, ReadableFileSystem};
 use rspack_hash::{HashDigest, HashFunction, HashSalt, RspackHash, RspackHashDigest};
 use sugar_path::{AsPath, SugarPath};
 
@@ -142,7 +142,10 @@ impl CopyRspackPlugin {
     logger: &CompilationLogger,
   ) -> Option<RunPatternResult> {
     // Exclude directories
-    if entry.is_dir() {
+    if AsyncReadableFileSystem::metadata(&*compilation.input_filesystem, &entry)
+      .await
+      .is_ok_and(|metadata| metadata.is_directory)
+    {
       return None;
     }
     if let Some(ignore) = &pattern.glob_options.ignore
@@ -223,24 +226,25 @@ impl CopyRspackPlugin {
     // TODO cache
 
     logger.debug(format!("reading '{}'...", absolute_filename.display()));
-    // TODO inputFileSystem
 
-    let source = match tokio::fs::read(absolute_filename.clone()).await {
-      Ok(data) => {
-        logger.debug(format!("read '{}'...", absolute_filename.display()));
+    let source =
+      match AsyncReadableFileSystem::read(&*compilation.input_filesystem, &absolute_filename).await
+      {
+        Ok(data) => {
+          logger.debug(format!("read '{}'...", absolute_filename.display()));
 
-        RawSource::Buffer(data)
-      }
-      Err(e) => {
-        let e: Error = DiagnosticError::from(e.boxed()).into();
-        let rspack_err: Vec<Diagnostic> = vec![e.into()];
-        diagnostics
-          .lock()
-          .expect("failed to obtain lock of `diagnostics`")
-          .extend(rspack_err);
-        return None;
-      }
-    };
+          RawSource::Buffer(data)
+        }
+        Err(e) => {
+          let e: Error = e.into();
+          let rspack_err: Vec<Diagnostic> = vec![e.into()];
+          diagnostics
+            .lock()
+            .expect("failed to obtain lock of `diagnostics`")
+            .extend(rspack_err);
+          return None;
+        }
+      };
 
     let filename = if matches!(&to_type, ToType::Template) {
       logger.log(format!(
@@ -285,7 +289,7 @@ impl CopyRspackPlugin {
     })
   }
 
-  fn run_patter(
+  async fn run_patter(
     compilation: &Compilation,
     pattern: &CopyPattern,
     _index: usize,
@@ -315,14 +319,16 @@ impl CopyRspackPlugin {
 
     logger.debug(format!("getting stats for '{}'...", abs_from.display()));
 
-    let from_type = if let Ok(meta) = fs::metadata(&abs_from) {
-      if meta.is_dir() {
+    let from_type = if let Ok(meta) =
+      AsyncReadableFileSystem::metadata(&*compilation.input_filesystem, &abs_from).await
+    {
+      if meta.is_directory {
         logger.debug(format!(
           "determined '{}' is a directory",
           abs_from.display()
         ));
         FromType::Dir
-      } else if meta.is_file() {
+      } else if meta.is_file {
         logger.debug(format!("determined '{}' is a file", abs_from.display()));
         FromType::File
       } else {
@@ -388,21 +394,22 @@ impl CopyRspackPlugin {
 
     logger.log(format!("begin globbing '{glob_query}'..."));
 
-    let glob_entries = glob::glob_with(
-      &glob_query,
-      MatchOptions {
-        case_sensitive: pattern.glob_options.case_sensitive_match.unwrap_or(true),
-        require_literal_separator: Default::default(),
-        require_literal_leading_dot: !dot_enable.unwrap_or(false),
-      },
-    );
+    let match_options = MatchOptions {
+      case_sensitive: pattern.glob_options.case_sensitive_match.unwrap_or(true),
+      require_literal_separator: Default::default(),
+      require_literal_leading_dot: !dot_enable.unwrap_or(false),
+    };
+    let glob_entries = if compilation.input_filesystem.is_native() {
+      glob::glob_with(&glob_query, match_options).map(|entries| entries.flatten().collect())
+    } else {
+      glob_input_filesystem(&*compilation.input_filesystem, &glob_query, match_options)
+    };
 
     match glob_entries {
       Ok(entries) => {
         let entries: Vec<_> = entries
+          .into_iter()
           .filter_map(|entry| {
-            let entry = entry.ok()?;
-
             let filters = pattern.glob_options.ignore.as_ref();
 
             if let Some(filters) = filters {
@@ -519,20 +526,18 @@ impl Plugin for CopyRspackPlugin {
     let context_dependencies = DashSet::default();
     let diagnostics = Mutex::new(Vec::new());
 
-    let mut copied_result: Vec<(i32, RunPatternResult)> = self
-      .patterns
-      .iter()
-      .enumerate()
-      .map(|(index, pattern)| {
-        let mut pattern = pattern.clone();
-        if pattern.context.is_none() {
-          pattern.context = Some(args.compilation.options.context.as_path().into());
-        } else if let Some(ctx) = pattern.context.clone()
-          && !ctx.is_absolute()
-        {
-          pattern.context = Some(args.compilation.options.context.as_path().join(ctx))
-        };
-
+    let mut pattern_results = Vec::with_capacity(self.patterns.len());
+    for (index, pattern) in self.patterns.iter().enumerate() {
+      let mut pattern = pattern.clone();
+      if pattern.context.is_none() {
+        pattern.context = Some(args.compilation.options.context.as_path().into());
+      } else if let Some(ctx) = pattern.context.clone()
+        && !ctx.is_absolute()
+      {
+        pattern.context = Some(args.compilation.options.context.as_path().join(ctx))
+      };
+
+      pattern_results.push(
         Self::run_patter(
           args.compilation,
           &pattern,
@@ -542,8 +547,11 @@ impl Plugin for CopyRspackPlugin {
           &diagnostics,
           &logger,
         )
-      })
-      .collect::<Vec<_>>()
+        .await,
+      );
+    }
+
+    let mut copied_result: Vec<(i32, RunPatternResult)> = pattern_results
       .into_iter()
       .flatten()
       .flat_map(|item| {
@@ -602,6 +610,49 @@ impl Plugin for CopyRspackPlugin {
   }
 }
 
+/// Glob the entries of a non-native input file system, the walk starts from the directory
+/// before the first component with glob characters.
+fn glob_input_filesystem(
+  fs: &dyn InputFileSystem,
+  glob_query: &str,
+  match_options: MatchOptions,
+) -> Result<Vec<PathBuf>, PatternError> {
+  let pattern = GlobPattern::new(glob_query)?;
+  let base = Path::new(glob_query)
+    .components()
+    .take_while(|component| {
+      !component
+        .as_os_str()
+        .to_string_lossy()
+        .contains(['*', '?', '[', ']', '\\'])
+    })
+    .collect::<PathBuf>();
+  let mut entries = vec![];
+  let mut dirs = vec![base];
+  while let Some(dir) = dirs.pop() {
+    let Ok(metadata) = ReadableFileSystem::metadata(fs, &dir) else {
+      continue;
+    };
+    if metadata.is_file {
+      if pattern.matches_path_with(&dir, match_options) {
+        entries.push(dir);
+      }
+      continue;
+    }
+    for name in ReadableFileSystem::read_dir(fs, &dir).unwrap_or_default() {
+      let path = dir.join(name);
+      if pattern.matches_path_with(&path, match_options) {
+        entries.push(path.clone());
+      }
+      if ReadableFileSystem::metadata(fs, &path).is_ok_and(|metadata| metadata.is_directory) {
+        dirs.push(path);
+      }
+    }
+  }
+  entries.sort();
+  Ok(entries)
+}
+
 fn get_closest_common_parent_dir(paths: &Vec<&Path>) -> Option<PathBuf> {
   // If there are no matching files, return `None`.
   if paths.is_empty() {
@@ -676,6 +727,30 @@ fn test_escape() {
   assert_eq!(escape_glob_chars("a/b/c"), r#"a/b/c"#);
 }
 
+#[test]
+fn test_glob_input_filesystem() {
+  let fs = rspack_fs::MemoryFileSystem::new();
+  fs.insert("/project/public/a.txt", "");
+  fs.insert("/project/public/nested/b.txt", "");
+  fs.insert("/project/public/nested/c.js", "");
+  fs.insert("/project/public/.hidden.txt", "");
+  let options = MatchOptions {
+    require_literal_leading_dot: true,
+    ..MatchOptions::new()
+  };
+  assert_eq!(
+    glob_input_filesystem(&fs, "/project/public/**/*.txt", options).unwrap(),
+    vec![
+      PathBuf::from("/project/public/a.txt"),
+      PathBuf::from("/project/public/nested/b.txt")
+    ]
+  );
+  assert_eq!(
+    glob_input_filesystem(&fs, "/project/public/nested/c.js", options).unwrap(),
+    vec![PathBuf::from("/project/public/nested/c.js")]
+  );
+}
+
 // If this test fails, you should modify `set_info` function, according to your changes about AssetInfo
 // Make sure every field of AssetInfo is considered
 #[test]
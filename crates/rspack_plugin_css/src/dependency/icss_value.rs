@@ -0,0 +1,132 @@
+use rspack_core::{
+  Compilation, DependencyTemplate, ModuleIdentifier, TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+use rustc_hash::FxHashSet;
+
+/// Replaces the usage of a value imported by `@value` or `:import` from another CSS module
+/// with the value exported by that module.
+#[derive(Debug, Clone)]
+pub struct CssIcssValueDependency {
+  request: String,
+  name: String,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssValueDependency {
+  pub fn new(request: String, name: String, start: u32, end: u32) -> Self {
+    Self {
+      request,
+      name,
+      start,
+      end,
+    }
+  }
+}
+
+impl DependencyTemplate for CssIcssValueDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+    if let Some(value) = resolve_icss_value(
+      compilation,
+      module.identifier(),
+      self.request.clone(),
+      self.name.clone(),
+    ) {
+      source.replace(self.start, self.end, &value, None);
+    }
+  }
+}
+
+/// Warnings for the values imported by `@value` which are not exported by the imported modules.
+pub fn unresolved_icss_values(compilation: &Compilation) -> Vec<Diagnostic> {
+  let module_graph = &compilation.module_graph;
+  let mut modules = module_graph
+    .module_graph_modules()
+    .values()
+    .filter_map(|mgm| {
+      let values = mgm.build_info.as_ref()?.icss_values.as_ref()?;
+      Some((mgm.module_identifier, values))
+    })
+    .collect::<Vec<_>>();
+  modules.sort_unstable_by_key(|(identifier, _)| *identifier);
+
+  let mut diagnostics = vec![];
+  for (identifier, values) in modules {
+    let mut values = values
+      .iter()
+      .filter_map(|(alias, (name, from))| Some((alias, name, from.as_ref()?)))
+      .collect::<Vec<_>>();
+    values.sort_unstable();
+    for (alias, name, request) in values {
+      if resolve_icss_value(compilation, identifier, request.clone(), name.clone()).is_some() {
+        continue;
+      }
+      let Some(module) = module_graph.module_by_identifier(&identifier) else {
+        continue;
+      };
+      diagnostics.push(Diagnostic::warn(
+        "Unresolved @value".to_string(),
+        format!(
+          "{}\n@value '{name}' (imported as '{alias}') was not found in '{request}'",
+          module.readable_identifier(&compilation.options.context)
+        ),
+      ));
+    }
+  }
+  diagnostics
+}
+
+/// Follow the value `name` imported from `request` by the `origin` module, through the modules
+/// re-exporting it, until its literal value.
+pub fn resolve_icss_value(
+  compilation: &Compilation,
+  mut origin: ModuleIdentifier,
+  mut request: String,
+  mut name: String,
+) -> Option<String> {
+  let module_graph = &compilation.module_graph;
+  let mut visited = FxHashSet::default();
+  loop {
+    let target = module_graph
+      .module_by_identifier(&origin)?
+      .get_dependencies()
+      .iter()
+      .find_map(|id| {
+        let dependency = module_graph.dependency_by_id(id)?.as_module_dependency()?;
+        if dependency.request() == request {
+          module_graph.module_identifier_by_dependency_id(id).copied()
+        } else {
+          None
+        }
+      })?;
+    if !visited.insert((target, name.clone())) {
+      return None;
+    }
+    let (value, from) = module_graph
+      .module_graph_module_by_identifier(&target)?
+      .build_info
+      .as_ref()?
+      .icss_values
+      .as_ref()?
+      .get(&name)?
+      .clone();
+    match from {
+      Some(from) => {
+        origin = target;
+        request = from;
+        name = value;
+      }
+      None => return Some(value),
+    }
+  }
+}
@@ -1,7 +1,9 @@
 mod compose;
+mod icss_value;
 mod import;
 mod url;
 
 pub use compose::*;
+pub use icss_value::*;
 pub use import::*;
 pub use url::*;
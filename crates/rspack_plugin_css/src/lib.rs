@@ -5,6 +5,7 @@ pub mod dependency;
 mod parser_and_generator;
 pub mod plugin;
 pub mod swc_css_compiler;
+pub mod targets;
 mod utils;
 pub mod visitors;
 
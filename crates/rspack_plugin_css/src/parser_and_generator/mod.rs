@@ -4,6 +4,7 @@ use std::collections::HashMap;
 
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
+use preset_env_base::Versions;
 use regex::Regex;
 use rkyv::{from_bytes, to_bytes, AlignedVec};
 use rspack_core::{
@@ -30,7 +31,7 @@ use crate::{
 };
 use crate::{
   utils::{export_locals_convention, stringify_css_modules_exports_elements},
-  visitors::analyze_dependencies,
+  visitors::{analyze_dependencies, analyze_icss},
 };
 
 static REGEX_IS_MODULES: Lazy<Regex> =
@@ -47,6 +48,8 @@ type CssExportsType = Option<IndexMap<Vec<String>, Vec<(String, Option<String>)>
 #[derive(Debug)]
 pub struct CssParserAndGenerator {
   pub config: CssConfig,
+  /// Browsers the CSS is lowered and prefixed for.
+  pub targets: Option<Versions>,
   pub exports: CssExportsType,
 }
 
@@ -102,7 +105,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut source_map = None;
     let mut diagnostic_vec = vec![];
 
-    if is_enable_css_modules {
+    if is_enable_css_modules || self.targets.is_some() {
       let TWithDiagnosticArray {
         inner: mut stylesheet,
         diagnostic,
@@ -116,30 +119,37 @@ impl ParserAndGenerator for CssParserAndGenerator {
         },
       )?;
 
-      let result = swc_core::css::modules::compile(
-        &mut stylesheet,
-        ModulesTransformConfig::new(
-          &resource_data
-            .resource_path
-            .relative(&compiler_options.context),
-          &self.config.modules.local_ident_name,
-          &compiler_options.output,
-        ),
-      );
-      let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
-      exports.sort_keys();
-
-      self.exports = Some(IndexMap::from_iter(
-        exports
-          .iter()
-          .map(|(name, elements)| {
-            let mut names = export_locals_convention(name, &self.config.modules.locals_convention);
-            names.sort_unstable();
-            names.dedup();
-            (names, stringify_css_modules_exports_elements(elements))
-          })
-          .collect::<Vec<_>>(),
-      ));
+      if is_enable_css_modules {
+        let result = swc_core::css::modules::compile(
+          &mut stylesheet,
+          ModulesTransformConfig::new(
+            &resource_data
+              .resource_path
+              .relative(&compiler_options.context),
+            &self.config.modules.local_ident_name,
+            &compiler_options.output,
+          ),
+        );
+        let mut exports: IndexMap<JsWord, _> = result.renamed.into_iter().collect();
+        exports.sort_keys();
+
+        self.exports = Some(IndexMap::from_iter(
+          exports
+            .iter()
+            .map(|(name, elements)| {
+              let mut names =
+                export_locals_convention(name, &self.config.modules.locals_convention);
+              names.sort_unstable();
+              names.dedup();
+              (names, stringify_css_modules_exports_elements(elements))
+            })
+            .collect::<Vec<_>>(),
+        ));
+      }
+
+      if let Some(targets) = self.targets {
+        swc_compiler.transform_for_targets(&mut stylesheet, targets);
+      }
 
       let (code, map) = swc_compiler.codegen(
         &stylesheet,
@@ -170,30 +180,45 @@ impl ParserAndGenerator for CssParserAndGenerator {
       &mut diagnostic_vec,
     );
 
-    let dependencies = if let Some(locals) = &self.exports
-      && !locals.is_empty()
-    {
-      let mut dep_set = FxHashSet::default();
-      let compose_deps = locals
-        .iter()
-        .flat_map(|(_, value)| value)
-        .filter_map(|(_, from)| {
-          if let Some(from) = from {
-            if dep_set.contains(&from) {
-              None
-            } else {
-              dep_set.insert(from);
-              Some(Box::new(CssComposeDependency::new(from.to_owned(), None)) as BoxDependency)
-            }
-          } else {
-            None
-          }
-        });
-      dependencies.extend(compose_deps);
-      dependencies
-    } else {
-      dependencies
-    };
+    let mut presentational_dependencies = vec![];
+    let mut icss_requests = vec![];
+    if is_enable_css_modules {
+      let icss = analyze_icss(&new_stylesheet_ast, &source_code);
+      if !icss.exports.is_empty() {
+        let locals = self.exports.get_or_insert_with(Default::default);
+        for (name, (value, from)) in &icss.exports {
+          let mut names = export_locals_convention(
+            &JsWord::from(name.as_str()),
+            &self.config.modules.locals_convention,
+          );
+          names.sort_unstable();
+          names.dedup();
+          let value = serde_json::to_string(value).expect("Failed to stringify icss value");
+          locals.insert(names, vec![(value, from.to_owned())]);
+        }
+        build_info.icss_values = Some(icss.exports.into_iter().collect());
+      }
+      icss_requests = icss.requests;
+      presentational_dependencies = icss.presentational_dependencies;
+    }
+
+    let mut dep_set = FxHashSet::default();
+    let compose_deps = self
+      .exports
+      .iter()
+      .flatten()
+      .flat_map(|(_, value)| value)
+      .filter_map(|(_, from)| from.as_ref())
+      .chain(icss_requests.iter())
+      .filter_map(|from| {
+        if dep_set.contains(&from) {
+          None
+        } else {
+          dep_set.insert(from);
+          Some(Box::new(CssComposeDependency::new(from.to_owned(), None)) as BoxDependency)
+        }
+      });
+    dependencies.extend(compose_deps);
 
     let new_source = if devtool.source_map() {
       if let Some(source_map) = source_map {
@@ -220,7 +245,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
       ParseResult {
         dependencies,
         blocks: vec![],
-        presentational_dependencies: vec![],
+        presentational_dependencies,
         source: new_source,
         analyze_result: Default::default(),
       }
@@ -17,7 +17,9 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_hash::RspackHash;
 
+use crate::dependency::unresolved_icss_values;
 use crate::parser_and_generator::CssParserAndGenerator;
+use crate::targets::resolve_targets;
 use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
 use crate::CssPlugin;
 
@@ -117,12 +119,20 @@ impl Plugin for CssPlugin {
   fn apply(
     &self,
     ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
-    _options: &mut CompilerOptions,
+    options: &mut CompilerOptions,
   ) -> Result<()> {
     let config = self.config.clone();
+    // the css is only lowered and prefixed for explicit targets, they are resolved from the
+    // browserslist `target` of the compiler on the js side
+    let targets = Some(&self.config.targets)
+      .filter(|targets| !targets.is_empty())
+      .cloned()
+      .map(resolve_targets)
+      .transpose()?;
     let builder = move || {
       Box::new(CssParserAndGenerator {
         config: config.clone(),
+        targets,
         exports: None,
       }) as Box<dyn ParserAndGenerator>
     };
@@ -159,6 +169,12 @@ impl Plugin for CssPlugin {
     Ok(())
   }
 
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let diagnostics = unresolved_icss_values(compilation);
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
   async fn content_hash(
     &self,
     _ctx: rspack_core::PluginContext,
@@ -169,7 +185,7 @@ impl Plugin for CssPlugin {
       .chunk_by_ukey
       .get(&args.chunk_ukey)
       .expect("should have chunk");
-    let ordered_modules = Self::get_ordered_chunk_css_modules(
+    let (ordered_modules, _) = Self::get_ordered_chunk_css_modules(
       chunk,
       &compilation.chunk_graph,
       &compilation.module_graph,
@@ -211,7 +227,7 @@ impl Plugin for CssPlugin {
       return Ok(vec![]);
     }
 
-    let ordered_css_modules = Self::get_ordered_chunk_css_modules(
+    let (ordered_css_modules, _) = Self::get_ordered_chunk_css_modules(
       chunk,
       &compilation.chunk_graph,
       &compilation.module_graph,
@@ -271,4 +287,37 @@ impl Plugin for CssPlugin {
       false,
     )])
   }
+
+  async fn process_assets_stage_additional(
+    &self,
+    _ctx: rspack_core::PluginContext,
+    args: rspack_core::ProcessAssetsArgs<'_>,
+  ) -> rspack_core::PluginProcessAssetsOutput {
+    if self.config.ignore_order {
+      return Ok(());
+    }
+
+    let compilation = args.compilation;
+    let mut chunks = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| !matches!(chunk.kind, ChunkKind::HotUpdate))
+      .collect::<Vec<_>>();
+    chunks.sort_unstable_by_key(|chunk| chunk.ukey);
+    let diagnostics = chunks
+      .into_iter()
+      .flat_map(|chunk| {
+        let (_, conflicts) = Self::get_ordered_chunk_css_modules(
+          chunk,
+          &compilation.chunk_graph,
+          &compilation.module_graph,
+          compilation,
+        );
+        conflicts
+      })
+      .map(|conflicts| conflicts.into_diagnostic(compilation))
+      .collect::<Vec<_>>();
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
 }
@@ -8,8 +8,10 @@ use bitflags::bitflags;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::Filename;
-use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, PathData, SourceType};
-use rspack_error::internal_error_bail;
+use rspack_core::{
+  Chunk, ChunkGraph, ChunkGroupUkey, Compilation, Module, ModuleGraph, PathData, SourceType,
+};
+use rspack_error::{internal_error_bail, Diagnostic};
 use rspack_identifier::IdentifierSet;
 
 static ESCAPE_LOCAL_IDENT_REGEX: Lazy<Regex> =
@@ -101,6 +103,10 @@ impl Default for LocalsConvention {
 #[derive(Debug, Clone)]
 pub struct CssConfig {
   pub modules: ModulesConfig,
+  /// Don't warn about the conflicting orders of CSS modules between chunk groups.
+  pub ignore_order: bool,
+  /// Browserslist queries the CSS is lowered and prefixed for, the CSS is left as is without any.
+  pub targets: Vec<String>,
 }
 
 impl CssPlugin {
@@ -109,30 +115,31 @@ impl CssPlugin {
   }
 
   pub(crate) fn get_ordered_chunk_css_modules<'chunk_graph>(
-    chunk: &Chunk,
+    chunk: &'chunk_graph Chunk,
     chunk_graph: &'chunk_graph ChunkGraph,
     module_graph: &'chunk_graph ModuleGraph,
     compilation: &Compilation,
-  ) -> Vec<&'chunk_graph dyn Module> {
+  ) -> (
+    Vec<&'chunk_graph dyn Module>,
+    Vec<CssOrderConflicts<'chunk_graph>>,
+  ) {
     // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/css/CssModulesPlugin.js#L368
     let mut css_modules = chunk_graph
       .get_chunk_modules_iterable_by_source_type(&chunk.ukey, SourceType::Css, module_graph)
       .collect::<Vec<_>>();
     css_modules.sort_unstable_by_key(|module| module.identifier());
 
-    let css_modules = Self::get_modules_in_order(chunk, css_modules, compilation);
-
-    css_modules
+    Self::get_modules_in_order(chunk, css_modules, compilation)
   }
 
   pub(crate) fn get_modules_in_order<'module>(
-    chunk: &Chunk,
+    chunk: &'module Chunk,
     modules: Vec<&'module dyn Module>,
     compilation: &Compilation,
-  ) -> Vec<&'module dyn Module> {
+  ) -> (Vec<&'module dyn Module>, Vec<CssOrderConflicts<'module>>) {
     // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/css/CssModulesPlugin.js#L269
     if modules.is_empty() {
-      return vec![];
+      return (vec![], vec![]);
     };
 
     let modules_list = modules.clone();
@@ -168,6 +175,7 @@ impl CssPlugin {
         };
 
         SortedModules {
+          chunk_group: chunk_group.ukey,
           set: sorted_modules.iter().map(|m| m.identifier()).collect(),
           list: sorted_modules,
         }
@@ -181,12 +189,13 @@ impl CssPlugin {
         .expect("must have one")
         .list;
       ret.reverse();
-      return ret;
+      return (ret, vec![]);
     };
 
     modules_by_chunk_group.sort_unstable_by(compare_module_lists);
 
     let mut final_modules: Vec<&'module dyn Module> = vec![];
+    let mut conflicts = vec![];
 
     loop {
       let mut failed_modules: IdentifierSet = Default::default();
@@ -198,7 +207,7 @@ impl CssPlugin {
       let mut selected_module = *list.last().expect("TODO:");
       let mut has_failed = None;
       'outer: loop {
-        for SortedModules { set, list } in &modules_by_chunk_group {
+        for SortedModules { set, list, .. } in &modules_by_chunk_group {
           if list.is_empty() {
             continue;
           }
@@ -223,29 +232,17 @@ impl CssPlugin {
       }
       if let Some(has_failed) = has_failed {
         // There is a not resolve-able conflict with the selectedModule
-        // TODO(hyf0): we should emit a warning here
-        tracing::warn!("Conflicting order between");
-        // 		if (compilation) {
-        // 			// TODO print better warning
-        // 			compilation.warnings.push(
-        // 				new Error(
-        // 					`chunk ${
-        // 						chunk.name || chunk.id
-        // 					}\nConflicting order between ${hasFailed.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)} and ${selectedModule.readableIdentifier(
-        // 						compilation.requestShortener
-        // 					)}`
-        // 				)
-        // 			);
-        // 		}
-
+        conflicts.push(CssOrderConflicts::new(
+          chunk,
+          has_failed,
+          &modules_by_chunk_group,
+        ));
         selected_module = has_failed;
       }
       // Insert the selected module into the final modules list
       final_modules.push(selected_module);
       // Remove the selected module from all lists
-      for SortedModules { set, list } in &mut modules_by_chunk_group {
+      for SortedModules { set, list, .. } in &mut modules_by_chunk_group {
         let last_module = list.last();
         if last_module.map_or(false, |last_module| last_module == &selected_module) {
           list.pop();
@@ -260,16 +257,108 @@ impl CssPlugin {
 
       modules_by_chunk_group.sort_unstable_by(compare_module_lists);
     }
-    final_modules
+    (final_modules, conflicts)
   }
 }
 
 #[derive(Debug)]
 struct SortedModules<'module> {
+  pub chunk_group: ChunkGroupUkey,
   pub list: Vec<&'module dyn Module>,
   pub set: IdentifierSet,
 }
 
+/// A module added to a CSS chunk despite the desired orders of some chunk groups, like the
+/// conflicting order warning of mini-css-extract-plugin.
+#[derive(Debug)]
+pub(crate) struct CssOrderConflicts<'module> {
+  chunk: &'module Chunk,
+  module: &'module dyn Module,
+  /// Modules desired before the added module, with the chunk groups desiring them.
+  conflicts: Vec<(&'module dyn Module, Vec<ChunkGroupUkey>)>,
+  /// Chunk groups whose desired order is fulfilled by the added module.
+  fulfilled: Vec<ChunkGroupUkey>,
+}
+
+impl<'module> CssOrderConflicts<'module> {
+  fn new(
+    chunk: &'module Chunk,
+    module: &'module dyn Module,
+    modules_by_chunk_group: &[SortedModules<'module>],
+  ) -> Self {
+    let mut conflicts: Vec<(&dyn Module, Vec<ChunkGroupUkey>)> = vec![];
+    let mut fulfilled = vec![];
+    for SortedModules {
+      chunk_group,
+      list,
+      set,
+    } in modules_by_chunk_group
+    {
+      let Some(last_module) = list.last() else {
+        continue;
+      };
+      if last_module == &module {
+        fulfilled.push(*chunk_group);
+      } else if set.contains(&module.identifier()) {
+        if let Some((_, chunk_groups)) = conflicts.iter_mut().find(|(m, _)| m == last_module) {
+          chunk_groups.push(*chunk_group);
+        } else {
+          conflicts.push((*last_module, vec![*chunk_group]));
+        }
+      }
+    }
+    conflicts.sort_by_key(|(m, _)| m.identifier());
+    Self {
+      chunk,
+      module,
+      conflicts,
+      fulfilled,
+    }
+  }
+
+  pub(crate) fn into_diagnostic(self, compilation: &Compilation) -> Diagnostic {
+    let context = &compilation.options.context;
+    let chunk_group_names = |chunk_groups: &[ChunkGroupUkey]| {
+      chunk_groups
+        .iter()
+        .map(|ukey| {
+          ukey
+            .as_ref(&compilation.chunk_group_by_ukey)
+            .name()
+            .unwrap_or("(anonymous)")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+    };
+
+    let mut message = format!(
+      "chunk {} [rspack.CssPlugin]\nConflicting order. Following module has been added:\n * {}\n\
+       despite it was not able to fulfill desired ordering with these modules:",
+      self
+        .chunk
+        .name
+        .as_deref()
+        .or(self.chunk.id.as_deref())
+        .unwrap_or_default(),
+      self.module.readable_identifier(context),
+    );
+    for (module, chunk_groups) in &self.conflicts {
+      message += &format!(
+        "\n * {}\n   - couldn't fulfill desired order of chunk group(s) {}",
+        module.readable_identifier(context),
+        chunk_group_names(chunk_groups)
+      );
+      if !self.fulfilled.is_empty() {
+        message += &format!(
+          "\n   - while fulfilling desired order of chunk group(s) {}",
+          chunk_group_names(&self.fulfilled)
+        );
+      }
+    }
+    Diagnostic::warn("Conflicting order".to_string(), message)
+  }
+}
+
 fn compare_module_lists(a: &SortedModules, b: &SortedModules) -> cmp::Ordering {
   let a = &a.list;
   let b = &b.list;
@@ -1,17 +1,26 @@
 use std::sync::Arc;
 
+use preset_env_base::Versions;
+use regex::Regex;
 use rspack_core::rspack_sources::{self, SourceExt};
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use swc_core::common::comments::{Comments, SingleThreadedComments};
 use swc_core::common::{input::SourceFileInput, source_map::SourceMapGenConfig, FileName};
 use swc_core::common::{Globals, GLOBALS};
 use swc_core::css::codegen::{
   writer::basic::{BasicCssWriter, BasicCssWriterConfig},
   CodeGenerator, CodegenConfig, Emit,
 };
+use swc_core::css::compat::compiler::{Compiler, Config};
 use swc_core::css::minifier;
 use swc_core::css::parser::{lexer::Lexer, parser::ParserConfig};
+use swc_core::css::prefixer::{options::Options as PrefixerOptions, prefixer};
+use swc_core::css::visit::VisitMutWith;
 use swc_core::css::{ast::Stylesheet, parser::parser::Parser};
 
+use crate::targets::{lowering_features, minify_unsafe_features, should_lower_logical_properties};
+use crate::visitors::lower_logical_properties;
+
 #[derive(Default)]
 pub struct SwcCssCompiler {
   cm: Arc<swc_core::common::SourceMap>,
@@ -23,12 +32,22 @@ impl SwcCssCompiler {
     path: &str,
     source: String,
     config: ParserConfig,
+  ) -> Result<TWithDiagnosticArray<Stylesheet>> {
+    self.parse_file_with_comments(path, source, config, None)
+  }
+
+  fn parse_file_with_comments(
+    &self,
+    path: &str,
+    source: String,
+    config: ParserConfig,
+    comments: Option<&dyn Comments>,
   ) -> Result<TWithDiagnosticArray<Stylesheet>> {
     let fm = self
       .cm
       .new_source_file(FileName::Custom(path.to_string()), source);
 
-    let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+    let lexer = Lexer::new(SourceFileInput::from(&*fm), comments, config);
     let mut parser = Parser::new(lexer, config);
     let stylesheet = parser.parse_all();
     stylesheet
@@ -36,6 +55,20 @@ impl SwcCssCompiler {
       .map(|stylesheet| stylesheet.with_diagnostic(vec![]))
   }
 
+  /// Lower the syntax and add the vendor prefixes needed by the `targets`.
+  pub fn transform_for_targets(&self, ast: &mut Stylesheet, targets: Versions) {
+    GLOBALS.set(&Globals::default(), || {
+      let process = lowering_features(targets);
+      if !process.is_empty() {
+        ast.visit_mut_with(&mut Compiler::new(Config { process }));
+      }
+      if should_lower_logical_properties(targets) {
+        lower_logical_properties(ast);
+      }
+      ast.visit_mut_with(&mut prefixer(PrefixerOptions { env: Some(targets) }));
+    });
+  }
+
   pub fn codegen(
     &self,
     ast: &Stylesheet,
@@ -81,14 +114,68 @@ impl SwcCssCompiler {
     input_source: String,
     input_source_map: Option<rspack_sources::SourceMap>,
     gen_source_map: SwcCssSourceMapGenConfig,
-  ) -> Result<rspack_sources::BoxSource> {
-    let parsed = self.parse_file(filename, input_source.clone(), Default::default())?;
+    options: &SwcCssMinifyOptions,
+  ) -> Result<SwcCssMinifyOutput> {
+    let comments = SingleThreadedComments::default();
+    let parsed = self.parse_file_with_comments(
+      filename,
+      input_source.clone(),
+      Default::default(),
+      options
+        .extract_comments
+        .is_some()
+        .then_some(&comments as &dyn Comments),
+    )?;
     // ignore errors since css in webpack is tolerant, and diagnostics already reported in parse.
     let (mut ast, _) = parsed.split_into_parts();
     GLOBALS.set(&Globals::default(), || {
       minifier::minify(&mut ast, minifier::options::MinifyOptions::default());
+      if let Some(targets) = options.targets {
+        let process = minify_unsafe_features(targets);
+        if !process.is_empty() {
+          ast.visit_mut_with(&mut Compiler::new(Config { process }));
+        }
+      }
     });
-    let (code, source_map) = self.codegen_impl(&ast, gen_source_map, true)?;
+    let extracted_comments = options
+      .extract_comments
+      .map(|condition| {
+        let (leading, trailing) = comments.borrow_all();
+        let mut extracted = leading
+          .iter()
+          .chain(trailing.iter())
+          .flat_map(|(pos, comments)| comments.iter().map(move |c| (*pos, c)))
+          .filter(|(_, c)| condition.is_match(&c.text))
+          .collect::<Vec<_>>();
+        extracted.sort_by_key(|(pos, _)| *pos);
+        extracted
+          .into_iter()
+          .map(|(_, c)| format!("/*{}*/", c.text))
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    let source = self.create_minified_source(
+      filename,
+      &ast,
+      input_source,
+      input_source_map,
+      gen_source_map,
+    )?;
+    Ok(SwcCssMinifyOutput {
+      source,
+      extracted_comments,
+    })
+  }
+
+  fn create_minified_source(
+    &self,
+    filename: &str,
+    ast: &Stylesheet,
+    input_source: String,
+    input_source_map: Option<rspack_sources::SourceMap>,
+    gen_source_map: SwcCssSourceMapGenConfig,
+  ) -> Result<rspack_sources::BoxSource> {
+    let (code, source_map) = self.codegen_impl(ast, gen_source_map, true)?;
     if let Some(source_map) = source_map {
       let source = rspack_sources::SourceMapSource::new(rspack_sources::SourceMapSourceOptions {
         value: code,
@@ -107,6 +194,21 @@ impl SwcCssCompiler {
   }
 }
 
+#[derive(Debug, Default)]
+pub struct SwcCssMinifyOptions<'a> {
+  /// Comments matching the condition are collected into
+  /// [SwcCssMinifyOutput::extracted_comments] instead of being dropped.
+  pub extract_comments: Option<&'a Regex>,
+  /// Syntax produced by the minifier is lowered again if these browsers don't support it.
+  pub targets: Option<Versions>,
+}
+
+#[derive(Debug)]
+pub struct SwcCssMinifyOutput {
+  pub source: rspack_sources::BoxSource,
+  pub extracted_comments: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SwcCssSourceMapGenConfig {
   pub enable: bool,
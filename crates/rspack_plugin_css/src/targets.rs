@@ -0,0 +1,167 @@
+use preset_env_base::{
+  query::{targets_to_versions, Query, Targets},
+  version::{should_enable, Version},
+  Versions,
+};
+use rspack_error::{internal_error, Result};
+use swc_core::css::compat::feature::Features;
+
+/// Resolves browserslist queries into the minimal version of each browser.
+pub fn resolve_targets(targets: Vec<String>) -> Result<Versions> {
+  targets_to_versions(Some(Targets::Query(Query::Multiple(targets))))
+    .map_err(|e| internal_error!("Failed to resolve css targets: {}", e))
+}
+
+fn version(v: &str) -> Option<Version> {
+  Some(v.parse().expect("should be a valid browser version"))
+}
+
+/// Browser versions supporting `#rrggbbaa` and `#rgba` hex colors.
+/// https://caniuse.com/css-rrggbbaa
+fn color_hex_alpha() -> Versions {
+  Versions {
+    chrome: version("62"),
+    edge: version("79"),
+    firefox: version("49"),
+    safari: version("10"),
+    opera: version("49"),
+    ios: version("10"),
+    samsung: version("8"),
+    android: version("62"),
+    ..Default::default()
+  }
+}
+
+/// Browser versions supporting nested style rules.
+/// https://caniuse.com/css-nesting
+fn nesting() -> Versions {
+  Versions {
+    chrome: version("112"),
+    edge: version("112"),
+    firefox: version("117"),
+    safari: version("16.5"),
+    opera: version("98"),
+    ios: version("16.5"),
+    samsung: version("23"),
+    android: version("112"),
+    ..Default::default()
+  }
+}
+
+/// Browser versions supporting range syntax in media queries, e.g. `(width >= 600px)`.
+/// https://caniuse.com/css-media-range-syntax
+fn media_query_ranges() -> Versions {
+  Versions {
+    chrome: version("104"),
+    edge: version("104"),
+    firefox: version("63"),
+    safari: version("16.4"),
+    opera: version("91"),
+    ios: version("16.4"),
+    samsung: version("20"),
+    android: version("104"),
+    ..Default::default()
+  }
+}
+
+/// Browser versions supporting the CSS Color 4 syntax of `rgb()` and `hsl()`, which are
+/// space separated, take the alpha after a `/`, and alias `rgba()` and `hsla()`.
+/// https://caniuse.com/mdn-css_types_color_space_separated_functional_notation
+fn color_functions_level_4() -> Versions {
+  Versions {
+    chrome: version("65"),
+    edge: version("79"),
+    firefox: version("52"),
+    safari: version("12.1"),
+    opera: version("52"),
+    ios: version("12.2"),
+    samsung: version("9.2"),
+    android: version("65"),
+    ..Default::default()
+  }
+}
+
+/// Browser versions supporting a list of selectors in `:not()`.
+/// https://caniuse.com/css-not-sel-list
+fn selector_not_list() -> Versions {
+  Versions {
+    chrome: version("88"),
+    edge: version("88"),
+    firefox: version("84"),
+    safari: version("9"),
+    opera: version("74"),
+    ios: version("9"),
+    samsung: version("15"),
+    android: version("88"),
+    ..Default::default()
+  }
+}
+
+/// Browser versions supporting `inset` and the logical shorthands of margin and padding,
+/// e.g. `margin-block` and `padding-inline`.
+/// https://caniuse.com/css-logical-props
+fn logical_properties() -> Versions {
+  Versions {
+    chrome: version("87"),
+    edge: version("87"),
+    firefox: version("66"),
+    safari: version("14.1"),
+    opera: version("73"),
+    ios: version("14.5"),
+    samsung: version("14"),
+    android: version("87"),
+    ..Default::default()
+  }
+}
+
+/// Whether `inset` and the logical properties have to be replaced with the physical ones,
+/// which isn't covered by the [Features] of the compat compiler.
+pub fn should_lower_logical_properties(targets: Versions) -> bool {
+  should_enable(targets, logical_properties(), false)
+}
+
+/// Syntax of the sources which is not supported by all of the `targets`, it's lowered before
+/// the vendor prefixes are added.
+pub fn lowering_features(targets: Versions) -> Features {
+  let mut features = Features::empty();
+  if should_enable(targets, nesting(), false) {
+    features |= Features::NESTING;
+  }
+  if should_enable(targets, media_query_ranges(), false) {
+    features |= Features::MEDIA_QUERY_RANGES;
+  }
+  if should_enable(targets, color_hex_alpha(), false) {
+    features |= Features::COLOR_HEX_ALPHA;
+  }
+  if should_enable(targets, color_functions_level_4(), false) {
+    features |= Features::COLOR_SPACE_SEPARATED_PARAMETERS
+      | Features::COLOR_ALPHA_PARAMETER
+      | Features::COLOR_LEGACY_RGB_AND_HSL;
+  }
+  if should_enable(targets, selector_not_list(), false) {
+    features |= Features::SELECTOR_NOT;
+  }
+  features
+}
+
+/// Features the minifier may produce which are not supported by all of the `targets`.
+/// They are lowered after minification to keep the output working in those browsers.
+pub fn minify_unsafe_features(targets: Versions) -> Features {
+  let mut features = Features::empty();
+  // colors with alpha are compressed to `#rrggbbaa` or `#rgba`
+  if should_enable(targets, color_hex_alpha(), false) {
+    features |= Features::COLOR_HEX_ALPHA;
+  }
+  // colors are compressed to the shortest notation, which may be the CSS Color 4 one,
+  // e.g. `rgb()` with an alpha, or a percentage alpha
+  if should_enable(targets, color_functions_level_4(), false) {
+    features |= Features::COLOR_ALPHA_PARAMETER
+      | Features::COLOR_SPACE_SEPARATED_PARAMETERS
+      | Features::COLOR_LEGACY_RGB_AND_HSL;
+  }
+  // `:not(.a):not(.b)` may be merged into `:not(.a, .b)`
+  if should_enable(targets, selector_not_list(), false) {
+    features |= Features::SELECTOR_NOT;
+  }
+  features
+}
@@ -0,0 +1,373 @@
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{BoxDependencyTemplate, ConstDependency, SpanExt};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::common::Span;
+use swc_core::css::ast::{
+  AtRulePrelude, Declaration, Ident, Rule, Stylesheet, Token, TokenAndSpan,
+};
+use swc_core::css::visit::{Visit, VisitWith};
+
+use crate::dependency::CssIcssValueDependency;
+
+/// A value of ICSS, either the literal value, or the name and the request of the module it's
+/// imported from.
+pub type IcssValue = (String, Option<String>);
+
+#[derive(Debug, Default)]
+pub struct IcssAnalysis {
+  /// Values exported by `:export` and `@value`.
+  pub exports: IndexMap<String, IcssValue>,
+  /// Requests of the modules the values are imported from.
+  pub requests: Vec<String>,
+  /// Removals of the ICSS rules and replacements of the values used by the module.
+  pub presentational_dependencies: Vec<BoxDependencyTemplate>,
+}
+
+static VALUE_KEYWORD: &str = "value";
+
+static IMPORT_SELECTOR: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r#"^:import\(\s*(?:"([^"]*)"|'([^']*)'|([^)\s]+))\s*\)$"#).expect("Invalid regex")
+});
+
+static VALUE_IMPORT: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(r#"^([\s\S]+?)\s+from\s+(?:"([^"]*)"|'([^']*)'|([\w-]+))$"#).expect("Invalid regex")
+});
+
+static VALUE_IMPORT_ITEM: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regex"));
+
+static VALUE_DEFINITION: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^([\w-]+)\s*:?\s*([\s\S]*)$").expect("Invalid regex"));
+
+/// Analyze the ICSS of a CSS module: `:import("./x.css") { alias: name }`,
+/// `:export { name: value }`, `@value name: value` and `@value name as alias from "./x.css"`.
+///
+/// The rules are removed from the generated CSS, and the values are replaced where they are
+/// used by declarations and at-rule preludes.
+pub fn analyze_icss(ss: &Stylesheet, source: &str) -> IcssAnalysis {
+  let mut analyzer = IcssAnalyzer {
+    source,
+    values: Default::default(),
+    analysis: Default::default(),
+  };
+  let mut rest = Vec::with_capacity(ss.rules.len());
+  for rule in &ss.rules {
+    if !analyzer.analyze_rule(rule) {
+      rest.push(rule);
+    }
+  }
+
+  if !analyzer.values.is_empty() {
+    let mut usage = IcssValueUsage {
+      values: &analyzer.values,
+      in_value: false,
+      usages: Vec::new(),
+    };
+    for rule in rest {
+      rule.visit_with(&mut usage);
+    }
+    let dependencies = usage
+      .usages
+      .into_iter()
+      .filter_map(|(span, name)| {
+        let (value, from) = analyzer.values.get(&name)?;
+        Some(match from {
+          Some(request) => Box::new(CssIcssValueDependency::new(
+            request.to_owned(),
+            value.to_owned(),
+            span.real_lo(),
+            span.real_hi(),
+          )) as BoxDependencyTemplate,
+          None => Box::new(ConstDependency::new(
+            span.real_lo(),
+            span.real_hi(),
+            value.as_str().into(),
+            None,
+          )),
+        })
+      })
+      .collect::<Vec<_>>();
+    analyzer
+      .analysis
+      .presentational_dependencies
+      .extend(dependencies);
+  }
+
+  analyzer.analysis
+}
+
+struct IcssAnalyzer<'a> {
+  source: &'a str,
+  /// Values usable by the module, including the ones imported by `:import`.
+  values: HashMap<String, IcssValue>,
+  analysis: IcssAnalysis,
+}
+
+impl<'a> IcssAnalyzer<'a> {
+  /// Returns `true` if the rule is an ICSS rule.
+  fn analyze_rule(&mut self, rule: &Rule) -> bool {
+    let span = match rule {
+      Rule::AtRule(at_rule)
+        if at_rule
+          .name
+          .as_ident()
+          .is_some_and(|ident| &*ident.value == VALUE_KEYWORD) =>
+      {
+        let Some(definition) = self
+          .text(at_rule.span)
+          .trim()
+          .strip_prefix('@')
+          .and_then(|text| text.strip_prefix(VALUE_KEYWORD))
+        else {
+          return false;
+        };
+        let definition = definition.trim().trim_end_matches(';').trim_end();
+        self.analyze_value_definition(definition);
+        at_rule.span
+      }
+      Rule::QualifiedRule(qualified_rule) => {
+        let prelude_span = Span::new(
+          qualified_rule.span.lo,
+          qualified_rule.block.span.lo,
+          Default::default(),
+        );
+        let prelude = self.text(prelude_span).trim();
+        let block = self.block_declarations(qualified_rule.block.span);
+        if prelude == ":export" {
+          for (name, value) in block {
+            let value = self.lookup(value);
+            self.analysis.exports.insert(name.to_owned(), value);
+          }
+        } else if let Some(captures) = IMPORT_SELECTOR.captures(prelude) {
+          let Some(request) = captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .or_else(|| captures.get(3))
+            .map(|m| m.as_str().to_owned())
+          else {
+            return false;
+          };
+          for (alias, name) in block {
+            self
+              .values
+              .insert(alias.to_owned(), (name.to_owned(), Some(request.clone())));
+          }
+          self.add_request(request);
+        } else {
+          return false;
+        }
+        qualified_rule.span
+      }
+      _ => return false,
+    };
+
+    self
+      .analysis
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        span.real_lo(),
+        span.real_hi(),
+        "".into(),
+        None,
+      )));
+    true
+  }
+
+  fn analyze_value_definition(&mut self, definition: &str) {
+    if let Some(captures) = VALUE_IMPORT.captures(definition) {
+      let request = if let Some(request) = captures.get(2).or_else(|| captures.get(3)) {
+        request.as_str().to_owned()
+      } else {
+        // `@value primary from colors`, where `colors` is a value of the path
+        let Some((path, None)) = self.values.get(&captures[4]) else {
+          return;
+        };
+        path.trim_matches(|c| c == '"' || c == '\'').to_owned()
+      };
+      let names = captures[1]
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+      for name in names.split(',') {
+        let Some(item) = VALUE_IMPORT_ITEM.captures(name.trim()) else {
+          continue;
+        };
+        let name = item[1].to_owned();
+        let alias = item
+          .get(2)
+          .map_or_else(|| name.clone(), |m| m.as_str().to_owned());
+        let value = (name, Some(request.clone()));
+        self.values.insert(alias.clone(), value.clone());
+        self.analysis.exports.insert(alias, value);
+      }
+      self.add_request(request);
+    } else if let Some(captures) = VALUE_DEFINITION.captures(definition) {
+      let name = captures[1].to_owned();
+      let value = self.lookup(captures[2].trim());
+      self.values.insert(name.clone(), value.clone());
+      self.analysis.exports.insert(name, value);
+    }
+  }
+
+  /// The value referred by `text` if it's the name of a value, otherwise the literal `text`.
+  fn lookup(&self, text: &str) -> IcssValue {
+    self
+      .values
+      .get(text)
+      .cloned()
+      .unwrap_or_else(|| (text.to_owned(), None))
+  }
+
+  fn add_request(&mut self, request: String) {
+    if !self.analysis.requests.contains(&request) {
+      self.analysis.requests.push(request);
+    }
+  }
+
+  fn text(&self, span: Span) -> &'a str {
+    self
+      .source
+      .get(span.real_lo() as usize..span.real_hi() as usize)
+      .unwrap_or_default()
+  }
+
+  /// The `name: value` pairs in a block of `:import` or `:export`.
+  fn block_declarations(&self, span: Span) -> Vec<(&'a str, &'a str)> {
+    self
+      .text(span)
+      .trim()
+      .trim_start_matches('{')
+      .trim_end_matches('}')
+      .split(';')
+      .filter_map(|declaration| {
+        let (name, value) = declaration.split_once(':')?;
+        let (name, value) = (name.trim(), value.trim());
+        (!name.is_empty()).then_some((name, value))
+      })
+      .collect()
+  }
+}
+
+/// Collect the identifiers referring to values in declarations and at-rule preludes.
+struct IcssValueUsage<'a> {
+  values: &'a HashMap<String, IcssValue>,
+  in_value: bool,
+  usages: Vec<(Span, String)>,
+}
+
+impl IcssValueUsage<'_> {
+  fn add_usage(&mut self, value: &str, span: Span) {
+    if self.in_value && self.values.contains_key(value) {
+      self.usages.push((span, value.to_owned()));
+    }
+  }
+}
+
+impl Visit for IcssValueUsage<'_> {
+  fn visit_declaration(&mut self, n: &Declaration) {
+    self.in_value = true;
+    n.value.visit_with(self);
+    self.in_value = false;
+  }
+
+  fn visit_at_rule_prelude(&mut self, n: &AtRulePrelude) {
+    self.in_value = true;
+    n.visit_children_with(self);
+    self.in_value = false;
+  }
+
+  fn visit_ident(&mut self, n: &Ident) {
+    self.add_usage(&n.value, n.span);
+  }
+
+  fn visit_token_and_span(&mut self, n: &TokenAndSpan) {
+    if let Token::Ident { value, .. } = &n.token {
+      self.add_usage(value, n.span);
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::swc_css_compiler::SwcCssCompiler;
+
+  fn analyze(source: &str) -> IcssAnalysis {
+    let (stylesheet, _) = SwcCssCompiler::default()
+      .parse_file("test.css", source.to_string(), Default::default())
+      .expect("should parse the stylesheet")
+      .split_into_parts();
+    analyze_icss(&stylesheet, source)
+  }
+
+  fn value(value: &str, from: Option<&str>) -> IcssValue {
+    (value.to_string(), from.map(ToString::to_string))
+  }
+
+  #[test]
+  fn values_and_exports() {
+    let analysis = analyze(
+      r#"@value primary: red;
+@value secondary, tertiary as accent from "./colors.css";
+:import("./sizes.css") {
+  imported-size: size;
+}
+:export {
+  brand: primary;
+  gap: imported-size;
+}
+.button {
+  color: primary;
+  background: accent;
+  margin: imported-size;
+}
+"#,
+    );
+
+    assert_eq!(
+      analysis.exports.into_iter().collect::<Vec<_>>(),
+      vec![
+        ("primary".to_string(), value("red", None)),
+        (
+          "secondary".to_string(),
+          value("secondary", Some("./colors.css"))
+        ),
+        (
+          "accent".to_string(),
+          value("tertiary", Some("./colors.css"))
+        ),
+        ("brand".to_string(), value("red", None)),
+        ("gap".to_string(), value("size", Some("./sizes.css"))),
+      ]
+    );
+    assert_eq!(analysis.requests, vec!["./colors.css", "./sizes.css"]);
+    // 4 removed ICSS rules and 3 replaced usages in `.button`
+    assert_eq!(analysis.presentational_dependencies.len(), 7);
+  }
+
+  #[test]
+  fn values_imported_from_a_value() {
+    let analysis = analyze(
+      r#"@value colors: "./colors.css";
+@value primary from colors;
+"#,
+    );
+
+    assert_eq!(
+      analysis.exports.get("primary"),
+      Some(&value("primary", Some("./colors.css")))
+    );
+    assert_eq!(analysis.requests, vec!["./colors.css"]);
+  }
+
+  #[test]
+  fn no_icss() {
+    let analysis = analyze(".button { color: red; }");
+
+    assert!(analysis.exports.is_empty());
+    assert!(analysis.requests.is_empty());
+    assert!(analysis.presentational_dependencies.is_empty());
+  }
+}
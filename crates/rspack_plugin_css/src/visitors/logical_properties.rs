@@ -0,0 +1,108 @@
+use swc_core::common::EqIgnoreSpan;
+use swc_core::css::ast::{
+  ComponentValue, Declaration, DeclarationName, Ident, SimpleBlock, Stylesheet,
+};
+use swc_core::css::visit::{VisitMut, VisitMutWith};
+
+/// Replace `inset` and the logical properties of margin and padding with the physical ones,
+/// for the browsers which don't support them.
+///
+/// Only the declarations which don't depend on the writing direction are lowered, e.g.
+/// `margin-inline: 1px 2px` is kept since its left and right are swapped in rtl, the vendor
+/// prefixed fallbacks of those are added by the prefixer.
+pub fn lower_logical_properties(ss: &mut Stylesheet) {
+  ss.visit_mut_with(&mut LogicalProperties);
+}
+
+struct LogicalProperties;
+
+impl VisitMut for LogicalProperties {
+  fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+    n.visit_mut_children_with(self);
+
+    let mut value = Vec::with_capacity(n.value.len());
+    for component in std::mem::take(&mut n.value) {
+      match component {
+        ComponentValue::Declaration(declaration)
+          if let Some(physical) = to_physical(&declaration) =>
+        {
+          value.extend(physical.into_iter().map(ComponentValue::Declaration))
+        }
+        component => value.push(component),
+      }
+    }
+    n.value = value;
+  }
+}
+
+/// The physical properties a logical property is lowered to, in the order of the values of
+/// the shorthand.
+fn physical_names(name: &str) -> Option<&'static [&'static str]> {
+  let names: &'static [&'static str] = match name {
+    "inset" => &["top", "right", "bottom", "left"],
+    "inset-block" => &["top", "bottom"],
+    "inset-block-start" => &["top"],
+    "inset-block-end" => &["bottom"],
+    "inset-inline" => &["left", "right"],
+    "margin-block" => &["margin-top", "margin-bottom"],
+    "margin-block-start" => &["margin-top"],
+    "margin-block-end" => &["margin-bottom"],
+    "margin-inline" => &["margin-left", "margin-right"],
+    "padding-block" => &["padding-top", "padding-bottom"],
+    "padding-block-start" => &["padding-top"],
+    "padding-block-end" => &["padding-bottom"],
+    "padding-inline" => &["padding-left", "padding-right"],
+    _ => return None,
+  };
+  Some(names)
+}
+
+/// The physical declarations of a logical one, `None` if it's kept as it is.
+fn to_physical(declaration: &Declaration) -> Option<Vec<Box<Declaration>>> {
+  let DeclarationName::Ident(name) = &declaration.name else {
+    return None;
+  };
+  let logical = name.value.to_ascii_lowercase();
+  let names = physical_names(&logical)?;
+  let values = &declaration.value;
+  // a variable may be substituted by several values, which can't be split here
+  if values.iter().any(|value| {
+    matches!(value, ComponentValue::Function(f) if f.name.as_ident().is_some_and(|ident| {
+      ident.value.eq_ignore_ascii_case("var") || ident.value.eq_ignore_ascii_case("env")
+    }))
+  }) {
+    return None;
+  }
+  let sides = match (names.len(), values.as_slice()) {
+    (4, [all]) => vec![all, all, all, all],
+    (4, [vertical, horizontal]) => vec![vertical, horizontal, vertical, horizontal],
+    (4, [top, horizontal, bottom]) => vec![top, horizontal, bottom, horizontal],
+    (4, [top, right, bottom, left]) => vec![top, right, bottom, left],
+    (2, [both]) => vec![both, both],
+    (2, [start, end]) => vec![start, end],
+    (1, [value]) => vec![value],
+    _ => return None,
+  };
+  // the start of the inline axis is the right in rtl
+  if logical.ends_with("-inline") && !sides[0].eq_ignore_span(sides[1]) {
+    return None;
+  }
+  Some(
+    names
+      .iter()
+      .zip(sides)
+      .map(|(physical, value)| {
+        Box::new(Declaration {
+          span: declaration.span,
+          name: DeclarationName::Ident(Ident {
+            span: name.span,
+            value: (*physical).into(),
+            raw: None,
+          }),
+          value: vec![value.clone()],
+          important: declaration.important.clone(),
+        })
+      })
+      .collect(),
+  )
+}
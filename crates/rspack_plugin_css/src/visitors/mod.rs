@@ -1,3 +1,7 @@
+mod icss;
+mod logical_properties;
+pub use icss::*;
+pub use logical_properties::*;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{BoxDependency, ModuleDependency, SpanExt};
@@ -1,7 +1,12 @@
 #![feature(let_chains)]
 
+mod module_filename_helpers;
+
 use std::collections::HashSet;
-use std::{hash::Hash, path::Path};
+use std::{
+  hash::{Hash, Hasher},
+  path::Path,
+};
 
 use dashmap::DashMap;
 use once_cell::sync::Lazy;
@@ -12,18 +17,56 @@ use rspack_core::Logger;
 use rspack_core::{
   contextify,
   rspack_sources::{BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap},
-  AssetInfo, Compilation, CompilationAsset, JsChunkHashArgs, PathData, Plugin, PluginContext,
-  PluginJsChunkHashHookOutput, PluginProcessAssetsOutput, PluginRenderModuleContentOutput,
-  ProcessAssetsArgs, RenderModuleContentArgs, SourceType,
+  ApplyContext, AssetInfo, Compilation, CompilationAsset, CompilerOptions, Filename,
+  JsChunkHashArgs, PathData, Plugin, PluginContext, PluginJsChunkHashHookOutput,
+  PluginProcessAssetsOutput, PluginRenderModuleContentOutput, ProcessAssetsArgs,
+  RenderModuleContentArgs, SourceType,
 };
 use rspack_error::miette::IntoDiagnostic;
-use rspack_error::{Error, Result};
+use rspack_error::{internal_error, Error, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use rspack_regex::RspackRegex;
 use rspack_util::swc::normalize_custom_filename;
+use rspack_util::try_any_sync;
 use rustc_hash::FxHashMap as HashMap;
 use serde_json::json;
 
+pub use crate::module_filename_helpers::{create_filename, ModuleFilenameTemplateData};
+
 static IS_CSS_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.css($|\?)").expect("TODO:"));
 
+#[derive(Debug, Clone, Hash)]
+pub enum DevtoolRule {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl DevtoolRule {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum DevtoolRules {
+  String(String),
+  Regexp(RspackRegex),
+  Array(Vec<DevtoolRule>),
+}
+
+impl DevtoolRules {
+  pub fn try_match(&self, data: &str) -> Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+      Self::Array(l) => try_any_sync(l, |i| i.try_match(data)),
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct DevtoolPluginOptions {
   pub inline: bool,
@@ -32,6 +75,27 @@ pub struct DevtoolPluginOptions {
   pub columns: bool,
   pub no_sources: bool,
   pub public_path: Option<String>,
+  /// Filename template of the emitted source map, defaults to `output.sourceMapFilename`.
+  /// It's what `SourceMapDevToolPlugin` of webpack uses to choose between inline and external maps.
+  pub filename: Option<Filename>,
+  /// Sources matching these conditions are added to the `ignoreList` of the source map.
+  pub ignore_list: Option<DevtoolRules>,
+  /// If true, a deterministic debug ID is embedded in both the source map and the asset.
+  pub debug_ids: bool,
+  /// Template of the source names in the source map, defaults to `[resource-path]`.
+  pub module_filename_template: Option<String>,
+  /// Template used when the source name generated by `module_filename_template` is already taken.
+  pub fallback_module_filename_template: Option<String>,
+  /// Value of the `sourceRoot` field of the source map.
+  pub source_root: Option<String>,
+  /// The `[file]` of `filename` is relative to this directory.
+  pub file_context: Option<String>,
+  /// Include all assets that pass test assertion.
+  pub test: Option<DevtoolRules>,
+  /// Include all assets matching any of these conditions.
+  pub include: Option<DevtoolRules>,
+  /// Exclude all assets matching any of these conditions.
+  pub exclude: Option<DevtoolRules>,
 }
 
 #[derive(Debug)]
@@ -39,10 +103,19 @@ pub struct DevtoolPlugin {
   inline: bool,
   source_mapping_url_comment: Option<String>,
   module_filename_template: String,
+  fallback_module_filename_template: String,
   namespace: String,
   columns: bool,
   no_sources: bool,
   public_path: Option<String>,
+  filename: Option<Filename>,
+  ignore_list: Option<DevtoolRules>,
+  debug_ids: bool,
+  source_root: Option<String>,
+  file_context: Option<String>,
+  test: Option<DevtoolRules>,
+  include: Option<DevtoolRules>,
+  exclude: Option<DevtoolRules>,
 }
 
 impl DevtoolPlugin {
@@ -52,13 +125,45 @@ impl DevtoolPlugin {
       source_mapping_url_comment: options
         .append
         .then(|| "# sourceMappingURL=[url]".to_string()),
-      module_filename_template: "[resourcePath]".to_string(),
+      module_filename_template: options
+        .module_filename_template
+        .unwrap_or_else(|| "[resource-path]".to_string()),
+      fallback_module_filename_template: options
+        .fallback_module_filename_template
+        .unwrap_or_else(|| "[resource-path]?[hash]".to_string()),
       namespace: options.namespace,
       columns: options.columns,
       no_sources: options.no_sources,
       public_path: options.public_path,
+      filename: options.filename,
+      ignore_list: options.ignore_list,
+      debug_ids: options.debug_ids,
+      source_root: options.source_root,
+      file_context: options.file_context,
+      test: options.test,
+      include: options.include,
+      exclude: options.exclude,
     }
   }
+
+  fn match_object(&self, filename: &str) -> Result<bool> {
+    if let Some(condition) = &self.test {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.include {
+      if !condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    if let Some(condition) = &self.exclude {
+      if condition.try_match(filename)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
+  }
 }
 
 static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
@@ -69,6 +174,23 @@ impl Plugin for DevtoolPlugin {
     "rspack.DevtoolPlugin"
   }
 
+  fn apply(
+    &self,
+    _ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    // `SourceMapDevToolPlugin` used with `devtool: false`, the modules still need to generate
+    // source maps for it
+    if !options.devtool.source_map() {
+      options.devtool.add_source_map();
+      options.devtool.add_module();
+      if !self.columns {
+        options.devtool.add_cheap();
+      }
+    }
+    Ok(())
+  }
+
   fn render_module_content<'a>(
     &'a self,
     _ctx: PluginContext,
@@ -81,7 +203,12 @@ impl Plugin for DevtoolPlugin {
         args.module_source = cached.value().clone();
         return Ok(args);
       } else if let Some(map) = origin_source.map(&MapOptions::new(devtool.cheap())) {
-        let source = wrap_eval_source_map(&origin_source.source(), map, args.compilation)?;
+        let source = wrap_eval_source_map(
+          &origin_source.source(),
+          map,
+          self.ignore_list.as_ref(),
+          args.compilation,
+        )?;
         MODULE_RENDER_CACHE.insert(origin_source, source.clone());
         args.module_source = source;
         return Ok(args);
@@ -97,6 +224,9 @@ impl Plugin for DevtoolPlugin {
   ) -> PluginJsChunkHashHookOutput {
     self.name().hash(&mut args.hasher);
     args.compilation.options.devtool.hash(&mut args.hasher);
+    self.ignore_list.hash(&mut args.hasher);
+    self.debug_ids.hash(&mut args.hasher);
+    self.filename.hash(&mut args.hasher);
     Ok(())
   }
 
@@ -110,46 +240,73 @@ impl Plugin for DevtoolPlugin {
     let no_map =
       !args.compilation.options.devtool.source_map() || args.compilation.options.devtool.eval();
     let context = args.compilation.options.context.clone();
-    let maps: HashMap<String, (Vec<u8>, Option<Vec<u8>>)> = args
+    let maps: HashMap<String, (Vec<u8>, Option<Vec<u8>>, Option<String>)> = args
       .compilation
       .assets_mut()
       .par_iter()
       .filter_map(|(filename, asset)| asset.get_source().map(|s| (filename, s)))
       .map(|(filename, source)| {
+        if !self.match_object(filename)? {
+          return Ok(None);
+        }
+        let mut code_buffer = Vec::new();
+        source.to_writer(&mut code_buffer).into_diagnostic()?;
+        let debug_id = (!no_map && self.debug_ids).then(|| generate_debug_id(&code_buffer));
         let map = (!no_map)
           .then_some(source)
           .and_then(|source| source.map(&MapOptions::new(self.columns)))
           .map(|mut map| {
             map.set_file(Some(filename.clone()));
-            for source in map.sources_mut() {
-              let resource_path = normalize_custom_filename(source);
-              let resource_path = contextify(&context, resource_path);
-              *source = self
-                .module_filename_template
-                .replace("[namespace]", &self.namespace)
-                .replace("[resourcePath]", &resource_path);
+            let mut ignore_list = vec![];
+            let mut used_source_names = HashSet::new();
+            for (index, source) in map.sources_mut().iter_mut().enumerate() {
+              let identifier = normalize_custom_filename(source);
+              if let Some(rules) = &self.ignore_list
+                && rules.try_match(identifier)?
+              {
+                ignore_list.push(index);
+              }
+              let template_data = || ModuleFilenameTemplateData {
+                identifier,
+                context: context.as_str(),
+                namespace: &self.namespace,
+              };
+              let mut source_name =
+                create_filename(&self.module_filename_template, template_data());
+              if used_source_names.contains(&source_name) {
+                source_name =
+                  create_filename(&self.fallback_module_filename_template, template_data());
+              }
+              while used_source_names.contains(&source_name) {
+                source_name.push('*');
+              }
+              used_source_names.insert(source_name.clone());
+              *source = source_name;
             }
             if self.no_sources {
               for content in map.sources_content_mut() {
                 *content = String::default();
               }
             }
-            let mut map_buffer = Vec::new();
-            map
-              .to_writer(&mut map_buffer)
-              .unwrap_or_else(|e| panic!("{}", e.to_string()));
+            let map_buffer = write_source_map(
+              &map,
+              &ignore_list,
+              self.source_root.as_deref(),
+              debug_id.as_deref(),
+            )?;
             Ok::<Vec<u8>, Error>(map_buffer)
           })
           .transpose()?;
-        let mut code_buffer = Vec::new();
-        source.to_writer(&mut code_buffer).into_diagnostic()?;
-        Ok((filename.to_owned(), (code_buffer, map)))
+        Ok(Some((filename.to_owned(), (code_buffer, map, debug_id))))
       })
-      .collect::<Result<_>>()?;
+      .collect::<Result<Vec<_>>>()?
+      .into_iter()
+      .flatten()
+      .collect();
     logger.time_end(start);
 
     let start = logger.time("emit source map assets");
-    for (filename, (code_buffer, map_buffer)) in maps {
+    for (filename, (code_buffer, map_buffer, debug_id)) in maps {
       let mut asset = args
         .compilation
         .assets_mut()
@@ -163,6 +320,16 @@ impl Plugin for DevtoolPlugin {
         continue;
       };
       let is_css = IS_CSS_FILE.is_match(&filename);
+      let raw_source = if let Some(debug_id) = debug_id {
+        let debug_id_comment = if is_css {
+          format!("\n/*# debugId={debug_id}*/")
+        } else {
+          format!("\n//# debugId={debug_id}")
+        };
+        ConcatSource::new([raw_source, RawSource::from(debug_id_comment).boxed()]).boxed()
+      } else {
+        raw_source
+      };
       let current_source_mapping_url_comment =
         self.source_mapping_url_comment.as_ref().map(|comment| {
           if is_css {
@@ -172,8 +339,11 @@ impl Plugin for DevtoolPlugin {
           }
         });
       if self.inline {
-        let current_source_mapping_url_comment = current_source_mapping_url_comment
-          .expect("DevToolPlugin: append can't be false when inline is true.");
+        let Some(current_source_mapping_url_comment) = current_source_mapping_url_comment else {
+          return Err(internal_error!(
+            "SourceMapDevToolPlugin: append can't be false when no filename is provided."
+          ));
+        };
         let base64 = rspack_base64::encode_to_string(&map_buffer);
         asset.source = Some(
           ConcatSource::new([
@@ -193,8 +363,15 @@ impl Plugin for DevtoolPlugin {
         let mut source_map_filename = filename.to_owned() + ".map";
         // TODO(ahabhgk): refactor remove the for loop
         // https://webpack.docschina.org/configuration/output/#outputsourcemapfilename
-        if args.compilation.options.devtool.source_map() {
-          let source_map_filename_config = &args.compilation.options.output.source_map_filename;
+        let source_map_filename_config = self.filename.as_ref().or_else(|| {
+          args
+            .compilation
+            .options
+            .devtool
+            .source_map()
+            .then_some(&args.compilation.options.output.source_map_filename)
+        });
+        if let Some(source_map_filename_config) = source_map_filename_config {
           for chunk in args.compilation.chunk_by_ukey.values() {
             let files: HashSet<String> =
               chunk.files.union(&chunk.auxiliary_files).cloned().collect();
@@ -206,6 +383,12 @@ impl Plugin for DevtoolPlugin {
                 } else {
                   &SourceType::JavaScript
                 };
+                let filename = self
+                  .file_context
+                  .as_ref()
+                  .and_then(|file_context| diff_paths(&filename, file_context))
+                  .map(|relative| relative.to_string_lossy().into_owned())
+                  .unwrap_or_else(|| filename.clone());
                 source_map_filename = args.compilation.get_asset_path(
                   source_map_filename_config,
                   PathData::default()
@@ -269,10 +452,17 @@ impl Plugin for DevtoolPlugin {
 pub fn wrap_eval_source_map(
   source: &str,
   mut map: SourceMap,
+  ignore_list_rules: Option<&DevtoolRules>,
   compilation: &Compilation,
 ) -> Result<BoxSource> {
-  for source in map.sources_mut() {
+  let mut ignore_list = vec![];
+  for (index, source) in map.sources_mut().iter_mut().enumerate() {
     let resource_path = normalize_custom_filename(source);
+    if let Some(rules) = ignore_list_rules
+      && rules.try_match(resource_path)?
+    {
+      ignore_list.push(index);
+    }
     let resource_path = contextify(&compilation.options.context, resource_path);
     *source = resource_path;
   }
@@ -281,13 +471,62 @@ pub fn wrap_eval_source_map(
       *content = String::default();
     }
   }
-  let mut map_buffer = Vec::new();
-  map
-    .to_writer(&mut map_buffer)
-    .unwrap_or_else(|e| panic!("{}", e.to_string()));
+  let map_buffer = write_source_map(&map, &ignore_list, None, None)?;
   let base64 = rspack_base64::encode_to_string(&map_buffer);
   let footer =
     format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
   let result = RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed();
   Ok(result)
 }
+
+/// Serializes the source map, adding the fields `rspack_sources` does not know about.
+fn write_source_map(
+  map: &SourceMap,
+  ignore_list: &[usize],
+  source_root: Option<&str>,
+  debug_id: Option<&str>,
+) -> Result<Vec<u8>> {
+  let mut map_buffer = Vec::new();
+  map
+    .to_writer(&mut map_buffer)
+    .map_err(|e| internal_error!(e.to_string()))?;
+  if ignore_list.is_empty() && source_root.is_none() && debug_id.is_none() {
+    return Ok(map_buffer);
+  }
+
+  let mut json: serde_json::Map<String, serde_json::Value> =
+    serde_json::from_slice(&map_buffer).into_diagnostic()?;
+  if !ignore_list.is_empty() {
+    // `x_google_ignoreList` is the name used before `ignoreList` got into the spec,
+    // emit both to support older devtools.
+    json.insert("ignoreList".to_string(), json!(ignore_list));
+    json.insert("x_google_ignoreList".to_string(), json!(ignore_list));
+  }
+  if let Some(source_root) = source_root {
+    json.insert("sourceRoot".to_string(), json!(source_root));
+  }
+  if let Some(debug_id) = debug_id {
+    json.insert("debugId".to_string(), json!(debug_id));
+  }
+  serde_json::to_vec(&json).into_diagnostic()
+}
+
+/// Generates a UUID formatted debug ID from the content of the asset,
+/// so the same output always gets the same ID.
+/// https://github.com/tc39/source-map/blob/main/proposals/debug-id.md
+fn generate_debug_id(content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::MD4);
+  hasher.write(content);
+  let hash = hasher.digest(&HashDigest::Hex);
+  let hash = hash.encoded();
+  let variant = (u8::from_str_radix(&hash[16..17], 16).expect("should be a hex digit") & 3) | 8;
+  format!(
+    "{}-{}-4{}-{:x}{}-{}",
+    &hash[0..8],
+    &hash[8..12],
+    &hash[13..16],
+    variant,
+    &hash[17..20],
+    &hash[20..32]
+  )
+}
@@ -0,0 +1,109 @@
+// https://github.com/webpack/webpack/blob/main/lib/ModuleFilenameHelpers.js
+
+use std::hash::Hasher;
+
+use rspack_core::contextify;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+
+/// Information extracted from a source name of a source map,
+/// which is a module identifier like `loader1!loader2!/path/to/resource?query`.
+#[derive(Debug)]
+pub struct ModuleFilenameTemplateData<'a> {
+  pub identifier: &'a str,
+  pub context: &'a str,
+  pub namespace: &'a str,
+}
+
+fn get_hash(identifier: &str) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::MD4);
+  hasher.write(identifier.as_bytes());
+  let digest = hasher.digest(&HashDigest::Hex);
+  digest.rendered(4).to_string()
+}
+
+fn split_query(resource: &str) -> (&str, &str) {
+  match resource.find('?') {
+    Some(index) => resource.split_at(index),
+    None => (resource, ""),
+  }
+}
+
+fn split_loaders(request: &str) -> (&str, &str) {
+  match request.rfind('!') {
+    Some(index) => (&request[..index], &request[index + 1..]),
+    None => ("", request),
+  }
+}
+
+/// Renders a module filename template, supports the placeholders of webpack's
+/// `output.devtoolModuleFilenameTemplate`, both the kebab case and the legacy camel case ones.
+pub fn create_filename(template: &str, data: ModuleFilenameTemplateData) -> String {
+  let short_identifier = contextify(data.context, data.identifier);
+  let (_, absolute_resource) = split_loaders(data.identifier);
+  let (short_all_loaders, resource) = split_loaders(&short_identifier);
+  let (absolute_resource_path, _) = split_query(absolute_resource);
+  let (resource_path, query) = split_query(resource);
+  let loaders = short_all_loaders
+    .split('!')
+    .filter(|loader| !loader.is_empty())
+    .map(|loader| split_query(loader).0)
+    .collect::<Vec<_>>()
+    .join("!");
+  let folder = resource_path
+    .rfind('/')
+    .map(|index| &resource_path[..index])
+    .unwrap_or_default();
+
+  let mut filename = template.to_string();
+  let replacements: [(&[&str], &str); 10] = [
+    (&["[identifier]"], &short_identifier),
+    (&["[resource]"], resource),
+    (&["[resource-path]", "[resourcePath]"], resource_path),
+    (
+      &["[absolute-resource-path]", "[absoluteResourcePath]"],
+      absolute_resource_path,
+    ),
+    (&["[all-loaders]", "[allLoaders]"], short_all_loaders),
+    (&["[loaders]"], &loaders),
+    (&["[query]"], query),
+    (&["[folder]"], folder),
+    (&["[namespace]"], data.namespace),
+    (&["[hash]"], &get_hash(data.identifier)),
+  ];
+  for (placeholders, value) in replacements {
+    for placeholder in placeholders {
+      if filename.contains(placeholder) {
+        filename = filename.replace(placeholder, value);
+      }
+    }
+  }
+  filename
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn create_filename_with_placeholders() {
+    let data = ModuleFilenameTemplateData {
+      identifier: "/app/node_modules/loader/index.js??ref!/app/src/index.js?raw",
+      context: "/app",
+      namespace: "app",
+    };
+    assert_eq!(
+      create_filename("webpack://[namespace]/[resource-path]", data),
+      "webpack://app/./src/index.js"
+    );
+
+    let data = ModuleFilenameTemplateData {
+      identifier: "/app/node_modules/loader/index.js??ref!/app/src/index.js?raw",
+      context: "/app",
+      namespace: "app",
+    };
+    assert_eq!(
+      create_filename("[loaders]|[query]|[folder]", data),
+      "./node_modules/loader/index.js|?raw|./src"
+    );
+  }
+}
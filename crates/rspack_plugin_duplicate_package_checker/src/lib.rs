@@ -0,0 +1,194 @@
+// https://github.com/darrenscerri/duplicate-package-checker-webpack-plugin
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use rspack_core::{BoxModule, Compilation, Logger, ModuleIdentifier, Plugin};
+use rspack_error::{Diagnostic, Result};
+use rspack_regex::RspackRegex;
+use rustc_hash::FxHashMap as HashMap;
+use semver::Version;
+
+#[derive(Debug)]
+pub enum DuplicatePackageExcludeRule {
+  /// Matches the package name exactly.
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl DuplicatePackageExcludeRule {
+  pub fn try_match(&self, name: &str) -> bool {
+    match self {
+      Self::String(s) => s == name,
+      Self::Regexp(r) => r.test(name),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+pub struct DuplicatePackageCheckerPluginOptions {
+  /// If true, the issuer chain of every duplicated package is printed.
+  pub verbose: bool,
+  /// If true, duplicates are reported as errors instead of warnings.
+  pub emit_error: bool,
+  /// Packages whose name matches any of these rules are never reported.
+  pub exclude: Vec<DuplicatePackageExcludeRule>,
+}
+
+#[derive(Debug)]
+struct PackageInstance {
+  version: String,
+  /// Directory containing the package.json
+  path: PathBuf,
+  /// The first module (ordered by identifier) resolved into this package
+  module: ModuleIdentifier,
+}
+
+#[derive(Debug)]
+pub struct DuplicatePackageCheckerPlugin {
+  options: DuplicatePackageCheckerPluginOptions,
+}
+
+impl DuplicatePackageCheckerPlugin {
+  pub fn new(options: DuplicatePackageCheckerPluginOptions) -> Self {
+    Self { options }
+  }
+
+  fn is_excluded(&self, name: &str) -> bool {
+    self.options.exclude.iter().any(|rule| rule.try_match(name))
+  }
+
+  fn issuer_chain(&self, module: &BoxModule, compilation: &Compilation) -> Vec<String> {
+    let context = &compilation.options.context;
+    let mut chain = vec![];
+    let mut current_issuer = compilation.module_graph.get_issuer(module);
+    while let Some(issuer) = current_issuer {
+      chain.push(issuer.readable_identifier(context).to_string());
+      current_issuer = compilation.module_graph.get_issuer(issuer);
+    }
+    chain
+  }
+
+  fn create_duplicate_package_message(
+    &self,
+    name: &str,
+    instances: &[PackageInstance],
+    compilation: &Compilation,
+  ) -> String {
+    let context: &Path = compilation.options.context.as_ref();
+    let mut message = format!("Multiple versions of \"{name}\" found in the module graph:\n");
+    for instance in instances {
+      let path = instance
+        .path
+        .strip_prefix(context)
+        .unwrap_or(&instance.path)
+        .display();
+      message.push_str(&format!(
+        "  - {}@{} from ./{}\n",
+        name, instance.version, path
+      ));
+      if !self.options.verbose {
+        continue;
+      }
+      if let Some(module) = compilation
+        .module_graph
+        .module_by_identifier(&instance.module)
+      {
+        for issuer in self.issuer_chain(module, compilation) {
+          message.push_str(&format!("    <- {issuer}\n"));
+        }
+      }
+    }
+    message
+  }
+}
+
+/// Compare the versions by semver, the ones that don't parse go last, ordered as strings.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+  match (Version::parse(a), Version::parse(b)) {
+    (Ok(a), Ok(b)) => a.cmp(&b),
+    (Ok(_), Err(_)) => Ordering::Less,
+    (Err(_), Ok(_)) => Ordering::Greater,
+    (Err(_), Err(_)) => a.cmp(b),
+  }
+}
+
+// This Plugin reports packages that are bundled in more than one version,
+// which usually means an incompatible semver range somewhere in the dependency tree
+// it is executed in hook `compilation.finishModules`
+#[async_trait]
+impl Plugin for DuplicatePackageCheckerPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DuplicatePackageCheckerPlugin"
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let logger = compilation.get_logger(self.name());
+    let start = logger.time("check duplicate packages");
+
+    // sort by module identifier, guarantee the first module of each package is stable
+    let mut modules = compilation
+      .module_graph
+      .modules()
+      .values()
+      .collect::<Vec<_>>();
+    modules.sort_by_key(|m| m.identifier());
+
+    let mut packages: HashMap<String, Vec<PackageInstance>> = HashMap::default();
+    for module in modules {
+      let Some(normal_module) = module.as_normal_module() else {
+        continue;
+      };
+      let Some(description) = &normal_module.resource_resolved_data().resource_description else {
+        continue;
+      };
+      let json = description.json();
+      let (Some(name), Some(version)) = (
+        json.get("name").and_then(|v| v.as_str()),
+        json.get("version").and_then(|v| v.as_str()),
+      ) else {
+        continue;
+      };
+      if self.is_excluded(name) {
+        continue;
+      }
+
+      let instances = packages.entry(name.to_string()).or_default();
+      if instances.iter().any(|i| i.path == description.path()) {
+        continue;
+      }
+      instances.push(PackageInstance {
+        version: version.to_string(),
+        path: description.path().to_path_buf(),
+        module: module.identifier(),
+      });
+    }
+
+    // sort by package name, guarantee the diagnostic order
+    let mut packages = packages
+      .into_iter()
+      .filter(|(_, instances)| instances.iter().any(|i| i.version != instances[0].version))
+      .collect::<Vec<_>>();
+    packages.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let diagnostics = packages
+      .into_iter()
+      .map(|(name, mut instances)| {
+        instances
+          .sort_by(|a, b| compare_versions(&a.version, &b.version).then(a.path.cmp(&b.path)));
+        let title = "Duplicate Package".to_string();
+        let message = self.create_duplicate_package_message(&name, &instances, compilation);
+        if self.options.emit_error {
+          Diagnostic::error(title, message)
+        } else {
+          Diagnostic::warn(title, message)
+        }
+      })
+      .collect::<Vec<_>>();
+
+    compilation.push_batch_diagnostic(diagnostics);
+    logger.time_end(start);
+    Ok(())
+  }
+}
@@ -1,5 +1,5 @@
 use rspack_core::{
-  impl_runtime_module,
+  impl_runtime_module, render_runtime_template,
   rspack_sources::{BoxSource, RawSource, SourceExt},
   Compilation, RuntimeModule,
 };
@@ -23,8 +23,12 @@ impl RuntimeModule for HotModuleReplacementRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/hot_module_replacement.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    RawSource::from(render_runtime_template(
+      &compilation.options.output.environment,
+      include_str!("runtime/hot_module_replacement.js"),
+    ))
+    .boxed()
   }
 }
 
@@ -134,7 +134,7 @@ function createModuleHotObject(moduleId, me) {
 			if (idx >= 0) hot._disposeHandlers.splice(idx, 1);
 		},
 		invalidate: function () {
-			this._selfInvalidated = true;
+			hot._selfInvalidated = true;
 			switch (currentStatus) {
 				case "idle":
 					currentUpdateApplyHandlers = [];
@@ -1,6 +1,5 @@
 use std::{
   collections::hash_map::DefaultHasher,
-  fs,
   hash::{Hash, Hasher},
   path::{Path, PathBuf},
 };
@@ -15,6 +14,7 @@ use rspack_core::{
   CompilationAsset, Filename, PathData, Plugin,
 };
 use rspack_error::AnyhowError;
+use rspack_fs::AsyncReadableFileSystem;
 use serde::Deserialize;
 use swc_html::visit::VisitMutWith;
 
@@ -75,13 +75,17 @@ impl Plugin for HtmlRspackPlugin {
         AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
       );
 
-      let content = fs::read_to_string(&resolved_template)
-        .context(format!(
-          "failed to read `{}` from `{}`",
-          resolved_template.display(),
-          &compilation.options.context
-        ))
-        .map_err(AnyhowError::from)?;
+      let content =
+        AsyncReadableFileSystem::read(&*compilation.input_filesystem, &resolved_template)
+          .await
+          .map_err(anyhow::Error::from)
+          .and_then(|content| String::from_utf8(content).map_err(anyhow::Error::from))
+          .context(format!(
+            "failed to read `{}` from `{}`",
+            resolved_template.display(),
+            &compilation.options.context
+          ))
+          .map_err(AnyhowError::from)?;
 
       let url = resolved_template.to_string_lossy().to_string();
       compilation.file_dependencies.insert(resolved_template);
@@ -204,13 +208,15 @@ impl Plugin for HtmlRspackPlugin {
       let favicon_file_path = PathBuf::from(config.get_relative_path(compilation, favicon));
 
       let resolved_favicon = AsRef::<Path>::as_ref(&compilation.options.context).join(url.path());
-      let content = fs::read(resolved_favicon)
-        .context(format!(
-          "failed to read `{}` from `{}`",
-          url.path(),
-          &compilation.options.context
-        ))
-        .map_err(AnyhowError::from)?;
+      let content =
+        AsyncReadableFileSystem::read(&*compilation.input_filesystem, &resolved_favicon)
+          .await
+          .context(format!(
+            "failed to read `{}` from `{}`",
+            url.path(),
+            &compilation.options.context
+          ))
+          .map_err(AnyhowError::from)?;
       compilation.emit_asset(
         favicon_file_path.to_string_lossy().to_string(),
         CompilationAsset::from(RawSource::from(content).boxed()),
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use rspack_ast::RspackAst;
 use rspack_core::rspack_sources::{
   BoxSource, MapOptions, OriginalSource, RawSource, ReplaceSource, Source, SourceExt, SourceMap,
@@ -16,17 +18,34 @@ use swc_core::common::SyntaxContext;
 
 use crate::ast::CodegenOptions;
 use crate::inner_graph_plugin::InnerGraphPlugin;
+use crate::parser_plugin::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
 use crate::utils::syntax_by_module_type;
 use crate::visitors::ScanDependenciesResult;
 use crate::visitors::{run_before_pass, scan_dependencies, swc_visitor::resolver};
 use crate::{SideEffectsFlagPluginVisitor, SyntaxContextInfo};
-#[derive(Debug)]
-pub struct JavaScriptParserAndGenerator;
+#[derive(Default)]
+pub struct JavaScriptParserAndGenerator {
+  parser_plugins: Vec<Arc<dyn JavascriptParserPlugin>>,
+}
+
+impl std::fmt::Debug for JavaScriptParserAndGenerator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JavaScriptParserAndGenerator")
+      .field("parser_plugins", &"{..}")
+      .finish()
+  }
+}
 
 #[allow(unused)]
 impl JavaScriptParserAndGenerator {
   pub(crate) fn new() -> Self {
-    Self {}
+    Self::default()
+  }
+
+  /// Register a plugin to the hooks of the [JavascriptParser](crate::visitors::JavascriptParser),
+  /// it's called after the builtin plugins.
+  pub fn add_parser_plugin(&mut self, plugin: BoxJavascriptParserPlugin) {
+    self.parser_plugins.push(plugin.into());
   }
 
   fn source_block(
@@ -84,6 +103,7 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       module_type,
       resource_data,
       compiler_options,
+      definitions,
       build_info,
       build_meta,
       module_identifier,
@@ -182,16 +202,18 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
       mut rewrite_usage_span,
       import_map,
       mut warning_diagnostics,
-    } = match ast.visit(|program, context| {
+    } = match ast.transform(|program, context| {
       scan_dependencies(
         program,
         context.unresolved_mark,
         resource_data,
         compiler_options,
+        definitions,
         module_type,
         build_info,
         build_meta,
         module_identifier,
+        &self.parser_plugins,
       )
     }) {
       Ok(result) => result,
@@ -0,0 +1,154 @@
+use rspack_core::{ConstDependency, SpanExt};
+use swc_core::common::{Span, Spanned};
+use swc_core::ecma::ast::{ArrowExpr, BlockStmt, Bool, Class, CondExpr, Expr, Function, Ident};
+use swc_core::ecma::ast::{IfStmt, Lit, Number, Stmt, VarDecl, VarDeclKind};
+use swc_core::ecma::utils::find_pat_ids;
+use swc_core::ecma::visit::{noop_visit_mut_type, noop_visit_type, Visit, VisitMut};
+use swc_core::ecma::visit::{VisitMutWith, VisitWith};
+
+use super::JavascriptParserPlugin;
+use crate::visitors::JavascriptParser;
+
+/// Port of `ConstPlugin` in webpack, replaces the tests of the `if` statements and the conditional
+/// expressions which are always truthy or falsy with `true` or `false`, and their dead branches.
+pub struct ConstPlugin;
+
+impl JavascriptParserPlugin for ConstPlugin {
+  fn statement_if(&self, parser: &mut JavascriptParser, stmt: &IfStmt) -> Option<bool> {
+    let bool = fold_test(parser, &stmt.test)?;
+    let branch_to_remove = if bool {
+      stmt.alt.as_deref()
+    } else {
+      Some(&*stmt.cons)
+    };
+    if let Some(branch) = branch_to_remove {
+      // the `var` declarations in the dead branch are still hoisted
+      let declarations = hoisted_declarations(branch);
+      let replacement = if declarations.is_empty() {
+        "{}".to_string()
+      } else {
+        format!("{{ var {}; }}", declarations.join(", "))
+      };
+      replace(parser, branch.span(), replacement, None);
+    }
+    Some(bool)
+  }
+
+  fn expression_conditional_operation(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &CondExpr,
+  ) -> Option<bool> {
+    let bool = fold_test(parser, &expr.test)?;
+    // expressions are never hoisted, so the dead branch can be replaced as a whole
+    let branch_to_remove = if bool { &expr.alt } else { &expr.cons };
+    replace(parser, branch_to_remove.span(), "0".to_string(), None);
+    Some(bool)
+  }
+}
+
+/// Replace the test with `true` or `false` if it's always truthy or falsy and has no side effects,
+/// the test is walked instead if it could have side effects.
+fn fold_test(parser: &mut JavascriptParser, test: &Expr) -> Option<bool> {
+  let param = parser.evaluate_expression(test);
+  let bool = param.as_bool()?;
+  if param.could_have_side_effects() {
+    test.visit_with(parser);
+  } else {
+    replace(parser, test.span(), bool.to_string(), Some(bool));
+  }
+  Some(bool)
+}
+
+fn replace(parser: &mut JavascriptParser, span: Span, code: String, folded: Option<bool>) {
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      span.real_lo(),
+      span.real_hi(),
+      code.into(),
+      None,
+    )));
+  parser.const_replacements.push((span, folded));
+}
+
+/// The names declared by `var` in the statement, excluding the ones in the nested functions.
+fn hoisted_declarations(stmt: &Stmt) -> Vec<String> {
+  #[derive(Default)]
+  struct HoistedDeclarations(Vec<String>);
+
+  impl Visit for HoistedDeclarations {
+    noop_visit_type!();
+
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+      if var_decl.kind == VarDeclKind::Var {
+        self.0.extend(
+          find_pat_ids::<_, Ident>(&var_decl.decls)
+            .into_iter()
+            .map(|ident| ident.sym.to_string()),
+        );
+      }
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_class(&mut self, _: &Class) {}
+  }
+
+  let mut declarations = HoistedDeclarations::default();
+  stmt.visit_with(&mut declarations);
+  declarations.0
+}
+
+/// Replace the nodes replaced by the [ConstPlugin] in the ast too, so the scanners running after
+/// the [JavascriptParser] don't walk them, e.g. to collect the dependencies in the dead branches.
+pub struct ConstReplacedRemover<'a> {
+  const_replacements: &'a [(Span, Option<bool>)],
+}
+
+impl<'a> ConstReplacedRemover<'a> {
+  pub fn new(const_replacements: &'a [(Span, Option<bool>)]) -> Self {
+    Self { const_replacements }
+  }
+
+  fn find(&self, span: Span) -> Option<Option<bool>> {
+    self
+      .const_replacements
+      .iter()
+      .find(|(replaced, _)| *replaced == span)
+      .map(|(_, folded)| *folded)
+  }
+}
+
+impl VisitMut for ConstReplacedRemover<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_stmt(&mut self, stmt: &mut Stmt) {
+    let span = stmt.span();
+    if self.find(span).is_some() {
+      *stmt = Stmt::Block(BlockStmt {
+        span,
+        stmts: vec![],
+      });
+      return;
+    }
+    stmt.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_expr(&mut self, expr: &mut Expr) {
+    let span = expr.span();
+    match self.find(span) {
+      Some(Some(value)) => *expr = Expr::Lit(Lit::Bool(Bool { span, value })),
+      Some(None) => {
+        *expr = Expr::Lit(Lit::Num(Number {
+          span,
+          value: 0.0,
+          raw: None,
+        }))
+      }
+      None => expr.visit_mut_children_with(self),
+    }
+  }
+}
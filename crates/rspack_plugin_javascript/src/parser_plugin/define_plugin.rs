@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use rspack_core::Define;
+use swc_core::common::{FileName, SourceMap, SyntaxContext};
+use swc_core::ecma::ast::{EsVersion, Expr, Ident, Lit, UnaryExpr, UnaryOp};
+use swc_core::ecma::parser::{parse_file_as_expr, EsConfig, Syntax};
+
+use super::JavascriptParserPlugin;
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+
+/// Evaluates the free variables defined for the compilation to the value of the code they are
+/// defined as, so the branches depending on them can be eliminated.
+pub struct DefineParserPlugin {
+  definitions: Define,
+}
+
+impl DefineParserPlugin {
+  pub fn new(definitions: Define) -> Self {
+    Self { definitions }
+  }
+}
+
+impl JavascriptParserPlugin for DefineParserPlugin {
+  fn evaluate_typeof(
+    &self,
+    expression: &Ident,
+    start: u32,
+    end: u32,
+    unresolved_mark: SyntaxContext,
+  ) -> Option<BasicEvaluatedExpression> {
+    if expression.span.ctxt != unresolved_mark {
+      return None;
+    }
+    let code = self.definitions.get(expression.sym.as_str())?;
+    let type_of = typeof_definition(&parse_definition(code)?)?;
+    Some(eval::evaluate_to_string(type_of.to_string(), start, end))
+  }
+
+  fn evaluate_identifier(
+    &self,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    let code = self.definitions.get(ident)?;
+    evaluate_definition(&parse_definition(code)?, start, end)
+  }
+}
+
+/// Parse the code of a definition, `None` if it's not a valid expression.
+pub fn parse_definition(code: &str) -> Option<Box<Expr>> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Anon, code.to_string());
+  parse_file_as_expr(
+    &fm,
+    Syntax::Es(EsConfig::default()),
+    EsVersion::EsNext,
+    None,
+    &mut vec![],
+  )
+  .ok()
+}
+
+fn evaluate_definition(expr: &Expr, start: u32, end: u32) -> Option<BasicEvaluatedExpression> {
+  let mut res = BasicEvaluatedExpression::with_range(start, end);
+  match expr {
+    Expr::Lit(Lit::Str(str)) => res.set_string(str.value.to_string()),
+    Expr::Lit(Lit::Bool(bool)) => {
+      res.set_bool(bool.value);
+      res.set_side_effects(false);
+    }
+    Expr::Lit(Lit::Null(_)) => res.set_null(),
+    Expr::Lit(Lit::Num(num)) => res.set_number(num.value),
+    Expr::Lit(Lit::BigInt(bigint)) => res.set_bigint(*bigint.value.clone()),
+    Expr::Ident(ident) if &*ident.sym == "undefined" => res.set_undefined(),
+    Expr::Unary(UnaryExpr {
+      op: op @ (UnaryOp::Minus | UnaryOp::Plus),
+      arg: box Expr::Lit(Lit::Num(num)),
+      ..
+    }) => res.set_number(if *op == UnaryOp::Minus {
+      -num.value
+    } else {
+      num.value
+    }),
+    Expr::Paren(paren) => return evaluate_definition(&paren.expr, start, end),
+    _ => return None,
+  }
+  Some(res)
+}
+
+/// The result of `typeof` of the code of a definition, `None` if it's unknown.
+pub fn typeof_definition(expr: &Expr) -> Option<&'static str> {
+  match expr {
+    Expr::Fn(_) | Expr::Arrow(_) | Expr::Class(_) => Some("function"),
+    Expr::Object(_) | Expr::Array(_) | Expr::Lit(Lit::Regex(_)) => Some("object"),
+    Expr::Paren(paren) => typeof_definition(&paren.expr),
+    _ => evaluate_definition(expr, 0, 0)?.as_typeof(),
+  }
+}
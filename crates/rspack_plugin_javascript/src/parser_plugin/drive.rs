@@ -1,20 +1,34 @@
+use std::sync::Arc;
+
+use swc_core::ecma::ast::{CallExpr, CondExpr, Ident, IfStmt, ImportDecl, MemberExpr};
+use swc_core::ecma::ast::{ModuleDecl, NewExpr, Stmt};
+
 use super::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
 use crate::utils::eval::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
 
 pub struct JavaScriptParserPluginDrive {
-  plugins: Vec<BoxJavascriptParserPlugin>,
+  plugins: Vec<Arc<dyn JavascriptParserPlugin>>,
 }
 
 impl JavaScriptParserPluginDrive {
   pub fn new(plugins: Vec<BoxJavascriptParserPlugin>) -> Self {
-    Self { plugins }
+    Self {
+      plugins: plugins.into_iter().map(Arc::from).collect(),
+    }
+  }
+
+  /// Append the plugins registered from outside, they are called after the builtin ones.
+  pub fn with_plugins(mut self, plugins: &[Arc<dyn JavascriptParserPlugin>]) -> Self {
+    self.plugins.extend(plugins.iter().cloned());
+    self
   }
 }
 
 impl JavascriptParserPlugin for JavaScriptParserPluginDrive {
   fn evaluate_typeof(
     &self,
-    expression: &swc_core::ecma::ast::Ident,
+    expression: &Ident,
     start: u32,
     end: u32,
     unresolved_mark: swc_core::common::SyntaxContext,
@@ -27,4 +41,111 @@ impl JavascriptParserPlugin for JavaScriptParserPluginDrive {
     }
     None
   }
+
+  fn evaluate_identifier(
+    &self,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    for plugin in &self.plugins {
+      let res = plugin.evaluate_identifier(ident, start, end);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn statement(&self, parser: &mut JavascriptParser, stmt: &Stmt) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.statement(parser, stmt);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn statement_if(&self, parser: &mut JavascriptParser, stmt: &IfStmt) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.statement_if(parser, stmt);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn expression_conditional_operation(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &CondExpr,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.expression_conditional_operation(parser, expr);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn import(&self, parser: &mut JavascriptParser, import_decl: &ImportDecl, source: &str) {
+    for plugin in &self.plugins {
+      plugin.import(parser, import_decl, source);
+    }
+  }
+
+  fn export(&self, parser: &mut JavascriptParser, module_decl: &ModuleDecl) {
+    for plugin in &self.plugins {
+      plugin.export(parser, module_decl);
+    }
+  }
+
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.call(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn new_expression(&self, parser: &mut JavascriptParser, expr: &NewExpr, for_name: &str) {
+    for plugin in &self.plugins {
+      plugin.new_expression(parser, expr, for_name);
+    }
+  }
+
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.member(parser, expr, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    for plugin in &self.plugins {
+      let res = plugin.identifier(parser, ident, for_name);
+      if res.is_some() {
+        return res;
+      }
+    }
+    None
+  }
 }
@@ -1,5 +1,11 @@
+mod common_js_imports_parse_plugin;
+mod const_plugin;
+mod define_plugin;
 mod drive;
 mod r#trait;
 
+pub(crate) use self::common_js_imports_parse_plugin::CommonJsImportsParserPlugin;
+pub(crate) use self::const_plugin::{ConstPlugin, ConstReplacedRemover};
+pub use self::define_plugin::{parse_definition, typeof_definition, DefineParserPlugin};
 pub use self::drive::JavaScriptParserPluginDrive;
 pub use self::r#trait::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
@@ -1,13 +1,100 @@
+use swc_core::ecma::ast::{CallExpr, CondExpr, Ident, IfStmt, ImportDecl, MemberExpr};
+use swc_core::ecma::ast::{ModuleDecl, NewExpr, Stmt};
+
 use crate::utils::eval::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
 
-pub trait JavascriptParserPlugin {
+/// Hooks of the [JavascriptParser], port of `JavascriptParser.hooks` in webpack.
+///
+/// The hooks returning `Option<bool>` are bailing hooks, the first plugin returning `Some` wins,
+/// `Some(true)` means the node is handled by the plugin and its children are not walked anymore.
+/// The hooks returning nothing are additive, every plugin is called, the harmony imports and
+/// exports, `new Worker()` and `new URL()` are always collected by the builtin scanners, so the
+/// plugins can only add the dependencies of their own to them.
+/// The `for_name` of a hook is the name of the free variable, `import.meta`, or the chain of the
+/// static properties of them, e.g. `require`, `process.env.NODE_ENV` and `import.meta.env`.
+pub trait JavascriptParserPlugin: Send + Sync {
   fn evaluate_typeof(
     &self,
-    expression: &swc_core::ecma::ast::Ident,
-    start: u32,
-    end: u32,
-    unresolved_mark: swc_core::common::SyntaxContext, // remove this after `parser.scope.definitions`
-  ) -> Option<BasicEvaluatedExpression>;
+    _expression: &Ident,
+    _start: u32,
+    _end: u32,
+    _unresolved_mark: swc_core::common::SyntaxContext, // remove this after `parser.scope.definitions`
+  ) -> Option<BasicEvaluatedExpression> {
+    None
+  }
+
+  /// Evaluate a free variable, `import.meta`, or a chain of the static properties of them,
+  /// the `ident` is the full name like `process.env.NODE_ENV` and `import.meta.env.MODE`.
+  fn evaluate_identifier(
+    &self,
+    _ident: &str,
+    _start: u32,
+    _end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    None
+  }
+
+  /// Called for every statement before it's walked.
+  fn statement(&self, _parser: &mut JavascriptParser, _stmt: &Stmt) -> Option<bool> {
+    None
+  }
+
+  /// Called for `if` statements, `Some` means the test is always truthy or falsy, then only the
+  /// branch taken is walked, the plugin walks the test itself if needed.
+  fn statement_if(&self, _parser: &mut JavascriptParser, _stmt: &IfStmt) -> Option<bool> {
+    None
+  }
+
+  /// Called for conditional expressions, same as [JavascriptParserPlugin::statement_if].
+  fn expression_conditional_operation(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &CondExpr,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// Called for `import` declarations, `source` is the request being imported.
+  fn import(&self, _parser: &mut JavascriptParser, _import_decl: &ImportDecl, _source: &str) {}
+
+  /// Called for the module declarations other than `import` declarations, e.g.
+  /// `export const a = 1`, `export default a` and `export * from './a'`.
+  fn export(&self, _parser: &mut JavascriptParser, _module_decl: &ModuleDecl) {}
+
+  /// Called for the calls of a free name, e.g. `require('./a')` and `module.hot.accept()`.
+  fn call(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &CallExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// Called for the `new` expressions of a free name, e.g. `new Worker(url)`.
+  fn new_expression(&self, _parser: &mut JavascriptParser, _expr: &NewExpr, _for_name: &str) {}
+
+  /// Called for the member expressions of a free name, e.g. `process.env.NODE_ENV`, the
+  /// `import.meta` ones not defined by the `DefinePlugin` are still replaced by the builtin scanner.
+  fn member(
+    &self,
+    _parser: &mut JavascriptParser,
+    _expr: &MemberExpr,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
+
+  /// Called for the references of a free variable, e.g. `__dirname`.
+  fn identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    _ident: &Ident,
+    _for_name: &str,
+  ) -> Option<bool> {
+    None
+  }
 }
 
 pub type BoxJavascriptParserPlugin = Box<dyn JavascriptParserPlugin>;
@@ -3,7 +3,7 @@ use rspack_core::SpanExt;
 use swc_core::common::Spanned;
 use swc_core::ecma::ast::{BinExpr, BinaryOp};
 
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
 // FIXME: a temp hack to avoid bwchecker.
 bitflags::bitflags! {
@@ -14,11 +14,11 @@ bitflags::bitflags! {
 }
 
 pub fn expression_logic_operator(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  parser: &JavascriptParser<'_>,
   expr: &BinExpr,
 ) -> (Option<Vec<ConstDependency>>, Continue) {
   if expr.op == BinaryOp::LogicalAnd || expr.op == BinaryOp::LogicalOr {
-    let param = scanner.evaluate_expression(&expr.left);
+    let param = parser.evaluate_expression(&expr.left);
     let boolean = param.as_bool();
     let Some(bool) = boolean else {
       return (None, Continue::all());
@@ -42,6 +42,32 @@ pub fn expression_logic_operator(
       c.insert(Continue::LEFT);
     }
 
+    if !keep_right {
+      let dep = ConstDependency::new(
+        expr.right.span().real_lo(),
+        expr.right.span().hi().0 - 1,
+        "0".into(),
+        None,
+      );
+      deps.push(dep);
+    } else {
+      c.insert(Continue::RIGHT)
+    }
+    (Some(deps), c)
+  } else if expr.op == BinaryOp::NullishCoalescing {
+    let param = parser.evaluate_expression(&expr.left);
+    let Some(keep_right) = param.as_nullish() else {
+      return (None, Continue::all());
+    };
+    let mut deps = vec![];
+    let mut c = Continue::empty();
+    if !param.could_have_side_effects() && keep_right {
+      let dep = ConstDependency::new(param.range().0, param.range().1 - 1, " null".into(), None);
+      deps.push(dep);
+    } else {
+      c.insert(Continue::LEFT);
+    }
+
     if !keep_right {
       let dep = ConstDependency::new(
         expr.right.span().real_lo(),
@@ -57,9 +83,4 @@ pub fn expression_logic_operator(
   } else {
     (None, Continue::all())
   }
-  // else if expr.op == BinaryOp::NullishCoalescing {
-  //   // TODO: support `??`
-  //   expr.visit_children_with(scanner);
-  //   None
-  // }
 }
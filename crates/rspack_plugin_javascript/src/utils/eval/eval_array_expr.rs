@@ -2,10 +2,10 @@ use rspack_core::SpanExt;
 use swc_core::ecma::ast::ArrayLit;
 
 use super::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
 pub fn eval_array_expression<'a>(
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
+  parser: &'a JavascriptParser<'a>,
   expr: &'a ArrayLit,
 ) -> Option<BasicEvaluatedExpression> {
   let mut items = vec![];
@@ -14,7 +14,7 @@ pub fn eval_array_expression<'a>(
     if let Some(elem) = elem
       && elem.spread.is_none()
     {
-      items.push(scanner.evaluate_expression(&elem.expr));
+      items.push(parser.evaluate_expression(&elem.expr));
     } else {
       return None;
     }
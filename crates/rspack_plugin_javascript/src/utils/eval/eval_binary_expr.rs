@@ -2,17 +2,17 @@ use rspack_core::SpanExt;
 use swc_core::ecma::ast::{BinExpr, BinaryOp};
 
 use crate::utils::eval::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
 /// `eql` is `true` for `===` and `false` for `!==`
 fn handle_strict_equality_comparison<'a>(
   eql: bool,
   expr: &'a BinExpr,
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
+  parser: &'a JavascriptParser<'a>,
 ) -> Option<BasicEvaluatedExpression> {
   assert!(expr.op == BinaryOp::EqEqEq || expr.op == BinaryOp::NotEqEq);
-  let left = scanner.evaluate_expression(&expr.left);
-  let right = scanner.evaluate_expression(&expr.right);
+  let left = parser.evaluate_expression(&expr.left);
+  let right = parser.evaluate_expression(&expr.right);
   let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
   let left_const = left.is_compile_time_value();
   let right_const = right.is_compile_time_value();
@@ -29,16 +29,22 @@ fn handle_strict_equality_comparison<'a>(
 fn handle_abstract_equality_comparison<'a>(
   eql: bool,
   expr: &'a BinExpr,
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
+  parser: &'a JavascriptParser<'a>,
 ) -> Option<BasicEvaluatedExpression> {
-  let left = scanner.evaluate_expression(&expr.left);
-  let right = scanner.evaluate_expression(&expr.right);
+  let left = parser.evaluate_expression(&expr.left);
+  let right = parser.evaluate_expression(&expr.right);
   let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
 
   let left_const = left.is_compile_time_value();
   let right_const = right.is_compile_time_value();
 
-  if left_const && right_const {
+  if left.is_nullish_value() && right.is_nullish_value() {
+    // `null == undefined`
+    res.set_bool(eql);
+    res.set_side_effects(left.could_have_side_effects() || right.could_have_side_effects());
+    Some(res)
+  } else if left_const && right_const && left.ty == right.ty {
+    // The values of different types may be converted before comparing, e.g. `1 == "1"`
     res.set_bool(eql == left.compare_compile_time_value(&right));
     res.set_side_effects(left.could_have_side_effects() || right.could_have_side_effects());
     Some(res)
@@ -51,15 +57,42 @@ fn handle_abstract_equality_comparison<'a>(
   }
 }
 
+/// `&&`, `||` and `??`, which is evaluated to the value of the operand it returns
+fn handle_logical_expression<'a>(
+  expr: &'a BinExpr,
+  parser: &'a JavascriptParser<'a>,
+) -> Option<BasicEvaluatedExpression> {
+  let mut left = parser.evaluate_expression(&expr.left);
+  let returns_left = match expr.op {
+    BinaryOp::LogicalAnd => !left.as_bool()?,
+    BinaryOp::LogicalOr => left.as_bool()?,
+    BinaryOp::NullishCoalescing => !left.as_nullish()?,
+    _ => unreachable!(),
+  };
+  if returns_left {
+    left.set_range(expr.span.real_lo(), expr.span.hi().0);
+    return Some(left);
+  }
+  let mut right = parser.evaluate_expression(&expr.right);
+  if left.could_have_side_effects() {
+    right.set_side_effects(true);
+  }
+  right.set_range(expr.span.real_lo(), expr.span.hi().0);
+  Some(right)
+}
+
 pub fn eval_binary_expression<'a>(
-  scanner: &'a CommonJsImportDependencyScanner<'a>,
+  parser: &'a JavascriptParser<'a>,
   expr: &'a BinExpr,
 ) -> Option<BasicEvaluatedExpression> {
   match expr.op {
-    BinaryOp::EqEq => handle_abstract_equality_comparison(true, expr, scanner),
-    BinaryOp::NotEq => handle_abstract_equality_comparison(false, expr, scanner),
-    BinaryOp::EqEqEq => handle_strict_equality_comparison(true, expr, scanner),
-    BinaryOp::NotEqEq => handle_strict_equality_comparison(false, expr, scanner),
+    BinaryOp::EqEq => handle_abstract_equality_comparison(true, expr, parser),
+    BinaryOp::NotEq => handle_abstract_equality_comparison(false, expr, parser),
+    BinaryOp::EqEqEq => handle_strict_equality_comparison(true, expr, parser),
+    BinaryOp::NotEqEq => handle_strict_equality_comparison(false, expr, parser),
+    BinaryOp::LogicalAnd | BinaryOp::LogicalOr | BinaryOp::NullishCoalescing => {
+      handle_logical_expression(expr, parser)
+    }
     _ => None,
   }
 }
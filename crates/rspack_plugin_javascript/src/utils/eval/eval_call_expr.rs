@@ -0,0 +1,115 @@
+use rspack_core::SpanExt;
+use swc_core::ecma::ast::{CallExpr, Callee, Expr, MemberProp};
+
+use super::BasicEvaluatedExpression;
+use crate::visitors::JavascriptParser;
+
+/// Evaluate the calls of the methods of the strings known at compile time:
+/// `replace` with a string pattern, `substr`, `substring`, `slice` and `concat`.
+pub fn eval_call_expression(
+  parser: &JavascriptParser,
+  expr: &CallExpr,
+) -> Option<BasicEvaluatedExpression> {
+  let Callee::Expr(box Expr::Member(callee)) = &expr.callee else {
+    return None;
+  };
+  let MemberProp::Ident(method) = &callee.prop else {
+    return None;
+  };
+  if expr.args.iter().any(|arg| arg.spread.is_some()) {
+    return None;
+  }
+  let object = parser.evaluate_expression(&callee.obj);
+  if !object.is_string() {
+    return None;
+  }
+  let args = expr
+    .args
+    .iter()
+    .map(|arg| parser.evaluate_expression(&arg.expr))
+    .collect::<Vec<_>>();
+  let string = object.string();
+
+  let result = match &*method.sym {
+    "replace" => {
+      let [pattern, replacement] = &args[..] else {
+        return None;
+      };
+      // `$` starts a replacement pattern like `$&`, which isn't supported
+      if !pattern.is_string() || !replacement.is_string() || replacement.string().contains('$') {
+        return None;
+      }
+      string.replacen(pattern.string(), replacement.string(), 1)
+    }
+    "substr" | "substring" | "slice" => {
+      // the indexes count the UTF-16 code units, which are the chars only in the BMP
+      if string.chars().any(|c| c.len_utf16() > 1) {
+        return None;
+      }
+      let chars = string.chars().collect::<Vec<_>>();
+      let len = chars.len() as f64;
+      let mut numbers = Vec::with_capacity(args.len());
+      for arg in args.iter().take(2) {
+        if !arg.is_number() {
+          return None;
+        }
+        numbers.push(arg.number());
+      }
+      let (start, end) = match (&*method.sym, numbers.as_slice()) {
+        ("substr", &[start]) => (relative_index(start, len), len),
+        ("substr", &[start, length]) => {
+          let start = relative_index(start, len);
+          (start, (start + clamp_index(length, len)).min(len))
+        }
+        ("substring", &[start]) => (clamp_index(start, len), len),
+        ("substring", &[start, end]) => {
+          let (start, end) = (clamp_index(start, len), clamp_index(end, len));
+          (start.min(end), start.max(end))
+        }
+        ("slice", &[start]) => (relative_index(start, len), len),
+        ("slice", &[start, end]) => (relative_index(start, len), relative_index(end, len)),
+        _ => return None,
+      };
+      if start >= end {
+        String::new()
+      } else {
+        chars[start as usize..end as usize].iter().collect()
+      }
+    }
+    "concat" => {
+      let mut result = string.to_string();
+      for arg in &args {
+        result.push_str(&arg.as_string()?);
+      }
+      result
+    }
+    _ => return None,
+  };
+
+  let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
+  res.set_string(result);
+  res.set_side_effects(
+    object.could_have_side_effects() || args.iter().any(|arg| arg.could_have_side_effects()),
+  );
+  Some(res)
+}
+
+/// An index counted from the end if it's negative, like the arguments of `slice`.
+fn relative_index(index: f64, len: f64) -> f64 {
+  let index = index.trunc();
+  if index.is_nan() {
+    0.0
+  } else if index < 0.0 {
+    (len + index).max(0.0)
+  } else {
+    index.min(len)
+  }
+}
+
+fn clamp_index(index: f64, len: f64) -> f64 {
+  if index.is_nan() {
+    0.0
+  } else {
+    index.trunc().clamp(0.0, len)
+  }
+}
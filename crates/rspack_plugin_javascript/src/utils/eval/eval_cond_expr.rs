@@ -1,27 +1,27 @@
 use swc_core::ecma::ast::CondExpr;
 
 use super::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
 pub fn eval_cond_expression(
-  scanner: &CommonJsImportDependencyScanner,
+  parser: &JavascriptParser,
   cond: &CondExpr,
 ) -> Option<BasicEvaluatedExpression> {
-  let condition = scanner.evaluate_expression(&cond.test);
+  let condition = parser.evaluate_expression(&cond.test);
   let condition_value = condition.as_bool();
   let mut res;
   if let Some(bool) = condition_value {
     if bool {
-      res = scanner.evaluate_expression(&cond.cons)
+      res = parser.evaluate_expression(&cond.cons)
     } else {
-      res = scanner.evaluate_expression(&cond.alt)
+      res = parser.evaluate_expression(&cond.alt)
     };
     if condition.is_conditional() {
       res.set_side_effects(true)
     }
   } else {
-    let cons = scanner.evaluate_expression(&cond.cons);
-    let alt = scanner.evaluate_expression(&cond.alt);
+    let cons = parser.evaluate_expression(&cond.cons);
+    let alt = parser.evaluate_expression(&cond.alt);
     res = BasicEvaluatedExpression::new();
     if cons.is_conditional() {
       res.set_options(cons.options)
@@ -5,14 +5,18 @@ use swc_core::ecma::ast::Lit;
 use super::BasicEvaluatedExpression;
 
 pub fn eval_lit_expr(expr: &Lit) -> Option<BasicEvaluatedExpression> {
+  let mut res = BasicEvaluatedExpression::with_range(expr.span().real_lo(), expr.span_hi().0);
   match expr {
-    Lit::Str(str) => {
-      let mut res = BasicEvaluatedExpression::new();
-      res.set_range(str.span().real_lo(), str.span_hi().0);
-      res.set_string(str.value.to_string());
-      Some(res)
+    Lit::Str(str) => res.set_string(str.value.to_string()),
+    Lit::Bool(bool) => {
+      res.set_bool(bool.value);
+      res.set_side_effects(false);
     }
-    // TODO:
-    _ => None,
+    Lit::Null(_) => res.set_null(),
+    Lit::Num(num) => res.set_number(num.value),
+    Lit::BigInt(bigint) => res.set_bigint(*bigint.value.clone()),
+    // TODO: regexp
+    _ => return None,
   }
+  Some(res)
 }
@@ -0,0 +1,105 @@
+use rspack_core::SpanExt;
+use swc_core::common::SyntaxContext;
+use swc_core::ecma::ast::{
+  Expr, Ident, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, OptChainBase, OptChainExpr,
+};
+
+use super::BasicEvaluatedExpression;
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::visitors::JavascriptParser;
+
+/// The name of a free variable, `import.meta`, or a chain of the static properties of them,
+/// e.g. `process.env.NODE_ENV` and `import.meta.env.MODE`.
+pub fn get_free_name(expr: &Expr, unresolved_ctxt: SyntaxContext) -> Option<String> {
+  match expr {
+    Expr::Ident(ident) if ident.span.ctxt == unresolved_ctxt => Some(ident.sym.to_string()),
+    Expr::MetaProp(MetaPropExpr {
+      kind: MetaPropKind::ImportMeta,
+      ..
+    }) => Some("import.meta".to_string()),
+    Expr::Member(member) => get_free_member_name(member, unresolved_ctxt),
+    Expr::OptChain(OptChainExpr {
+      base: box OptChainBase::Member(member),
+      ..
+    }) => get_free_member_name(member, unresolved_ctxt),
+    Expr::Paren(paren) => get_free_name(&paren.expr, unresolved_ctxt),
+    _ => None,
+  }
+}
+
+fn get_free_member_name(member: &MemberExpr, unresolved_ctxt: SyntaxContext) -> Option<String> {
+  let object = get_free_name(&member.obj, unresolved_ctxt)?;
+  let property = match &member.prop {
+    MemberProp::Ident(ident) => ident.sym.to_string(),
+    MemberProp::Computed(computed) => match &*computed.expr {
+      Expr::Lit(Lit::Str(str)) => str.value.to_string(),
+      _ => return None,
+    },
+    MemberProp::PrivateName(_) => return None,
+  };
+  Some(format!("{object}.{property}"))
+}
+
+fn eval_free_name(
+  parser: &JavascriptParser,
+  name: &str,
+  start: u32,
+  end: u32,
+) -> Option<BasicEvaluatedExpression> {
+  parser.plugin_drive.evaluate_identifier(name, start, end)
+}
+
+pub fn eval_ident(parser: &JavascriptParser, ident: &Ident) -> Option<BasicEvaluatedExpression> {
+  if ident.span.ctxt != parser.unresolved_ctxt {
+    return None;
+  }
+  let (start, end) = (ident.span.real_lo(), ident.span.hi().0);
+  if let Some(res) = eval_free_name(parser, &ident.sym, start, end) {
+    return Some(res);
+  }
+  if &*ident.sym == "undefined" {
+    let mut res = BasicEvaluatedExpression::with_range(start, end);
+    res.set_undefined();
+    return Some(res);
+  }
+  None
+}
+
+pub fn eval_meta_prop_expression(
+  parser: &JavascriptParser,
+  expr: &MetaPropExpr,
+) -> Option<BasicEvaluatedExpression> {
+  if expr.kind != MetaPropKind::ImportMeta {
+    return None;
+  }
+  eval_free_name(parser, "import.meta", expr.span.real_lo(), expr.span.hi().0)
+}
+
+pub fn eval_member_expression(
+  parser: &JavascriptParser,
+  expr: &MemberExpr,
+) -> Option<BasicEvaluatedExpression> {
+  let name = get_free_member_name(expr, parser.unresolved_ctxt)?;
+  eval_free_name(parser, &name, expr.span.real_lo(), expr.span.hi().0)
+}
+
+/// `a?.b` is evaluated to `undefined` if `a` is nullish, otherwise the value of `a.b`.
+pub fn eval_opt_chain_expression(
+  parser: &JavascriptParser,
+  expr: &OptChainExpr,
+) -> Option<BasicEvaluatedExpression> {
+  let OptChainBase::Member(member) = &*expr.base else {
+    return None;
+  };
+  if expr.optional {
+    let object = parser.evaluate_expression(&member.obj);
+    if object.as_nullish() == Some(true) {
+      let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
+      res.set_undefined();
+      res.set_side_effects(object.could_have_side_effects());
+      return Some(res);
+    }
+  }
+  let name = get_free_member_name(member, parser.unresolved_ctxt)?;
+  eval_free_name(parser, &name, expr.span.real_lo(), expr.span.hi().0)
+}
@@ -3,7 +3,7 @@ use swc_core::common::Spanned;
 use swc_core::ecma::ast::Tpl;
 
 use super::BasicEvaluatedExpression;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
 #[derive(Debug, Clone)]
 pub enum TemplateStringKind {
@@ -12,7 +12,7 @@ pub enum TemplateStringKind {
 }
 
 fn get_simplified_template_result(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  parser: &JavascriptParser<'_>,
   node: &Tpl,
 ) -> (Vec<BasicEvaluatedExpression>, Vec<BasicEvaluatedExpression>) {
   let mut quasis: Vec<BasicEvaluatedExpression> = vec![];
@@ -28,7 +28,7 @@ fn get_simplified_template_result(
     if i > 0 {
       let len = parts.len();
       let prev_expr = &mut parts[len - 1];
-      let expr = scanner.evaluate_expression(&node.exprs[i - 1]);
+      let expr = parser.evaluate_expression(&node.exprs[i - 1]);
       if !expr.could_have_side_effects()
         && let Some(str) = expr.as_string()
       {
@@ -55,10 +55,10 @@ fn get_simplified_template_result(
 }
 
 pub fn eval_tpl_expression(
-  scanner: &CommonJsImportDependencyScanner<'_>,
+  parser: &JavascriptParser<'_>,
   tpl: &Tpl,
 ) -> Option<BasicEvaluatedExpression> {
-  let (quasis, mut parts) = get_simplified_template_result(scanner, tpl);
+  let (quasis, mut parts) = get_simplified_template_result(parser, tpl);
   if parts.len() == 1 {
     let mut part = parts.remove(0);
     part.set_range(tpl.span().real_lo(), tpl.span().hi().0);
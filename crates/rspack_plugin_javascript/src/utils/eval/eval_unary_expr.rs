@@ -3,35 +3,49 @@ use swc_core::ecma::ast::{UnaryExpr, UnaryOp};
 
 use super::BasicEvaluatedExpression;
 use crate::parser_plugin::JavascriptParserPlugin;
-use crate::visitors::common_js_import_dependency_scanner::CommonJsImportDependencyScanner;
+use crate::visitors::JavascriptParser;
 
-fn eval_typeof(
-  scanner: &CommonJsImportDependencyScanner,
-  expr: &UnaryExpr,
-) -> Option<BasicEvaluatedExpression> {
+fn eval_typeof(parser: &JavascriptParser, expr: &UnaryExpr) -> Option<BasicEvaluatedExpression> {
   assert!(expr.op == UnaryOp::TypeOf);
   if let Some(ident) = expr.arg.as_ident()
-    && let res = scanner.plugin_drive.evaluate_typeof(
+    && let res = parser.plugin_drive.evaluate_typeof(
       ident,
       expr.span.real_lo(),
       expr.span.hi().0,
-      scanner.unresolved_ctxt,
+      parser.unresolved_ctxt,
     )
     && res.is_some()
   {
     return res;
   }
 
-  // TODO: if let `MetaProperty`, `MemberExpression` ...
-  None
+  // `typeof` of the values known at compile time, including the defined identifiers,
+  // `import.meta.xxx` and the member expressions of them
+  let arg = parser.evaluate_expression(&expr.arg);
+  let type_of = arg.as_typeof()?;
+  let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
+  res.set_string(type_of.to_string());
+  res.set_side_effects(arg.could_have_side_effects());
+  Some(res)
+}
+
+fn eval_not(parser: &JavascriptParser, expr: &UnaryExpr) -> Option<BasicEvaluatedExpression> {
+  assert!(expr.op == UnaryOp::Bang);
+  let arg = parser.evaluate_expression(&expr.arg);
+  let bool = arg.as_bool()?;
+  let mut res = BasicEvaluatedExpression::with_range(expr.span.real_lo(), expr.span.hi().0);
+  res.set_bool(!bool);
+  res.set_side_effects(arg.could_have_side_effects());
+  Some(res)
 }
 
 pub fn eval_unary_expression(
-  scanner: &CommonJsImportDependencyScanner,
+  parser: &JavascriptParser,
   expr: &UnaryExpr,
 ) -> Option<BasicEvaluatedExpression> {
   match expr.op {
-    UnaryOp::TypeOf => eval_typeof(scanner, expr),
+    UnaryOp::TypeOf => eval_typeof(parser, expr),
+    UnaryOp::Bang => eval_not(parser, expr),
     _ => None,
   }
 }
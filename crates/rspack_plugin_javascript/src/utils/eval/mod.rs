@@ -1,7 +1,9 @@
 mod eval_array_expr;
 mod eval_binary_expr;
+mod eval_call_expr;
 mod eval_cond_expr;
 mod eval_lit_expr;
+mod eval_member_expr;
 mod eval_tpl_expr;
 mod eval_unary_expr;
 
@@ -9,8 +11,13 @@ use rspack_core::DependencyLocation;
 
 pub use self::eval_array_expr::eval_array_expression;
 pub use self::eval_binary_expr::eval_binary_expression;
+pub use self::eval_call_expr::eval_call_expression;
 pub use self::eval_cond_expr::eval_cond_expression;
 pub use self::eval_lit_expr::eval_lit_expr;
+pub use self::eval_member_expr::{
+  eval_ident, eval_member_expression, eval_meta_prop_expression, eval_opt_chain_expression,
+  get_free_name,
+};
 pub use self::eval_tpl_expr::{eval_tpl_expression, TemplateStringKind};
 pub use self::eval_unary_expr::eval_unary_expression;
 
@@ -116,6 +123,14 @@ impl BasicEvaluatedExpression {
     matches!(self.ty, Ty::TypeArray)
   }
 
+  pub fn is_number(&self) -> bool {
+    matches!(self.ty, Ty::Number)
+  }
+
+  pub fn is_nullish_value(&self) -> bool {
+    matches!(self.ty, Ty::Null | Ty::Undefined)
+  }
+
   pub fn is_compile_time_value(&self) -> bool {
     matches!(
       self.ty,
@@ -131,12 +146,14 @@ impl BasicEvaluatedExpression {
   }
 
   pub fn as_string(&self) -> Option<std::string::String> {
-    if self.is_bool() {
-      Some(self.bool().to_string())
-    } else if self.is_string() {
-      Some(self.string().to_string())
-    } else {
-      None
+    match self.ty {
+      Ty::Boolean => Some(self.bool().to_string()),
+      Ty::String => Some(self.string().to_string()),
+      Ty::Null => Some("null".to_string()),
+      Ty::Undefined => Some("undefined".to_string()),
+      Ty::Number => Some(number_to_string(self.number())),
+      Ty::BigInt => self.bigint.as_ref().map(|bigint| bigint.to_string()),
+      _ => None,
     }
   }
 
@@ -146,7 +163,54 @@ impl BasicEvaluatedExpression {
     } else if self.falsy || self.nullish == Some(true) {
       Some(false)
     } else {
-      self.boolean
+      match self.ty {
+        Ty::Boolean => self.boolean,
+        Ty::Null | Ty::Undefined => Some(false),
+        Ty::String => Some(!self.string().is_empty()),
+        Ty::Number => Some(self.number() != 0.0 && !self.number().is_nan()),
+        Ty::BigInt => self
+          .bigint
+          .as_ref()
+          .map(|bigint| *bigint != Bigint::default()),
+        Ty::TypeArray | Ty::ConstArray => Some(true),
+        _ => None,
+      }
+    }
+  }
+
+  /// Whether the value is `null` or `undefined`, `None` if it's unknown.
+  pub fn as_nullish(&self) -> Option<Boolean> {
+    if self.nullish == Some(true) || self.is_nullish_value() {
+      Some(true)
+    } else if self.nullish == Some(false)
+      || self.truthy
+      || matches!(
+        self.ty,
+        Ty::Boolean
+          | Ty::String
+          | Ty::Number
+          | Ty::BigInt
+          | Ty::TypeArray
+          | Ty::ConstArray
+          | Ty::TemplateString
+      )
+    {
+      Some(false)
+    } else {
+      None
+    }
+  }
+
+  /// The result of `typeof` of the value, `None` if it's unknown.
+  pub fn as_typeof(&self) -> Option<&'static str> {
+    match self.ty {
+      Ty::Undefined => Some("undefined"),
+      Ty::Null | Ty::TypeArray | Ty::ConstArray => Some("object"),
+      Ty::String | Ty::TemplateString => Some("string"),
+      Ty::Number => Some("number"),
+      Ty::Boolean => Some("boolean"),
+      Ty::BigInt => Some("bigint"),
+      _ => None,
     }
   }
 
@@ -247,6 +311,28 @@ impl BasicEvaluatedExpression {
     self.side_effects = false;
   }
 
+  pub fn set_number(&mut self, number: Number) {
+    self.ty = Ty::Number;
+    self.number = Some(number);
+    self.side_effects = false;
+  }
+
+  pub fn set_bigint(&mut self, bigint: Bigint) {
+    self.ty = Ty::BigInt;
+    self.bigint = Some(bigint);
+    self.side_effects = false;
+  }
+
+  pub fn set_null(&mut self) {
+    self.ty = Ty::Null;
+    self.side_effects = false;
+  }
+
+  pub fn set_undefined(&mut self) {
+    self.ty = Ty::Undefined;
+    self.side_effects = false;
+  }
+
   pub fn string(&self) -> &String {
     self.string.as_ref().expect("make sure string exists")
   }
@@ -255,6 +341,10 @@ impl BasicEvaluatedExpression {
     self.boolean.expect("make sure bool exists")
   }
 
+  pub fn number(&self) -> Number {
+    self.number.expect("make sure number exists")
+  }
+
   pub fn range(&self) -> (u32, u32) {
     let range = self.range.expect("range should not empty");
     (range.start(), range.end())
@@ -266,3 +356,23 @@ pub fn evaluate_to_string(value: String, start: u32, end: u32) -> BasicEvaluated
   eval.set_string(value);
   eval
 }
+
+/// Format a number the way `String(number)` does for the common cases.
+fn number_to_string(number: Number) -> String {
+  if number.is_nan() {
+    "NaN".to_string()
+  } else if number.is_infinite() {
+    if number > 0.0 {
+      "Infinity"
+    } else {
+      "-Infinity"
+    }
+    .to_string()
+  } else if number == 0.0 {
+    "0".to_string()
+  } else if number.fract() == 0.0 && number.abs() < 1e21 {
+    format!("{number:.0}")
+  } else {
+    number.to_string()
+  }
+}
@@ -0,0 +1,184 @@
+use rspack_core::{BuildInfo, ConstDependency, Define, DependencyTemplate, SpanExt};
+use swc_core::common::{Span, Spanned, SyntaxContext};
+use swc_core::ecma::ast::{AssignExpr, Expr, Lit, MemberExpr, Pat, PatOrExpr, Prop};
+use swc_core::ecma::ast::{UnaryExpr, UnaryOp, UpdateExpr};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::parser_plugin::{parse_definition, typeof_definition};
+use crate::utils::eval::get_free_name;
+
+// Port from https://github.com/webpack/webpack/blob/main/lib/DefinePlugin.js
+pub struct DefineScanner<'a> {
+  presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  definitions: &'a Define,
+  unresolved_ctxt: SyntaxContext,
+  build_info: &'a mut BuildInfo,
+  const_replacements: &'a [(Span, Option<bool>)],
+}
+
+impl<'a> DefineScanner<'a> {
+  pub fn new(
+    presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    definitions: &'a Define,
+    unresolved_ctxt: SyntaxContext,
+    build_info: &'a mut BuildInfo,
+    const_replacements: &'a [(Span, Option<bool>)],
+  ) -> Self {
+    Self {
+      presentational_dependencies,
+      definitions,
+      unresolved_ctxt,
+      build_info,
+      const_replacements,
+    }
+  }
+
+  fn get_definition(&self, expr: &Expr) -> Option<(String, &'a String)> {
+    let name = get_free_name(expr, self.unresolved_ctxt)?;
+    let code = self.definitions.get(&name)?;
+    Some((name, code))
+  }
+
+  fn replace(&mut self, start: u32, end: u32, name: String, code: String) {
+    let value = self.definitions[&name].clone();
+    self.build_info.value_dependencies.insert(name, value);
+    // the folded tests and the dead branches are replaced as a whole, but the module still
+    // needs to be rebuilt when the values they used are changed
+    if self
+      .const_replacements
+      .iter()
+      .any(|(span, _)| span.real_lo() <= start && end <= span.real_hi())
+    {
+      return;
+    }
+    self
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        start,
+        end,
+        code.into(),
+        None,
+      )));
+  }
+
+  /// Visit the target of an assignment or update, the target itself is never replaced,
+  /// but the objects it's a property of are, e.g. `OBJECT.a = 1` -> `({ a: 0 }).a = 1`.
+  fn visit_assign_target(&mut self, target: &Expr) {
+    match target {
+      Expr::Ident(_) => {}
+      Expr::Member(member) => member.visit_with(self),
+      _ => target.visit_with(self),
+    }
+  }
+}
+
+impl Visit for DefineScanner<'_> {
+  noop_visit_type!();
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    if let Some((name, code)) = self.get_definition(expr) {
+      self.replace(
+        expr.span().real_lo(),
+        expr.span().real_hi(),
+        name,
+        to_code(code, false),
+      );
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_member_expr(&mut self, member_expr: &MemberExpr) {
+    if let Some((name, code)) = self.get_definition(&member_expr.obj) {
+      self.replace(
+        member_expr.obj.span().real_lo(),
+        member_expr.obj.span().real_hi(),
+        name,
+        to_code(code, true),
+      );
+      member_expr.prop.visit_with(self);
+      return;
+    }
+    member_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if unary_expr.op == UnaryOp::TypeOf
+      && let Some((name, code)) = self.get_definition(&unary_expr.arg)
+      && let Some(type_of) = parse_definition(code).and_then(|expr| typeof_definition(&expr))
+    {
+      self.replace(
+        unary_expr.span.real_lo(),
+        unary_expr.span.real_hi(),
+        name,
+        format!("\"{type_of}\""),
+      );
+      return;
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_prop(&mut self, prop: &Prop) {
+    // `{ DEFINED }` -> `{ DEFINED: code }`
+    if let Prop::Shorthand(ident) = prop
+      && ident.span.ctxt == self.unresolved_ctxt
+      && let Some(code) = self.definitions.get(ident.sym.as_str())
+    {
+      self.replace(
+        ident.span.real_lo(),
+        ident.span.real_hi(),
+        ident.sym.to_string(),
+        format!("{}: {}", ident.sym, to_code(code, false)),
+      );
+      return;
+    }
+    prop.visit_children_with(self);
+  }
+
+  fn visit_assign_expr(&mut self, assign_expr: &AssignExpr) {
+    match &assign_expr.left {
+      PatOrExpr::Expr(box target) | PatOrExpr::Pat(box Pat::Expr(box target)) => {
+        self.visit_assign_target(target)
+      }
+      PatOrExpr::Pat(box Pat::Ident(_)) => {}
+      left => left.visit_with(self),
+    }
+    assign_expr.right.visit_with(self);
+  }
+
+  fn visit_update_expr(&mut self, update_expr: &UpdateExpr) {
+    self.visit_assign_target(&update_expr.arg);
+  }
+}
+
+/// Whether the expression, or the object it's a property of, is replaced by a definition.
+pub fn is_defined_expr(expr: &Expr, definitions: &Define, unresolved_ctxt: SyntaxContext) -> bool {
+  if let Expr::Member(member) = expr
+    && is_defined_expr(&member.obj, definitions, unresolved_ctxt)
+  {
+    return true;
+  }
+  get_free_name(expr, unresolved_ctxt).is_some_and(|name| definitions.contains_key(&name))
+}
+
+/// Wrap the code in parentheses unless it's safe to be used as an operand, or the object of a
+/// member expression if `is_member_object` is true, as is, e.g. `ZERO.ABC` -> `(0).ABC`.
+fn to_code(code: &str, is_member_object: bool) -> String {
+  let is_safe = parse_definition(code).is_some_and(|expr| match *expr {
+    Expr::Lit(Lit::Num(_)) => !is_member_object,
+    Expr::Ident(_)
+    | Expr::This(_)
+    | Expr::Member(_)
+    | Expr::Call(_)
+    | Expr::Paren(_)
+    | Expr::Array(_)
+    | Expr::Tpl(_)
+    | Expr::Lit(Lit::Str(_) | Lit::Bool(_) | Lit::Null(_) | Lit::Regex(_) | Lit::BigInt(_)) => true,
+    _ => false,
+  });
+  if is_safe {
+    code.to_string()
+  } else {
+    format!("({code})")
+  }
+}
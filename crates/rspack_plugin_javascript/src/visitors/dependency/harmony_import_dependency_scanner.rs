@@ -596,6 +596,9 @@ mod test {
       all_star_exports: Default::default(),
       need_create_require: false,
       json_data: None,
+      js_incompatible_exports: None,
+      icss_values: None,
+      value_dependencies: Default::default(),
     };
     let mut import_map = Default::default();
     let mut deps = vec![];
@@ -1,10 +1,12 @@
-use rspack_core::{CompilerOptions, ConstDependency, DependencyTemplate, ResourceData, SpanExt};
+use rspack_core::SpanExt;
+use rspack_core::{CompilerOptions, ConstDependency, Define, DependencyTemplate, ResourceData};
 use rspack_error::Diagnostic;
-use swc_core::common::Spanned;
+use swc_core::common::{Spanned, SyntaxContext};
 use swc_core::ecma::ast::{Expr, NewExpr, UnaryExpr, UnaryOp};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 use url::Url;
 
+use super::define_scanner::is_defined_expr;
 use super::{
   expr_matcher, is_member_expr_starts_with_import_meta,
   is_member_expr_starts_with_import_meta_webpack_hot,
@@ -20,6 +22,8 @@ pub struct ImportMetaScanner<'a> {
   pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
   pub compiler_options: &'a CompilerOptions,
   pub resource_data: &'a ResourceData,
+  pub definitions: &'a Define,
+  pub unresolved_ctxt: SyntaxContext,
   pub warning_diagnostics: &'a mut Vec<Diagnostic>,
 }
 
@@ -28,17 +32,27 @@ impl<'a> ImportMetaScanner<'a> {
     presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
     resource_data: &'a ResourceData,
     compiler_options: &'a CompilerOptions,
+    definitions: &'a Define,
+    unresolved_ctxt: SyntaxContext,
     warning_diagnostics: &'a mut Vec<Diagnostic>,
   ) -> Self {
     Self {
       presentational_dependencies,
       resource_data,
       compiler_options,
+      definitions,
+      unresolved_ctxt,
       warning_diagnostics,
     }
   }
 }
 
+impl ImportMetaScanner<'_> {
+  fn is_defined(&self, expr: &Expr) -> bool {
+    !self.definitions.is_empty() && is_defined_expr(expr, self.definitions, self.unresolved_ctxt)
+  }
+}
+
 impl Visit for ImportMetaScanner<'_> {
   noop_visit_type!();
 
@@ -49,7 +63,9 @@ impl Visit for ImportMetaScanner<'_> {
       ..
     } = unary_expr
     {
-      if expr_matcher::is_import_meta(expr) {
+      if self.is_defined(expr) {
+        // replaced by `DefineScanner`
+      } else if expr_matcher::is_import_meta(expr) {
         self
           .presentational_dependencies
           .push(Box::new(ConstDependency::new(
@@ -83,8 +99,8 @@ impl Visit for ImportMetaScanner<'_> {
   }
 
   fn visit_expr(&mut self, expr: &Expr) {
-    // exclude import.meta.webpackHot
-    if is_member_expr_starts_with_import_meta_webpack_hot(expr) {
+    // exclude import.meta.webpackHot and the defined ones, which are replaced by `DefineScanner`
+    if is_member_expr_starts_with_import_meta_webpack_hot(expr) || self.is_defined(expr) {
       return;
     }
 
@@ -109,6 +109,32 @@ static WEBPACK_CHUNK_NAME_CAPTURE_RE: Lazy<regex::Regex> = Lazy::new(|| {
     .expect("invalid regex")
 });
 
+pub(crate) fn try_extract_webpack_chunk_name(
+  comments: &Option<&dyn Comments>,
+  span: &Span,
+) -> Option<String> {
+  comments.with_leading(span.lo, |comments| {
+    comments
+      .iter()
+      .rev()
+      .filter(|c| matches!(c.kind, CommentKind::Block))
+      .find_map(|comment| {
+        WEBPACK_CHUNK_NAME_CAPTURE_RE
+          .captures(&comment.text)
+          .and_then(|captures| {
+            if let Some(cap) = captures.name("_1") {
+              Some(cap)
+            } else if let Some(cap) = captures.name("_2") {
+              Some(cap)
+            } else {
+              captures.name("_3")
+            }
+          })
+          .map(|mat| mat.as_str().to_string())
+      })
+  })
+}
+
 impl<'a> ImportScanner<'a> {
   pub fn new(
     module_identifier: ModuleIdentifier,
@@ -127,32 +153,6 @@ impl<'a> ImportScanner<'a> {
       options,
     }
   }
-
-  fn try_extract_webpack_chunk_name(&self, first_arg_span_of_import_call: &Span) -> Option<String> {
-    self
-      .comments
-      .with_leading(first_arg_span_of_import_call.lo, |comments| {
-        let ret = comments
-          .iter()
-          .rev()
-          .filter(|c| matches!(c.kind, CommentKind::Block))
-          .find_map(|comment| {
-            WEBPACK_CHUNK_NAME_CAPTURE_RE
-              .captures(&comment.text)
-              .and_then(|captures| {
-                if let Some(cap) = captures.name("_1") {
-                  Some(cap)
-                } else if let Some(cap) = captures.name("_2") {
-                  Some(cap)
-                } else {
-                  captures.name("_3")
-                }
-              })
-              .map(|mat| mat.as_str().to_string())
-          });
-        ret
-      })
-  }
 }
 
 impl Visit for ImportScanner<'_> {
@@ -198,7 +198,7 @@ impl Visit for ImportScanner<'_> {
           self.dependencies.push(Box::new(dep));
           return;
         }
-        let chunk_name = self.try_extract_webpack_chunk_name(&imported.span);
+        let chunk_name = try_extract_webpack_chunk_name(&self.comments, &imported.span);
         let span = ErrorSpan::from(node.span);
         let dep = Box::new(ImportDependency::new(
           node.span.real_lo(),
@@ -220,7 +220,7 @@ impl Visit for ImportScanner<'_> {
         self.blocks.push(block);
       }
       Expr::Tpl(tpl) if tpl.quasis.len() == 1 => {
-        let chunk_name = self.try_extract_webpack_chunk_name(&tpl.span);
+        let chunk_name = try_extract_webpack_chunk_name(&self.comments, &tpl.span);
         let request = JsWord::from(
           tpl
             .quasis
@@ -252,7 +252,7 @@ impl Visit for ImportScanner<'_> {
         let Some((context, reg)) = scanner_context_module(dyn_imported.expr.as_ref()) else {
           return;
         };
-        let chunk_name = self.try_extract_webpack_chunk_name(&dyn_imported.span());
+        let chunk_name = try_extract_webpack_chunk_name(&self.comments, &dyn_imported.span());
         self
           .dependencies
           .push(Box::new(ImportContextDependency::new(
@@ -1,9 +1,9 @@
 mod api_scanner;
 mod common_js_export_scanner;
-pub(crate) mod common_js_import_dependency_scanner;
 mod common_js_scanner;
 mod compatibility_scanner;
 mod context_helper;
+mod define_scanner;
 mod export_info_api_scanner;
 mod harmony_detection_scanner;
 mod harmony_export_dependency_scanner;
@@ -13,29 +13,34 @@ mod hot_module_replacement_scanner;
 mod import_meta_scanner;
 mod import_scanner;
 mod node_stuff_scanner;
+mod parser;
 mod require_context_scanner;
 mod url_scanner;
 mod util;
 mod worker_scanner;
 
+use std::sync::Arc;
+
 use rspack_ast::javascript::Program;
 use rspack_core::{
-  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, BuildInfo, BuildMeta,
-  CompilerOptions, JavascriptParserUrl, ModuleIdentifier, ModuleType, ResourceData,
+  definitions_hash, AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, BuildInfo,
+  BuildMeta, CompilerOptions, Define, JavascriptParserUrl, ModuleIdentifier, ModuleType,
+  ResourceData, VALUE_DEP_MAIN,
 };
 use rspack_error::{BatchErrors, Diagnostic};
 use rustc_hash::FxHashMap as HashMap;
 use swc_core::common::Span;
 use swc_core::common::{comments::Comments, Mark, SyntaxContext};
 use swc_core::ecma::atoms::JsWord;
+use swc_core::ecma::visit::VisitMutWith;
 pub use util::*;
 
 use self::harmony_import_dependency_scanner::ImportMap;
+pub use self::parser::JavascriptParser;
 use self::{
   api_scanner::ApiScanner, common_js_export_scanner::CommonJsExportDependencyScanner,
-  common_js_import_dependency_scanner::CommonJsImportDependencyScanner,
   common_js_scanner::CommonJsScanner, compatibility_scanner::CompatibilityScanner,
-  export_info_api_scanner::ExportInfoApiScanner,
+  define_scanner::DefineScanner, export_info_api_scanner::ExportInfoApiScanner,
   harmony_detection_scanner::HarmonyDetectionScanner,
   harmony_export_dependency_scanner::HarmonyExportDependencyScanner,
   harmony_import_dependency_scanner::HarmonyImportDependencyScanner,
@@ -45,6 +50,7 @@ use self::{
   node_stuff_scanner::NodeStuffScanner, require_context_scanner::RequireContextScanner,
   url_scanner::UrlScanner, worker_scanner::WorkerScanner,
 };
+use crate::parser_plugin::{ConstReplacedRemover, JavascriptParserPlugin};
 
 pub struct ScanDependenciesResult {
   pub dependencies: Vec<BoxDependency>,
@@ -67,14 +73,16 @@ pub enum ExtraSpanInfo {
 
 #[allow(clippy::too_many_arguments)]
 pub fn scan_dependencies(
-  program: &Program,
+  program: &mut Program,
   unresolved_mark: Mark,
   resource_data: &ResourceData,
   compiler_options: &CompilerOptions,
+  definitions: &Define,
   module_type: &ModuleType,
   build_info: &mut BuildInfo,
   build_meta: &mut BuildMeta,
   module_identifier: ModuleIdentifier,
+  parser_plugins: &[Arc<dyn JavascriptParserPlugin>],
 ) -> Result<ScanDependenciesResult, BatchErrors> {
   let mut warning_diagnostics: Vec<Diagnostic> = vec![];
   let mut errors = vec![];
@@ -86,6 +94,44 @@ pub fn scan_dependencies(
   let mut parser_exports_state = None;
 
   let mut rewrite_usage_span = HashMap::default();
+
+  // the module is rebuilt once a definition is added or removed, `typeof` of any free name may
+  // depend on it
+  build_info
+    .value_dependencies
+    .insert(VALUE_DEP_MAIN.to_string(), definitions_hash(definitions));
+
+  // TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
+  // https://github.com/web-infra-dev/rspack/issues/3544
+  let const_replacements = {
+    let mut parser = JavascriptParser::new(
+      resource_data,
+      compiler_options,
+      &mut dependencies,
+      &mut presentational_dependencies,
+      unresolved_ctxt,
+      definitions,
+      parser_plugins,
+    );
+    program.visit_with(&mut parser);
+    parser.const_replacements
+  };
+
+  if !definitions.is_empty() {
+    program.visit_with(&mut DefineScanner::new(
+      &mut presentational_dependencies,
+      definitions,
+      unresolved_ctxt,
+      build_info,
+      &const_replacements,
+    ));
+  }
+
+  // the scanners below never see the folded tests and the dead branches
+  if !const_replacements.is_empty() {
+    program.visit_mut_with(&mut ConstReplacedRemover::new(&const_replacements));
+  }
+
   program.visit_with(&mut ApiScanner::new(
     unresolved_ctxt,
     resource_data,
@@ -103,13 +149,6 @@ pub fn scan_dependencies(
     unresolved_ctxt,
   ));
 
-  // TODO it should enable at js/auto or js/dynamic, but builtins provider will inject require at esm
-  // https://github.com/web-infra-dev/rspack/issues/3544
-  program.visit_with(&mut CommonJsImportDependencyScanner::new(
-    &mut dependencies,
-    &mut presentational_dependencies,
-    unresolved_ctxt,
-  ));
   if module_type.is_js_auto() || module_type.is_js_dynamic() {
     program.visit_with(&mut CommonJsScanner::new(
       &mut presentational_dependencies,
@@ -180,6 +219,7 @@ pub fn scan_dependencies(
       &module_identifier,
       &compiler_options.output,
       worker_syntax_list,
+      comments.map(|c| c as &dyn Comments),
     );
     program.visit_with(&mut worker_scanner);
     blocks.append(&mut worker_scanner.blocks);
@@ -206,6 +246,8 @@ pub fn scan_dependencies(
       &mut presentational_dependencies,
       resource_data,
       compiler_options,
+      definitions,
+      unresolved_ctxt,
       &mut warning_diagnostics,
     ));
   }
@@ -0,0 +1,537 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use rspack_core::{context_reg_exp, ContextOptions, DependencyCategory};
+use rspack_core::{BoxDependency, ConstDependency, ContextMode, ContextNameSpaceObject, Define};
+use rspack_core::{CompilerOptions, DependencyTemplate, ResourceData, SpanExt};
+use swc_core::common::{Span, Spanned, SyntaxContext};
+use swc_core::ecma::ast::{BinExpr, CallExpr, Callee, CondExpr, Expr, IfStmt, ModuleDecl};
+use swc_core::ecma::ast::{Lit, Stmt, TryStmt, UnaryExpr, UnaryOp};
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+use super::context_helper::scanner_context_module;
+use super::{expr_matcher, is_unresolved_member_object_ident, is_unresolved_require};
+use crate::dependency::{CommonJsRequireContextDependency, RequireHeaderDependency};
+use crate::dependency::{CommonJsRequireDependency, RequireResolveDependency};
+use crate::parser_plugin::{BoxJavascriptParserPlugin, CommonJsImportsParserPlugin, ConstPlugin};
+use crate::parser_plugin::{
+  DefineParserPlugin, JavaScriptParserPluginDrive, JavascriptParserPlugin,
+};
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::utils::{expression_logic_operator, Continue};
+
+/// Port of `JavascriptParser` in webpack, walks the ast and calls the hooks of the
+/// [JavascriptParserPlugin]s, the plugins add dependencies and presentational dependencies to it.
+pub struct JavascriptParser<'a> {
+  pub resource_data: &'a ResourceData,
+  pub compiler_options: &'a CompilerOptions,
+  pub dependencies: &'a mut Vec<BoxDependency>,
+  pub presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+  pub unresolved_ctxt: SyntaxContext,
+  /// Whether the node being walked is inside a `try` block.
+  pub in_try: bool,
+  pub(crate) in_if: bool,
+  /// The spans of the nodes replaced by [ConstDependency]s as a whole, with the values of the
+  /// folded tests, or `None` for the dead branches, see [crate::parser_plugin::ConstReplacedRemover].
+  pub(crate) const_replacements: Vec<(Span, Option<bool>)>,
+  // shared, so the hooks can be called with the parser borrowed mutably
+  pub(crate) plugin_drive: Rc<JavaScriptParserPluginDrive>,
+}
+
+impl<'a> JavascriptParser<'a> {
+  pub fn new(
+    resource_data: &'a ResourceData,
+    compiler_options: &'a CompilerOptions,
+    dependencies: &'a mut Vec<BoxDependency>,
+    presentational_dependencies: &'a mut Vec<Box<dyn DependencyTemplate>>,
+    unresolved_ctxt: SyntaxContext,
+    definitions: &Define,
+    parser_plugins: &[Arc<dyn JavascriptParserPlugin>],
+  ) -> Self {
+    let mut plugins: Vec<BoxJavascriptParserPlugin> = vec![Box::new(CommonJsImportsParserPlugin)];
+    if !definitions.is_empty() {
+      plugins.push(Box::new(DefineParserPlugin::new(definitions.clone())));
+      // the branches are only eliminated along with the definitions, they're mostly written for them
+      plugins.push(Box::new(ConstPlugin));
+    }
+    let plugin_drive =
+      Rc::new(JavaScriptParserPluginDrive::new(plugins).with_plugins(parser_plugins));
+    Self {
+      resource_data,
+      compiler_options,
+      dependencies,
+      presentational_dependencies,
+      unresolved_ctxt,
+      in_try: false,
+      in_if: false,
+      const_replacements: vec![],
+      plugin_drive,
+    }
+  }
+
+  fn add_require_resolve(&mut self, node: &CallExpr, weak: bool) {
+    if !node.args.is_empty() {
+      if let Some(Lit::Str(str)) = node.args.first().and_then(|x| x.expr.as_lit()) {
+        self
+          .dependencies
+          .push(Box::new(RequireResolveDependency::new(
+            node.span.real_lo(),
+            node.span.real_hi(),
+            str.value.to_string(),
+            weak,
+            node.span.into(),
+            self.in_try,
+          )));
+      }
+    }
+  }
+
+  fn replace_require_resolve(&mut self, expr: &Expr, value: &'static str) {
+    if (expr_matcher::is_require(expr)
+      || expr_matcher::is_require_resolve(expr)
+      || expr_matcher::is_require_resolve_weak(expr))
+      && is_unresolved_require(expr, self.unresolved_ctxt)
+    {
+      self
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span().real_lo(),
+          expr.span().real_hi(),
+          value.into(),
+          None,
+        )));
+    }
+  }
+
+  fn require_handler(
+    &'a self,
+    call_expr: &'a CallExpr,
+  ) -> Option<(Vec<CommonJsRequireDependency>, Vec<RequireHeaderDependency>)> {
+    if call_expr.args.len() != 1 {
+      return None;
+    }
+    let Some(ident) = call_expr.callee.as_expr().and_then(|expr| expr.as_ident()) else {
+      return None;
+    };
+    if !("require".eq(&ident.sym) && ident.span.ctxt == self.unresolved_ctxt) {
+      return None;
+    }
+    let Some(argument_expr) = call_expr.args.first().map(|arg| &arg.expr) else {
+      return None;
+    };
+
+    let process_require_item = |p: &BasicEvaluatedExpression| {
+      p.is_string().then(|| {
+        let dep = CommonJsRequireDependency::new(
+          p.string().to_string(),
+          Some(call_expr.span.into()),
+          p.range().0,
+          p.range().1,
+          self.in_try,
+        );
+        dep
+      })
+    };
+    let param = self.evaluate_expression(argument_expr);
+    let mut commonjs_require_deps = vec![];
+    let mut require_header_deps = vec![];
+    if param.is_conditional() {
+      let mut is_expression = false;
+      for p in param.options() {
+        if let Some(dep) = process_require_item(p) {
+          commonjs_require_deps.push(dep)
+        } else {
+          is_expression = true;
+        }
+      }
+      if !is_expression {
+        require_header_deps.push(RequireHeaderDependency::new(
+          call_expr.callee.span().real_lo(),
+          call_expr.callee.span().hi().0,
+        ));
+      }
+    }
+
+    if let Some(dep) = process_require_item(&param) {
+      commonjs_require_deps.push(dep);
+      require_header_deps.push(RequireHeaderDependency::new(
+        call_expr.callee.span().real_lo(),
+        call_expr.callee.span_hi().0,
+      ));
+    }
+
+    Some((commonjs_require_deps, require_header_deps))
+  }
+}
+
+impl Visit for JavascriptParser<'_> {
+  noop_visit_type!();
+
+  fn visit_try_stmt(&mut self, node: &TryStmt) {
+    self.in_try = true;
+    node.visit_children_with(self);
+    self.in_try = false;
+  }
+
+  fn visit_stmt(&mut self, stmt: &Stmt) {
+    if self.plugin_drive.clone().statement(self, stmt) == Some(true) {
+      return;
+    }
+    stmt.visit_children_with(self);
+  }
+
+  fn visit_module_decl(&mut self, module_decl: &ModuleDecl) {
+    let drive = self.plugin_drive.clone();
+    match module_decl {
+      ModuleDecl::Import(import_decl) => drive.import(self, import_decl, &import_decl.src.value),
+      _ => drive.export(self, module_decl),
+    }
+    module_decl.visit_children_with(self);
+  }
+
+  fn visit_expr(&mut self, expr: &Expr) {
+    let drive = self.plugin_drive.clone();
+    let handled = match expr {
+      Expr::Ident(ident) if ident.span.ctxt == self.unresolved_ctxt => {
+        drive.identifier(self, ident, &ident.sym)
+      }
+      Expr::Member(member) => eval::get_free_name(expr, self.unresolved_ctxt)
+        .and_then(|name| drive.member(self, member, &name)),
+      // additive, `new Worker()` and `new URL()` are still handled by the builtin scanners
+      Expr::New(new_expr) => {
+        if let Some(name) = eval::get_free_name(&new_expr.callee, self.unresolved_ctxt) {
+          drive.new_expression(self, new_expr, &name);
+        }
+        None
+      }
+      _ => None,
+    };
+    if handled == Some(true) {
+      return;
+    }
+    expr.visit_children_with(self);
+  }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    let Callee::Expr(expr) = &call_expr.callee else {
+      call_expr.visit_children_with(self);
+      return;
+    };
+
+    if let Some(name) = eval::get_free_name(expr, self.unresolved_ctxt)
+      && self.plugin_drive.clone().call(self, call_expr, &name) == Some(true)
+    {
+      return;
+    }
+
+    let deps = self.require_handler(call_expr);
+
+    if let Some((commonjs_require_deps, require_helper_deps)) = deps {
+      for dep in commonjs_require_deps {
+        self.dependencies.push(Box::new(dep))
+      }
+      for dep in require_helper_deps {
+        self.presentational_dependencies.push(Box::new(dep))
+      }
+    }
+
+    if let Expr::Ident(ident) = &**expr
+      && "require".eq(&ident.sym)
+      && ident.span.ctxt == self.unresolved_ctxt
+      && let Some(expr) = call_expr.args.first()
+      && call_expr.args.len() == 1
+      && expr.spread.is_none()
+      && let Some((context, reg)) = scanner_context_module(expr.expr.as_ref())
+    {
+      // `require.resolve`
+      self
+        .dependencies
+        .push(Box::new(CommonJsRequireContextDependency::new(
+          call_expr.callee.span().real_lo(),
+          call_expr.callee.span().real_hi(),
+          call_expr.span.real_hi(),
+          ContextOptions {
+            chunk_name: None,
+            mode: ContextMode::Sync,
+            recursive: true,
+            reg_exp: context_reg_exp(&reg, ""),
+            reg_str: reg,
+            include: None,
+            exclude: None,
+            category: DependencyCategory::CommonJS,
+            request: context,
+            namespace_object: ContextNameSpaceObject::Unset,
+          },
+          Some(call_expr.span.into()),
+        )));
+      return;
+    }
+
+    if is_unresolved_member_object_ident(expr, self.unresolved_ctxt) {
+      if expr_matcher::is_require_resolve(expr) {
+        self.add_require_resolve(call_expr, false);
+        return;
+      }
+      if expr_matcher::is_require_resolve_weak(expr) {
+        self.add_require_resolve(call_expr, true);
+        return;
+      }
+    }
+    call_expr.visit_children_with(self);
+  }
+
+  fn visit_unary_expr(&mut self, unary_expr: &UnaryExpr) {
+    if let UnaryExpr {
+      op: UnaryOp::TypeOf,
+      arg: box expr,
+      ..
+    } = unary_expr
+    {
+      if expr_matcher::is_require(expr)
+        || expr_matcher::is_require_resolve(expr)
+        || expr_matcher::is_require_resolve_weak(expr)
+      {
+        self
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            unary_expr.span().real_lo(),
+            unary_expr.span().real_hi(),
+            "'function'".into(),
+            None,
+          )));
+      }
+    }
+    unary_expr.visit_children_with(self);
+  }
+
+  fn visit_if_stmt(&mut self, if_stmt: &IfStmt) {
+    self.in_if = true;
+    match self.plugin_drive.clone().statement_if(self, if_stmt) {
+      Some(true) => if_stmt.cons.visit_with(self),
+      Some(false) => if_stmt.alt.visit_with(self),
+      None => {
+        self.replace_require_resolve(&if_stmt.test, "true");
+        // The dependencies in the branch which is never taken are not collected
+        match self.evaluate_expression(&if_stmt.test).as_bool() {
+          Some(true) => {
+            if_stmt.test.visit_with(self);
+            if_stmt.cons.visit_with(self);
+          }
+          Some(false) => {
+            if_stmt.test.visit_with(self);
+            if_stmt.alt.visit_with(self);
+          }
+          None => if_stmt.visit_children_with(self),
+        }
+      }
+    }
+    self.in_if = false;
+  }
+
+  fn visit_cond_expr(&mut self, cond_expr: &CondExpr) {
+    let drive = self.plugin_drive.clone();
+    match drive.expression_conditional_operation(self, cond_expr) {
+      Some(true) => cond_expr.cons.visit_with(self),
+      Some(false) => cond_expr.alt.visit_with(self),
+      None => match self.evaluate_expression(&cond_expr.test).as_bool() {
+        Some(true) => {
+          cond_expr.test.visit_with(self);
+          cond_expr.cons.visit_with(self);
+        }
+        Some(false) => {
+          cond_expr.test.visit_with(self);
+          cond_expr.alt.visit_with(self);
+        }
+        None => cond_expr.visit_children_with(self),
+      },
+    }
+  }
+
+  fn visit_bin_expr(&mut self, bin_expr: &BinExpr) {
+    let value = if self.in_if { "true" } else { "undefined" };
+    self.replace_require_resolve(&bin_expr.left, value);
+    self.replace_require_resolve(&bin_expr.right, value);
+
+    let (deps, c) = expression_logic_operator(self, bin_expr);
+    if c.contains(Continue::LEFT) {
+      bin_expr.left.visit_with(self);
+    }
+    if c.contains(Continue::RIGHT) {
+      bin_expr.right.visit_with(self);
+    }
+    let Some(deps) = deps else {
+      return;
+    };
+    for dep in deps {
+      self.presentational_dependencies.push(Box::new(dep))
+    }
+  }
+}
+
+impl JavascriptParser<'_> {
+  pub fn evaluate_expression(&self, expr: &Expr) -> BasicEvaluatedExpression {
+    match self.evaluating(expr) {
+      Some(evaluated) => evaluated,
+      None => BasicEvaluatedExpression::with_range(expr.span().real_lo(), expr.span_hi().0),
+    }
+  }
+
+  // same as `JavascriptParser._initializeEvaluating` in webpack
+  // FIXME: should mv it to plugin
+  fn evaluating(&self, expr: &Expr) -> Option<BasicEvaluatedExpression> {
+    match expr {
+      Expr::Tpl(tpl) => eval::eval_tpl_expression(self, tpl),
+      Expr::Lit(lit) => eval::eval_lit_expr(lit),
+      Expr::Cond(cond) => eval::eval_cond_expression(self, cond),
+      Expr::Unary(unary) => eval::eval_unary_expression(self, unary),
+      Expr::Bin(binary) => eval::eval_binary_expression(self, binary),
+      Expr::Array(array) => eval::eval_array_expression(self, array),
+      Expr::Ident(ident) => eval::eval_ident(self, ident),
+      Expr::Member(member) => eval::eval_member_expression(self, member),
+      Expr::MetaProp(meta_prop) => eval::eval_meta_prop_expression(self, meta_prop),
+      Expr::OptChain(opt_chain) => eval::eval_opt_chain_expression(self, opt_chain),
+      Expr::Call(call) => eval::eval_call_expression(self, call),
+      Expr::Paren(paren) => {
+        let mut res = self.evaluate_expression(&paren.expr);
+        res.set_range(paren.span.real_lo(), paren.span.hi().0);
+        Some(res)
+      }
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::path::PathBuf;
+  use std::sync::Arc;
+
+  use rspack_core::{ConstDependency, ModuleType, ResourceData, SpanExt};
+  use rspack_testing::TestConfig;
+  use swc_core::common::SyntaxContext;
+  use swc_core::ecma::ast::{CallExpr, ImportDecl, MemberExpr};
+
+  use super::JavascriptParser;
+  use crate::ast::parse;
+  use crate::parser_plugin::JavascriptParserPlugin;
+  use crate::visitors::swc_visitor::resolver;
+
+  struct FrameworkPlugin;
+
+  impl JavascriptParserPlugin for FrameworkPlugin {
+    fn import(&self, parser: &mut JavascriptParser, import_decl: &ImportDecl, source: &str) {
+      if source == "framework" && parser.resource_data.resource_path.ends_with("index.js") {
+        parser
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            import_decl.span.real_lo(),
+            import_decl.span.real_hi(),
+            "".into(),
+            None,
+          )));
+      }
+    }
+
+    fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+      (for_name == "framework.inject").then(|| {
+        parser
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            expr.span.real_lo(),
+            expr.span.real_hi(),
+            "undefined".into(),
+            None,
+          )));
+        true
+      })
+    }
+
+    fn member(
+      &self,
+      parser: &mut JavascriptParser,
+      expr: &MemberExpr,
+      for_name: &str,
+    ) -> Option<bool> {
+      (for_name == "framework.version").then(|| {
+        parser
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            expr.span.real_lo(),
+            expr.span.real_hi(),
+            "\"1.0.0\"".into(),
+            None,
+          )));
+        true
+      })
+    }
+  }
+
+  fn count_replacements(code: &str) -> usize {
+    let mut ast = parse(
+      code.into(),
+      swc_core::ecma::parser::Syntax::Es(Default::default()),
+      "",
+      &ModuleType::Js,
+    )
+    .expect("should parse the code");
+    ast.transform(|program, context| {
+      program.visit_mut_with(&mut resolver(
+        context.unresolved_mark,
+        context.top_level_mark,
+        false,
+      ));
+    });
+    let resource_data = ResourceData::new("/index.js".into(), PathBuf::from("/index.js"));
+    let config: TestConfig =
+      serde_json::from_value(serde_json::json!({})).expect("should be valid test config");
+    let (compiler_options, _) = config.apply(PathBuf::from("/"));
+    ast.visit(|program, context| {
+      let mut dependencies = vec![];
+      let mut presentational_dependencies = vec![];
+      let plugins: Vec<Arc<dyn JavascriptParserPlugin>> = vec![Arc::new(FrameworkPlugin)];
+      program.visit_with(&mut JavascriptParser::new(
+        &resource_data,
+        &compiler_options,
+        &mut dependencies,
+        &mut presentational_dependencies,
+        SyntaxContext::empty().apply_mark(context.unresolved_mark),
+        &Default::default(),
+        &plugins,
+      ));
+      presentational_dependencies.len()
+    })
+  }
+
+  #[test]
+  fn should_call_the_hooks_of_free_names() {
+    assert_eq!(count_replacements("console.log(framework.version);"), 1);
+    assert_eq!(count_replacements("a = framework.version || 1;"), 1);
+    // the arguments are not walked once the call is handled
+    assert_eq!(
+      count_replacements("framework.inject(framework.version);"),
+      1
+    );
+  }
+
+  #[test]
+  fn should_call_the_additive_hooks() {
+    // the specifiers are still walked, the harmony import is collected by the builtin scanner
+    assert_eq!(
+      count_replacements("import framework from 'framework'; framework.version;"),
+      1
+    );
+    assert_eq!(
+      count_replacements("import 'framework'; console.log(framework.version);"),
+      2
+    );
+  }
+
+  #[test]
+  fn should_not_call_the_hooks_of_bindings() {
+    assert_eq!(
+      count_replacements("function f(framework) { framework.inject(framework.version); }"),
+      0
+    );
+    assert_eq!(count_replacements("framework.other;"), 0);
+  }
+}
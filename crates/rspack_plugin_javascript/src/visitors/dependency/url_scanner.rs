@@ -1,6 +1,6 @@
 use rspack_core::{BoxDependency, SpanExt};
 use swc_core::ecma::{
-  ast::NewExpr,
+  ast::{CallExpr, Expr, ExprOrSpread, NewExpr},
   visit::{noop_visit_type, Visit, VisitWith},
 };
 
@@ -54,4 +54,25 @@ impl Visit for UrlScanner<'_> {
       new_expr.visit_children_with(self);
     }
   }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    // the url is a dependency of the worker created by the call, see `WorkerScanner`
+    if self
+      .worker_syntax_list
+      .match_worker_call(call_expr)
+      .is_some()
+      && let Some(ExprOrSpread {
+        spread: None,
+        expr: box Expr::New(new_url_expr),
+      }) = call_expr.args.first()
+      && rspack_core::needs_refactor::match_new_url(new_url_expr).is_some()
+    {
+      call_expr.callee.visit_with(self);
+      for arg in call_expr.args.iter().skip(1) {
+        arg.visit_with(self);
+      }
+      return;
+    }
+    call_expr.visit_children_with(self);
+  }
 }
@@ -1,15 +1,17 @@
 use std::hash::Hash;
 
 use rspack_core::{
-  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, ConstDependency,
-  DependencyLocation, EntryOptions, ErrorSpan, GroupOptions, ModuleIdentifier, OutputOptions,
-  SpanExt,
+  AsyncDependenciesBlock, BoxDependency, BoxDependencyTemplate, ChunkLoading, ChunkLoadingType,
+  ConstDependency, DependencyLocation, EntryOptions, ErrorSpan, GroupOptions, ModuleIdentifier,
+  OutputOptions, SpanExt,
 };
 use rspack_hash::RspackHash;
-use swc_core::common::Spanned;
-use swc_core::ecma::ast::{Expr, ExprOrSpread, NewExpr};
+use swc_core::common::comments::Comments;
+use swc_core::common::{Span, Spanned};
+use swc_core::ecma::ast::{CallExpr, Expr, ExprOrSpread, NewExpr};
 use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
 
+use super::import_scanner::try_extract_webpack_chunk_name;
 use crate::dependency::WorkerDependency;
 use crate::utils::get_literal_str_by_obj_prop;
 
@@ -22,14 +24,18 @@ pub struct WorkerScanner<'a> {
   module_identifier: &'a ModuleIdentifier,
   output_options: &'a OutputOptions,
   syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+  comments: Option<&'a dyn Comments>,
 }
 
 // new Worker(new URL("./foo.worker.js", import.meta.url));
+// navigator.serviceWorker.register(new URL("./sw.js", import.meta.url));
+// context.audioWorklet.addModule(new URL("./processor.js", import.meta.url));
 impl<'a> WorkerScanner<'a> {
   pub fn new(
     module_identifier: &'a ModuleIdentifier,
     output_options: &'a OutputOptions,
     syntax_list: &'a rspack_core::needs_refactor::WorkerSyntaxList,
+    comments: Option<&'a dyn Comments>,
   ) -> Self {
     Self {
       presentational_dependencies: Vec::new(),
@@ -39,14 +45,16 @@ impl<'a> WorkerScanner<'a> {
       module_identifier,
       output_options,
       syntax_list,
+      comments,
     }
   }
 
   fn add_dependencies(
     &mut self,
-    new_expr: &NewExpr,
+    span: Span,
     parsed_path: ParsedNewWorkerPath,
     parsed_options: Option<ParsedNewWorkerOptions>,
+    is_worklet: bool,
   ) {
     let mut hasher = RspackHash::from(self.output_options);
     self.module_identifier.hash(&mut hasher);
@@ -57,9 +65,11 @@ impl<'a> WorkerScanner<'a> {
       .rendered(self.output_options.hash_digest_length)
       .to_owned();
     let range = parsed_options.as_ref().map(|options| options.range);
-    let name = parsed_options.and_then(|options| options.name);
+    let name = parsed_path
+      .chunk_name
+      .or_else(|| parsed_options.and_then(|options| options.name));
     let output_module = self.output_options.module;
-    let span = ErrorSpan::from(new_expr.span);
+    let span = ErrorSpan::from(span);
     let dep = Box::new(WorkerDependency::new(
       parsed_path.range.0,
       parsed_path.range.1,
@@ -72,11 +82,20 @@ impl<'a> WorkerScanner<'a> {
       format!("{}:{}", span.start, span.end),
       Some(DependencyLocation::new(span.start, span.end)),
     );
+    // worklets are evaluated as modules, and neither `importScripts` nor `import()` is
+    // available in their global scope
+    let (chunk_loading, async_chunks) = if !is_worklet {
+      (self.output_options.worker_chunk_loading.clone(), None)
+    } else if output_module {
+      (ChunkLoading::Enable(ChunkLoadingType::Import), Some(false))
+    } else {
+      (ChunkLoading::Disable, Some(false))
+    };
     block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
       name,
       runtime: Some(runtime),
-      chunk_loading: Some(self.output_options.worker_chunk_loading.clone()),
-      async_chunks: None,
+      chunk_loading: Some(chunk_loading),
+      async_chunks,
       public_path: None,
       base_uri: None,
       filename: None,
@@ -85,7 +104,10 @@ impl<'a> WorkerScanner<'a> {
     block.add_dependency(dep);
     self.blocks.push(block);
 
-    if let Some(range) = range {
+    // the options of `addModule` don't have a `type`
+    if let Some(range) = range
+      && !is_worklet
+    {
       self
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
@@ -113,13 +135,11 @@ impl<'a> WorkerScanner<'a> {
     }
   }
 
-  fn parse_new_worker(
+  fn parse_worker_args(
     &self,
-    new_expr: &NewExpr,
+    args: &[ExprOrSpread],
   ) -> Option<(ParsedNewWorkerPath, Option<ParsedNewWorkerOptions>)> {
-    if self.syntax_list.match_new_worker(new_expr)
-      && let Some(args) = &new_expr.args
-      && let Some(expr_or_spread) = args.first()
+    if let Some(expr_or_spread) = args.first()
       && let ExprOrSpread {
         spread: None,
         expr: box Expr::New(new_url_expr),
@@ -129,6 +149,7 @@ impl<'a> WorkerScanner<'a> {
       let path = ParsedNewWorkerPath {
         range: (start, end),
         value: request,
+        chunk_name: try_extract_webpack_chunk_name(&self.comments, &new_url_expr.span),
       };
       let options = args.get(1).map(parse_new_worker_options);
       Some((path, options))
@@ -142,18 +163,33 @@ impl Visit for WorkerScanner<'_> {
   noop_visit_type!();
 
   fn visit_new_expr(&mut self, new_expr: &NewExpr) {
-    if let Some((parsed_path, parsed_options)) = self.parse_new_worker(new_expr) {
-      self.add_dependencies(new_expr, parsed_path, parsed_options);
+    if self.syntax_list.match_new_worker(new_expr)
+      && let Some(args) = &new_expr.args
+      && let Some((parsed_path, parsed_options)) = self.parse_worker_args(args)
+    {
+      self.add_dependencies(new_expr.span, parsed_path, parsed_options, false);
     } else {
       new_expr.visit_children_with(self);
     }
   }
+
+  fn visit_call_expr(&mut self, call_expr: &CallExpr) {
+    if let Some(syntax) = self.syntax_list.match_worker_call(call_expr)
+      && let Some((parsed_path, parsed_options)) = self.parse_worker_args(&call_expr.args)
+    {
+      let is_worklet = syntax.is_worklet();
+      self.add_dependencies(call_expr.span, parsed_path, parsed_options, is_worklet);
+    } else {
+      call_expr.visit_children_with(self);
+    }
+  }
 }
 
 #[derive(Debug)]
 struct ParsedNewWorkerPath {
   pub range: (u32, u32),
   pub value: String,
+  pub chunk_name: Option<String>,
 }
 
 #[derive(Debug)]
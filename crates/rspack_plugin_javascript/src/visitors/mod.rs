@@ -94,45 +94,11 @@ fn builtins_additional_feature_transforms<'b>(
 }
 
 /// Webpack builtin plugins
-/// - `define`: a port of `DefinePlugin`
 /// - `provide`: a port of `ProvidePlugin`
 fn builtins_webpack_plugin(options: &CompilerOptions, unresolved_mark: Mark) -> impl Fold + '_ {
-  chain!(
-    Optional::new(
-      rspack_swc_visitors::define(&options.builtins.define),
-      !options.builtins.define.is_empty()
-    ),
-    Optional::new(
-      builtins_webpack_plugin_define_optimizer(unresolved_mark),
-      !options.builtins.define.is_empty()
-    ),
-    Optional::new(
-      rspack_swc_visitors::provide(&options.builtins.provide, unresolved_mark),
-      !options.builtins.provide.is_empty()
-    )
-  )
-}
-
-/// Removing this will **not** cause semantic difference, but will avoid overheads that are not necessary.
-/// Expression simplifier and dead branch remover are working together to avoid parsing not necessary path.
-///
-/// Example:
-/// ```js
-/// if(process.env.NODE_ENV === "development") {
-///   module.exports = require(".../xxx.development.js")
-/// } else {
-///   module.exports = require(".../xxx.production.js")
-/// }
-/// ```
-/// The ordering of these two is important, `expr_simplifier` goes first and `dead_branch_remover` goes second.
-///
-/// `if(process.env.NODE_ENV === "development")` -> <define> -> `if("development" === "development")`
-/// `if("development" === "development") {} else {}` -> <expr_simplifier> -> `if(true) {} else {}`
-/// `if(true) {} else {}` -> <dead_branch_remover> -> `else` branch is removed
-fn builtins_webpack_plugin_define_optimizer(unresolved_mark: Mark) -> impl Fold {
-  chain!(
-    swc_visitor::expr_simplifier(unresolved_mark, Default::default()),
-    swc_visitor::dead_branch_remover(unresolved_mark)
+  Optional::new(
+    rspack_swc_visitors::provide(&options.builtins.provide, unresolved_mark),
+    !options.builtins.provide.is_empty(),
   )
 }
 
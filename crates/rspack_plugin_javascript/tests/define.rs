@@ -0,0 +1,93 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use rspack_core::{Define, DefinePlugin, Module, Plugin, PluginExt};
+use rspack_error::Result;
+use rspack_identifier::Identifiable;
+use rspack_testing::MemoryCompiler;
+
+/// Records the identifiers of the modules being built.
+#[derive(Debug)]
+struct BuiltModulesPlugin {
+  built: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl Plugin for BuiltModulesPlugin {
+  fn name(&self) -> &'static str {
+    "BuiltModulesPlugin"
+  }
+
+  async fn build_module(&self, module: &mut dyn Module) -> Result<()> {
+    self
+      .built
+      .lock()
+      .expect("should lock built modules")
+      .push(module.identifier().to_string());
+    Ok(())
+  }
+}
+
+fn take_built(built: &Mutex<Vec<String>>) -> Vec<String> {
+  let mut built = std::mem::take(&mut *built.lock().expect("should lock built modules"));
+  built.sort();
+  built
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_the_modules_using_the_changed_values() {
+  let values = Arc::new(Mutex::new(Define::from_iter([
+    ("A".to_string(), "1".to_string()),
+    ("B".to_string(), "2".to_string()),
+  ])));
+  let built = Arc::new(Mutex::new(vec![]));
+
+  let mut compiler = MemoryCompiler::from_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/define-values"),
+    |options, plugins| {
+      options.experiments.incremental_rebuild.make = Some(Default::default());
+      plugins.push(
+        DefinePlugin::from_fn({
+          let values = values.clone();
+          move || {
+            let values = values.lock().expect("should lock values").clone();
+            Box::pin(async move { Ok(values) })
+          }
+        })
+        .boxed(),
+      );
+      plugins.push(
+        BuiltModulesPlugin {
+          built: built.clone(),
+        }
+        .boxed(),
+      );
+    },
+  );
+  compiler.build().await.expect("should build");
+  assert_eq!(take_built(&built).len(), 4);
+
+  values
+    .lock()
+    .expect("should lock values")
+    .insert("A".to_string(), "3".to_string());
+  compiler.rebuild().await.expect("should rebuild");
+  let rebuilt = take_built(&built);
+  assert_eq!(rebuilt.len(), 1);
+  assert!(rebuilt[0].ends_with("a.js"));
+  compiler.assert_no_errors();
+
+  let source = compiler.asset("main.js");
+  assert!(source.contains("const a = 3;"));
+  assert!(source.contains("const b = 2;"));
+
+  // all the modules are rebuilt once a key is added, any of them may check whether it's defined
+  values
+    .lock()
+    .expect("should lock values")
+    .insert("C".to_string(), "\"on\"".to_string());
+  compiler.rebuild().await.expect("should rebuild");
+  assert_eq!(take_built(&built).len(), 4);
+  compiler.assert_no_errors();
+  assert!(compiler.asset("main.js").contains("\"on\""));
+}
@@ -0,0 +1 @@
+export const c = typeof C !== "undefined" ? C : "none";
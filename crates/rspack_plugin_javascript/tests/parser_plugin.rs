@@ -0,0 +1,122 @@
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use rspack_core::{
+  ConstDependency, ModuleType, ParserAndGenerator, Plugin, PluginContext, PluginExt,
+  PluginNormalModuleFactoryParserHookOutput, SpanExt,
+};
+use rspack_plugin_javascript::parser_and_generator::JavaScriptParserAndGenerator;
+use rspack_plugin_javascript::parser_plugin::JavascriptParserPlugin;
+use rspack_plugin_javascript::visitors::JavascriptParser;
+use rspack_testing::MemoryCompiler;
+use swc_core::ecma::ast::{ImportDecl, MemberExpr, ModuleDecl};
+
+/// Replaces `framework.version`, and records the import and export hooks called with the
+/// modules and the context they're parsed with.
+struct FrameworkParserPlugin {
+  calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl FrameworkParserPlugin {
+  fn record(&self, parser: &JavascriptParser, call: &str) {
+    let file = parser
+      .resource_data
+      .resource_path
+      .file_name()
+      .expect("should have file name")
+      .to_string_lossy();
+    self.calls.lock().expect("should lock calls").push(format!(
+      "{file} in {}: {call}",
+      parser.compiler_options.context
+    ));
+  }
+}
+
+impl JavascriptParserPlugin for FrameworkParserPlugin {
+  fn import(&self, parser: &mut JavascriptParser, _import_decl: &ImportDecl, source: &str) {
+    self.record(parser, &format!("import {source}"));
+  }
+
+  fn export(&self, parser: &mut JavascriptParser, _module_decl: &ModuleDecl) {
+    self.record(parser, "export");
+  }
+
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    (for_name == "framework.version").then(|| {
+      parser
+        .presentational_dependencies
+        .push(Box::new(ConstDependency::new(
+          expr.span.real_lo(),
+          expr.span.real_hi(),
+          "\"1.0.0\"".into(),
+          None,
+        )));
+      true
+    })
+  }
+}
+
+#[derive(Debug)]
+struct FrameworkPlugin {
+  calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Plugin for FrameworkPlugin {
+  fn name(&self) -> &'static str {
+    "FrameworkPlugin"
+  }
+
+  fn normal_module_factory_parser(
+    &self,
+    _ctx: PluginContext,
+    _module_type: &ModuleType,
+    parser_and_generator: &mut dyn ParserAndGenerator,
+  ) -> PluginNormalModuleFactoryParserHookOutput {
+    if let Some(parser_and_generator) =
+      parser_and_generator.downcast_mut::<JavaScriptParserAndGenerator>()
+    {
+      parser_and_generator.add_parser_plugin(Box::new(FrameworkParserPlugin {
+        calls: self.calls.clone(),
+      }));
+    }
+    Ok(())
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn call_the_parser_plugins_registered_by_plugins() {
+  let fixture_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/parser-plugin");
+  let calls = Arc::new(Mutex::new(vec![]));
+
+  let mut compiler = MemoryCompiler::from_fixture(&fixture_path, |_, plugins| {
+    plugins.push(
+      FrameworkPlugin {
+        calls: calls.clone(),
+      }
+      .boxed(),
+    );
+  });
+  compiler.build().await.expect("should build");
+  compiler.assert_no_errors();
+
+  let context = fixture_path.display();
+  let mut calls = std::mem::take(&mut *calls.lock().expect("should lock calls"));
+  calls.sort();
+  assert_eq!(
+    calls,
+    vec![
+      format!("a.js in {context}: export"),
+      format!("index.js in {context}: import ./a"),
+    ]
+  );
+  let main = compiler.asset("main.js");
+  // the import is still collected by the builtin scanner
+  assert!(main.contains("const a = \"a\""));
+  assert!(main.contains("\"1.0.0\""));
+  assert!(!main.contains("framework.version"));
+}
@@ -0,0 +1,247 @@
+use std::fmt;
+
+use json::{object::Object, JsonValue};
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+// The key toml uses to pass a datetime through serde, see `toml_datetime::__unstable::FIELD`.
+const TOML_DATETIME_FIELD: &str = "$__toml_private_datetime";
+
+/// Data formats which are parsed into a [JsonValue], so they share the exports analysis
+/// and code generation of json modules.
+#[derive(Debug, Clone, Copy)]
+pub enum DataFormat {
+  Json5,
+  Yaml,
+  Toml,
+}
+
+/// A parsing error, `start` and `end` are byte offsets of the parsed source.
+#[derive(Debug)]
+pub struct DataFormatError {
+  pub start: usize,
+  pub end: usize,
+  pub message: String,
+}
+
+impl DataFormat {
+  pub fn name(&self) -> &'static str {
+    match self {
+      DataFormat::Json5 => "Json5",
+      DataFormat::Yaml => "Yaml",
+      DataFormat::Toml => "Toml",
+    }
+  }
+
+  pub fn parse(&self, source: &str) -> Result<JsonValue, DataFormatError> {
+    match self {
+      DataFormat::Json5 => json5::from_str::<DataValue>(source)
+        .map(|v| v.0)
+        .map_err(|e| {
+          let json5::Error::Message { msg, location } = e;
+          let start = location
+            .map(|l| line_column_to_offset(source, l.line, l.column))
+            .unwrap_or_default();
+          DataFormatError::at(source, start, msg)
+        }),
+      DataFormat::Yaml => serde_yaml::from_str::<DataValue>(source)
+        .map(|v| v.0)
+        .map_err(|e| {
+          let start = e.location().map(|l| l.index()).unwrap_or_default();
+          DataFormatError::at(source, start, e.to_string())
+        }),
+      DataFormat::Toml => toml::from_str::<DataValue>(source)
+        .map(|v| v.0)
+        .map_err(|e| match e.span() {
+          Some(span) => DataFormatError {
+            start: span.start,
+            end: span.end,
+            message: e.message().to_string(),
+          },
+          None => DataFormatError::at(source, 0, e.message().to_string()),
+        }),
+    }
+  }
+}
+
+impl DataFormatError {
+  /// Creates an error pointing to the character at `start`.
+  fn at(source: &str, start: usize, message: String) -> Self {
+    let start = start.min(source.len());
+    let end = source[start..]
+      .chars()
+      .next()
+      .map_or(start, |c| start + c.len_utf8());
+    Self {
+      start,
+      end,
+      message,
+    }
+  }
+}
+
+/// Converts a 1-based line and 1-based column in characters to a byte offset.
+fn line_column_to_offset(source: &str, line: usize, column: usize) -> usize {
+  let line_offset = source
+    .split_inclusive('\n')
+    .take(line.saturating_sub(1))
+    .map(str::len)
+    .sum::<usize>();
+  source[line_offset..]
+    .chars()
+    .take(column.saturating_sub(1))
+    .fold(line_offset, |acc, cur| acc + cur.len_utf8())
+}
+
+/// Deserializes any self-describing format into a [JsonValue], keeps the order of keys.
+struct DataValue(JsonValue);
+
+impl<'de> Deserialize<'de> for DataValue {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer
+      .deserialize_any(DataValueVisitor)
+      .map(DataValue)
+  }
+}
+
+struct DataValueVisitor;
+
+impl<'de> Visitor<'de> for DataValueVisitor {
+  type Value = JsonValue;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("any valid data value")
+  }
+
+  fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+    Ok(v.into())
+  }
+
+  fn visit_none<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_unit<E>(self) -> Result<Self::Value, E> {
+    Ok(JsonValue::Null)
+  }
+
+  fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+    DataValue::deserialize(deserializer).map(|v| v.0)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut array = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+    while let Some(DataValue(value)) = seq.next_element()? {
+      array.push(value);
+    }
+    Ok(JsonValue::Array(array))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+    let mut object = Object::with_capacity(map.size_hint().unwrap_or_default());
+    while let Some(DataKey(key)) = map.next_key()? {
+      let DataValue(value) = map.next_value()?;
+      if key == TOML_DATETIME_FIELD {
+        return Ok(value);
+      }
+      object.insert(&key, value);
+    }
+    Ok(JsonValue::Object(object))
+  }
+}
+
+/// Keys of yaml mappings are not necessarily strings, stringify them like `JSON.stringify`.
+struct DataKey(String);
+
+impl<'de> Deserialize<'de> for DataKey {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(DataKeyVisitor).map(DataKey)
+  }
+}
+
+struct DataKeyVisitor;
+
+impl<'de> Visitor<'de> for DataKeyVisitor {
+  type Value = String;
+
+  fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    formatter.write_str("a string, number, boolean or null key")
+  }
+
+  fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+    Ok(v.to_string())
+  }
+
+  fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+    Ok(v.to_string())
+  }
+
+  fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+    Ok(v.to_string())
+  }
+
+  fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+    Ok(JsonValue::from(v).dump())
+  }
+
+  fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+    Ok(v.to_string())
+  }
+
+  fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+    Ok(v)
+  }
+
+  fn visit_unit<E>(self) -> Result<Self::Value, E> {
+    Ok("null".to_string())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn parse_data_formats() {
+    let yaml = DataFormat::Yaml
+      .parse("b: 1\na:\n  - x\n  - true\n2: ~\n")
+      .expect("should parse yaml");
+    assert_eq!(yaml.dump(), r#"{"b":1,"a":["x",true],"2":null}"#);
+
+    let toml = DataFormat::Toml
+      .parse("b = 1\n[a]\nc = \"x\"\n")
+      .expect("should parse toml");
+    assert_eq!(toml.dump(), r#"{"b":1,"a":{"c":"x"}}"#);
+
+    let json5 = DataFormat::Json5
+      .parse("{b: 1, 'a': [0x10,],}")
+      .expect("should parse json5");
+    assert_eq!(json5.dump(), r#"{"b":1,"a":[16]}"#);
+  }
+
+  #[test]
+  fn data_format_error_span() {
+    let source = "{\n  a: 1,\n  b: @\n}";
+    let error = DataFormat::Json5.parse(source).expect_err("should fail");
+    assert_eq!(&source[error.start..error.end], "@");
+  }
+}
@@ -20,13 +20,18 @@ use rspack_error::{
   TraceableError,
 };
 
+use crate::data_format::DataFormat;
 use crate::json_exports_dependency::JsonExportsDependency;
 
+mod data_format;
 mod json_exports_dependency;
 mod utils;
 
 #[derive(Debug)]
-struct JsonParserAndGenerator;
+struct JsonParserAndGenerator {
+  // `None` for plain json, which is parsed by the `json` crate.
+  format: Option<DataFormat>,
+}
 
 impl ParserAndGenerator for JsonParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
@@ -56,51 +61,69 @@ impl ParserAndGenerator for JsonParserAndGenerator {
     let strip_bom_source = source.strip_prefix('\u{feff}');
     let need_strip_bom = strip_bom_source.is_some();
 
-    let parse_result = json::parse(strip_bom_source.unwrap_or(&source)).map_err(|e| {
-      match e {
-        UnexpectedCharacter { ch, line, column } => {
-          let rope = ropey::Rope::from_str(&source);
-          let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
-          let start_offset = source[line_offset..]
-            .chars()
-            .take(column)
-            .fold(line_offset, |acc, cur| acc + cur.len_utf8());
-          let start_offset = if need_strip_bom {
-            start_offset + 1
-          } else {
-            start_offset
-          };
-          TraceableError::from_file(
-            resource_data.resource_path.to_string_lossy().to_string(),
-            source.into_owned(),
-            // one character offset
-            start_offset,
-            start_offset + 1,
-            "Json parsing error".to_string(),
-            format!("Unexpected character {ch}"),
-          )
-          .with_kind(DiagnosticKind::Json)
-          .into()
-        }
-        ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => {
-          internal_error!(format!("{e}"))
-        }
-        UnexpectedEndOfJson => {
-          // End offset of json file
-          let offset = source.len() - 1;
+    let parse_result = if let Some(format) = self.format {
+      let bom_len = source.len() - strip_bom_source.map_or(source.len(), str::len);
+      format
+        .parse(strip_bom_source.unwrap_or(&source))
+        .map_err(|e| {
           TraceableError::from_file(
             resource_data.resource_path.to_string_lossy().to_string(),
             source.into_owned(),
-            offset,
-            offset,
-            "Json parsing error".to_string(),
-            format!("{e}"),
+            e.start + bom_len,
+            e.end + bom_len,
+            format!("{} parsing error", format.name()),
+            e.message,
           )
           .with_kind(DiagnosticKind::Json)
           .into()
+        })
+    } else {
+      json::parse(strip_bom_source.unwrap_or(&source)).map_err(|e| {
+        match e {
+          UnexpectedCharacter { ch, line, column } => {
+            let rope = ropey::Rope::from_str(&source);
+            let line_offset = rope.try_line_to_byte(line - 1).expect("TODO:");
+            let start_offset = source[line_offset..]
+              .chars()
+              .take(column)
+              .fold(line_offset, |acc, cur| acc + cur.len_utf8());
+            let start_offset = if need_strip_bom {
+              start_offset + 1
+            } else {
+              start_offset
+            };
+            TraceableError::from_file(
+              resource_data.resource_path.to_string_lossy().to_string(),
+              source.into_owned(),
+              // one character offset
+              start_offset,
+              start_offset + 1,
+              "Json parsing error".to_string(),
+              format!("Unexpected character {ch}"),
+            )
+            .with_kind(DiagnosticKind::Json)
+            .into()
+          }
+          ExceededDepthLimit | WrongType(_) | FailedUtf8Parsing => {
+            internal_error!(format!("{e}"))
+          }
+          UnexpectedEndOfJson => {
+            // End offset of json file
+            let offset = source.len() - 1;
+            TraceableError::from_file(
+              resource_data.resource_path.to_string_lossy().to_string(),
+              source.into_owned(),
+              offset,
+              offset,
+              "Json parsing error".to_string(),
+              format!("{e}"),
+            )
+            .with_kind(DiagnosticKind::Json)
+            .into()
+          }
         }
-      }
-    });
+      })
+    };
 
     let (diagnostics, data) = match parse_result {
       Ok(data) => (vec![], Some(data)),
@@ -208,8 +231,22 @@ impl Plugin for JsonPlugin {
   ) -> Result<()> {
     ctx.context.register_parser_and_generator_builder(
       rspack_core::ModuleType::Json,
-      Box::new(|| Box::new(JsonParserAndGenerator {})),
+      Box::new(|| Box::new(JsonParserAndGenerator { format: None })),
     );
+    for (module_type, format) in [
+      (rspack_core::ModuleType::Json5, DataFormat::Json5),
+      (rspack_core::ModuleType::Yaml, DataFormat::Yaml),
+      (rspack_core::ModuleType::Toml, DataFormat::Toml),
+    ] {
+      ctx.context.register_parser_and_generator_builder(
+        module_type,
+        Box::new(move || {
+          Box::new(JsonParserAndGenerator {
+            format: Some(format),
+          })
+        }),
+      );
+    }
 
     Ok(())
   }
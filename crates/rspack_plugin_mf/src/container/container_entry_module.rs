@@ -187,8 +187,11 @@ impl Module for ContainerEntryModule {
           &mut code_generation_result.runtime_requirements,
           compilation,
         );
+        let environment = &compilation.options.output.environment;
         let module_raw = returning_function(
+          environment,
           &returning_function(
+            environment,
             &modules_iter
               .map(|(_, _, request, dependency_id)| {
                 module_raw(
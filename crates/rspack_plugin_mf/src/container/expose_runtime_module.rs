@@ -72,7 +72,13 @@ impl RuntimeModule for ExposeRuntimeModule {
     let module_map = data
       .module_map
       .iter()
-      .map(|(name, factory)| format!("{}: {},", json_stringify(name), basic_function("", factory)))
+      .map(|(name, factory)| {
+        format!(
+          "{}: {},",
+          json_stringify(name),
+          basic_function(&compilation.options.output.environment, "", factory)
+        )
+      })
       .collect::<Vec<_>>()
       .join("\n");
     let mut source = format!(
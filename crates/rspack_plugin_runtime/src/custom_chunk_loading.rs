@@ -0,0 +1,150 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, ChunkLoading, ChunkLoadingType, CompilationParams, CompilerOptions, Plugin,
+  PluginContext, PluginRuntimeRequirementsInTreeOutput, PluginThisCompilationHookOutput,
+  RuntimeGlobals, RuntimeModule, RuntimeRequirementsInTreeArgs, ThisCompilationArgs,
+};
+use rspack_error::{internal_error, Result};
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+type RuntimeModuleFactory = Box<dyn Fn() -> Box<dyn RuntimeModule> + Send + Sync>;
+
+/// Registers a chunk loading type which is not built in, so it can be selected by its name
+/// through `output.chunkLoading` or the `chunkLoading` of an entry.
+///
+/// The runtime module created by `runtime_module` is added to the chunks loading their chunks
+/// with this type, once they require any of the runtime globals it handles.
+///
+/// ```ignore
+/// plugins.push(
+///   CustomChunkLoadingPlugin::new("host", || Box::<HostChunkLoadingRuntimeModule>::default())
+///     .handle(
+///       RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+///       RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME,
+///     )
+///     .boxed(),
+/// );
+/// ```
+pub struct CustomChunkLoadingPlugin {
+  chunk_loading: ChunkLoading,
+  handlers: Vec<(RuntimeGlobals, RuntimeGlobals)>,
+  runtime_module: RuntimeModuleFactory,
+}
+
+impl CustomChunkLoadingPlugin {
+  pub fn new(
+    name: impl Into<String>,
+    runtime_module: impl Fn() -> Box<dyn RuntimeModule> + Send + Sync + 'static,
+  ) -> Self {
+    Self {
+      chunk_loading: ChunkLoading::Enable(ChunkLoadingType::Custom(name.into())),
+      handlers: Vec::new(),
+      runtime_module: Box::new(runtime_module),
+    }
+  }
+
+  /// Handle `runtime_requirement` with the runtime module, which needs `requirements` to do so.
+  pub fn handle(
+    mut self,
+    runtime_requirement: RuntimeGlobals,
+    requirements: RuntimeGlobals,
+  ) -> Self {
+    self.handlers.push((runtime_requirement, requirements));
+    self
+  }
+}
+
+impl fmt::Debug for CustomChunkLoadingPlugin {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("CustomChunkLoadingPlugin")
+      .field("chunk_loading", &self.chunk_loading)
+      .field("handlers", &self.handlers)
+      .finish()
+  }
+}
+
+#[async_trait]
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    if let ChunkLoading::Enable(ChunkLoadingType::Custom(name)) = &self.chunk_loading {
+      ctx.context.register_chunk_loading_type(name);
+    }
+    Ok(())
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    let compilation = &mut args.compilation;
+    let chunk = args.chunk;
+    if !is_enabled_for_chunk(chunk, &self.chunk_loading, compilation) {
+      return Ok(());
+    }
+
+    let mut has_chunk_loading = false;
+    for (runtime_requirement, requirements) in &self.handlers {
+      if args.runtime_requirements.contains(*runtime_requirement) {
+        has_chunk_loading = true;
+        args.runtime_requirements_mut.insert(*requirements);
+      }
+    }
+
+    if has_chunk_loading {
+      compilation.add_runtime_module(chunk, (self.runtime_module)());
+    }
+
+    Ok(())
+  }
+}
+
+/// Checks that the chunk loading type `name` which is not built in is provided by a
+/// [CustomChunkLoadingPlugin].
+#[derive(Debug)]
+pub(crate) struct EnableCustomChunkLoadingPlugin {
+  name: String,
+}
+
+impl EnableCustomChunkLoadingPlugin {
+  pub(crate) fn new(name: String) -> Self {
+    Self { name }
+  }
+}
+
+#[async_trait]
+impl Plugin for EnableCustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "EnableCustomChunkLoadingPlugin"
+  }
+
+  async fn this_compilation(
+    &self,
+    args: ThisCompilationArgs<'_>,
+    _params: &CompilationParams,
+  ) -> PluginThisCompilationHookOutput {
+    if args
+      .this_compilation
+      .plugin_driver
+      .registered_chunk_loading_types
+      .contains(&self.name)
+    {
+      return Ok(());
+    }
+    Err(internal_error!(
+      "Unsupported chunk loading type {}.\nPlugins which provide custom chunk loading types must be registered with a CustomChunkLoadingPlugin of the same name.",
+      self.name
+    ))
+  }
+}
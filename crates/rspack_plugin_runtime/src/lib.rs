@@ -25,6 +25,9 @@ pub use import_scripts_chunk_loading::ImportScriptsChunkLoadingPlugin;
 mod runtime_module;
 mod startup_chunk_dependencies;
 pub use startup_chunk_dependencies::StartupChunkDependenciesPlugin;
+mod custom_chunk_loading;
+pub use custom_chunk_loading::CustomChunkLoadingPlugin;
+use custom_chunk_loading::EnableCustomChunkLoadingPlugin;
 
 pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut Vec<BoxPlugin>) {
   match loading_type {
@@ -60,5 +63,9 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin.boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin.boxed()),
+    // Provided by the `CustomChunkLoadingPlugin` registering it, which must exist
+    ChunkLoadingType::Custom(name) => {
+      plugins.push(EnableCustomChunkLoadingPlugin::new(name).boxed())
+    }
   }
 }
@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, impl_runtime_module, returning_function,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -23,8 +23,30 @@ impl RuntimeModule for CompatGetDefaultExportRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/compat_get_default_export.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(include_str!("runtime/compat_get_default_export.js")).boxed();
+    }
+    RawSource::from(format!(
+      "// getDefaultExport function for compatibility with non-harmony modules\n{} = {};\n",
+      RuntimeGlobals::COMPAT_GET_DEFAULT_EXPORT,
+      basic_function(
+        environment,
+        "module",
+        &format!(
+          r#"var getter = module && module.__esModule ?
+	{} :
+	{};
+{}(getter, {{ a: getter }});
+return getter;"#,
+          returning_function(environment, "module['default']", ""),
+          returning_function(environment, "module", ""),
+          RuntimeGlobals::DEFINE_PROPERTY_GETTERS
+        )
+      )
+    ))
+    .boxed()
   }
 }
 
@@ -1,5 +1,5 @@
 use rspack_core::{
-  impl_runtime_module,
+  destructure_array, impl_runtime_module, render_const, render_runtime_template,
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
@@ -38,6 +38,7 @@ impl RuntimeModule for CssLoadingRuntimeModule {
         .chunk_by_ukey
         .get(&chunk_ukey)
         .expect("Chunk not found");
+      let environment = &compilation.options.output.environment;
       let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk_ukey);
 
       let with_hmr = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
@@ -76,7 +77,7 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       )));
 
       source.add(RawSource::from(
-        include_str!("runtime/css_loading.js").replace(
+        render_runtime_template(environment, include_str!("runtime/css_loading.js")).replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
           &compilation.options.output.cross_origin_loading.to_string(),
         ),
@@ -93,18 +94,27 @@ impl RuntimeModule for CssLoadingRuntimeModule {
           &compilation.options.output.chunk_loading_global
         );
         source.add(RawSource::from(
-          include_str!("runtime/css_loading_with_loading.js")
-            .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
-            .replace(
-              "CSS_MATCHER",
-              &render_condition_map(&condition_map, "chunkId").to_string(),
-            ),
+          render_runtime_template(
+            environment,
+            include_str!("runtime/css_loading_with_loading.js"),
+          )
+          .replace(
+            "$CHUNK_DATA$",
+            &destructure_array(environment, &["chunkIds"], "data"),
+          )
+          .replace("$CONST$", render_const(environment))
+          .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
+          .replace(
+            "CSS_MATCHER",
+            &render_condition_map(&condition_map, "chunkId").to_string(),
+          ),
         ));
       }
 
       if with_hmr {
-        source.add(RawSource::from(include_str!(
-          "runtime/css_loading_with_hmr.js"
+        source.add(RawSource::from(render_runtime_template(
+          environment,
+          include_str!("runtime/css_loading_with_hmr.js"),
         )));
       }
 
@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -23,8 +23,28 @@ impl RuntimeModule for DefinePropertyGettersRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/define_property_getters.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(include_str!("runtime/define_property_getters.js")).boxed();
+    }
+    let has_own_property = RuntimeGlobals::HAS_OWN_PROPERTY;
+    RawSource::from(format!(
+      "{} = {};\n",
+      RuntimeGlobals::DEFINE_PROPERTY_GETTERS,
+      basic_function(
+        environment,
+        "exports, definition",
+        &format!(
+          r#"for(var key in definition) {{
+	if({has_own_property}(definition, key) && !{has_own_property}(exports, key)) {{
+		Object.defineProperty(exports, key, {{ enumerable: true, get: definition[key] }});
+	}}
+}}"#
+        )
+      )
+    ))
+    .boxed()
   }
 }
 
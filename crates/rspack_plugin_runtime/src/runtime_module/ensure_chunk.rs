@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, impl_runtime_module, returning_function,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -25,10 +25,45 @@ impl RuntimeModule for EnsureChunkRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(match self.has_ensure_chunk_handlers {
+        true => include_str!("runtime/ensure_chunk.js"),
+        false => include_str!("runtime/ensure_chunk_with_inline.js"),
+      })
+      .boxed();
+    }
+    let ensure_chunk = RuntimeGlobals::ENSURE_CHUNK;
+    let handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS;
     RawSource::from(match self.has_ensure_chunk_handlers {
-      true => include_str!("runtime/ensure_chunk.js"),
-      false => include_str!("runtime/ensure_chunk_with_inline.js"),
+      true => format!(
+        r#"{handlers} = {{}};
+// This file contains only the entry chunk.
+// The chunk loading function for additional chunks
+{ensure_chunk} = {};
+"#,
+        basic_function(
+          environment,
+          "chunkId",
+          &format!(
+            "return Promise.all(Object.keys({handlers}).reduce({}, []));",
+            basic_function(
+              environment,
+              "promises, key",
+              &format!("\t{handlers}[key](chunkId, promises);\n\treturn promises;")
+            )
+          )
+        )
+      ),
+      false => format!(
+        r#"// The chunk loading function for additional chunks
+// Since all referenced chunks are already included
+// in this file, this function is empty here.
+{ensure_chunk} = {};
+"#,
+        returning_function(environment, "Promise.resolve()", "")
+      ),
     })
     .boxed()
   }
@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  impl_runtime_module, returning_function,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -23,8 +23,21 @@ impl RuntimeModule for HasOwnPropertyRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/has_own_property.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(include_str!("runtime/has_own_property.js")).boxed();
+    }
+    RawSource::from(format!(
+      "{} = {};\n",
+      RuntimeGlobals::HAS_OWN_PROPERTY,
+      returning_function(
+        environment,
+        "Object.prototype.hasOwnProperty.call(obj, prop)",
+        "obj, prop"
+      )
+    ))
+    .boxed()
   }
 }
 
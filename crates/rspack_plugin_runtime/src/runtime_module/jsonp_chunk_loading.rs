@@ -1,5 +1,5 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, destructure_array, impl_runtime_module, render_const, render_runtime_template,
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   Chunk, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
@@ -50,6 +50,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       .get(&self.chunk.expect("The chunk should be attached"))
       .expect("should have chunk");
 
+    let environment = &compilation.options.output.environment;
     let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk.ukey);
     let with_base_uri = runtime_requirements.contains(RuntimeGlobals::BASE_URI);
     let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
@@ -92,7 +93,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       let body = if matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
         "installedChunks[chunkId] = 0;".to_string()
       } else {
-        include_str!("runtime/jsonp_chunk_loading.js")
+        render_runtime_template(environment, include_str!("runtime/jsonp_chunk_loading.js"))
           .replace("$JS_MATCHER$", has_js_matcher.to_string().as_str())
           .replace(
             "$MATCH_FALLBACK$",
@@ -105,39 +106,45 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       };
 
       source.add(RawSource::from(format!(
-        r#"
-        {}.j = function (chunkId, promises) {{
-          {body}
-        }}
-        "#,
-        RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+        "{}.j = {};\n",
+        RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        basic_function(environment, "chunkId, promises", &body)
       )));
     }
 
     if with_hmr {
       source.add(RawSource::from(
-        include_str!("runtime/jsonp_chunk_loading_with_hmr.js")
-          .replace("$GLOBAL_OBJECT$", &compilation.options.output.global_object)
-          .replace(
-            "$HOT_UPDATE_GLOBAL$",
-            &serde_json::to_string(&compilation.options.output.hot_update_global)
-              .expect("failed to serde_json::to_string(hot_update_global)"),
-          ),
+        render_runtime_template(
+          environment,
+          include_str!("runtime/jsonp_chunk_loading_with_hmr.js"),
+        )
+        .replace("$GLOBAL_OBJECT$", &compilation.options.output.global_object)
+        .replace(
+          "$HOT_UPDATE_GLOBAL$",
+          &serde_json::to_string(&compilation.options.output.hot_update_global)
+            .expect("failed to serde_json::to_string(hot_update_global)"),
+        ),
       ));
       source.add(RawSource::from(
-        include_str!("runtime/javascript_hot_module_replacement.js").replace("$key$", "jsonp"),
+        render_runtime_template(
+          environment,
+          include_str!("runtime/javascript_hot_module_replacement.js"),
+        )
+        .replace("$key$", "jsonp"),
       ));
     }
 
     if with_hmr_manifest {
-      source.add(RawSource::from(include_str!(
-        "runtime/jsonp_chunk_loading_with_hmr_manifest.js"
+      source.add(RawSource::from(render_runtime_template(
+        environment,
+        include_str!("runtime/jsonp_chunk_loading_with_hmr_manifest.js"),
       )));
     }
 
     if with_on_chunk_load {
-      source.add(RawSource::from(include_str!(
-        "runtime/jsonp_chunk_loading_with_on_chunk_load.js"
+      source.add(RawSource::from(render_runtime_template(
+        environment,
+        include_str!("runtime/jsonp_chunk_loading_with_on_chunk_load.js"),
       )));
     }
 
@@ -147,15 +154,24 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
         &compilation.options.output.global_object, &compilation.options.output.chunk_loading_global
       );
       source.add(RawSource::from(
-        include_str!("runtime/jsonp_chunk_loading_with_callback.js")
-          .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
-          .replace(
-            "$WITH_ON_CHUNK_LOAD$",
-            match with_on_chunk_load {
-              true => "return __webpack_require__.O(result);",
-              false => "",
-            },
-          ),
+        render_runtime_template(
+          environment,
+          include_str!("runtime/jsonp_chunk_loading_with_callback.js"),
+        )
+        .replace(
+          "$CHUNK_DATA$",
+          &destructure_array(environment, &["chunkIds", "moreModules", "runtime"], "data")
+            .replace('\n', "\n\t"),
+        )
+        .replace("$CONST$", render_const(environment))
+        .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
+        .replace(
+          "$WITH_ON_CHUNK_LOAD$",
+          match with_on_chunk_load {
+            true => "return __webpack_require__.O(result);",
+            false => "",
+          },
+        ),
       ));
     }
 
@@ -1,5 +1,5 @@
 use rspack_core::{
-  impl_runtime_module,
+  impl_runtime_module, optional_access,
   rspack_sources::{BoxSource, RawSource, SourceExt},
   Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
@@ -58,7 +58,15 @@ impl RuntimeModule for LoadScriptRuntimeModule {
           &cross_origin_loading,
         )
         .replace("$URL$", &url)
-        .replace("$SCRIPT_TYPE$", &script_type),
+        .replace("$SCRIPT_TYPE$", &script_type)
+        .replace(
+          "$REMOVE_SCRIPT$",
+          &optional_access(
+            &compilation.options.output.environment,
+            "script.parentNode",
+            "removeChild(script)",
+          ),
+        ),
     )
     .boxed()
   }
@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -23,8 +23,24 @@ impl RuntimeModule for MakeNamespaceObjectRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/make_namespace_object.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(include_str!("runtime/make_namespace_object.js")).boxed();
+    }
+    RawSource::from(format!(
+      "// define __esModule on exports\n{} = {};\n",
+      RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+      basic_function(
+        environment,
+        "exports",
+        r#"if(typeof Symbol !== 'undefined' && Symbol.toStringTag) {
+	Object.defineProperty(exports, Symbol.toStringTag, { value: 'Module' });
+}
+Object.defineProperty(exports, '__esModule', { value: true });"#
+      )
+    ))
+    .boxed()
   }
 }
 
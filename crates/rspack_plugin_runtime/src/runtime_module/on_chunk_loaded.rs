@@ -1,7 +1,7 @@
 use rspack_core::{
-  impl_runtime_module,
+  basic_function, destructure_array, impl_runtime_module, render_const, returning_function,
   rspack_sources::{BoxSource, RawSource, SourceExt},
-  Compilation, RuntimeModule,
+  Compilation, RuntimeGlobals, RuntimeModule,
 };
 use rspack_identifier::Identifier;
 
@@ -23,8 +23,55 @@ impl RuntimeModule for OnChunkLoadedRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> BoxSource {
-    RawSource::from(include_str!("runtime/on_chunk_loaded.js")).boxed()
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let environment = &compilation.options.output.environment;
+    if environment.is_es5() {
+      return RawSource::from(include_str!("runtime/on_chunk_loaded.js")).boxed();
+    }
+    let on_chunks_loaded = RuntimeGlobals::ON_CHUNKS_LOADED;
+    let body = format!(
+      r#"if (chunkIds) {{
+	priority = priority || 0;
+	for (var i = deferred.length; i > 0 && deferred[i - 1][2] > priority; i--)
+		deferred[i] = deferred[i - 1];
+	deferred[i] = [chunkIds, fn, priority];
+	return;
+}}
+var notFulfilled = Infinity;
+for (var i = 0; i < deferred.length; i++) {{
+	{destructure}
+	var fulfilled = true;
+	for (var j = 0; j < chunkIds.length; j++) {{
+		if (
+			(priority & (1 === 0) || notFulfilled >= priority) &&
+			Object.keys({on_chunks_loaded}).every({every})
+		) {{
+			chunkIds.splice(j--, 1);
+		}} else {{
+			fulfilled = false;
+			if (priority < notFulfilled) notFulfilled = priority;
+		}}
+	}}
+	if (fulfilled) {{
+		deferred.splice(i--, 1);
+		var r = fn();
+		if (r !== undefined) result = r;
+	}}
+}}
+return result;"#,
+      destructure = destructure_array(environment, &["chunkIds", "fn", "priority"], "deferred[i]"),
+      every = returning_function(
+        environment,
+        &format!("{on_chunks_loaded}[key](chunkIds[j])"),
+        "key"
+      ),
+    );
+    RawSource::from(format!(
+      "{} deferred = [];\n{on_chunks_loaded} = {};\n",
+      render_const(environment),
+      basic_function(environment, "result, chunkIds, fn, priority", &body)
+    ))
+    .boxed()
   }
 }
 
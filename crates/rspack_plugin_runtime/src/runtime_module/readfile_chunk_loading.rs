@@ -1,5 +1,5 @@
 use rspack_core::{
-  impl_runtime_module,
+  impl_runtime_module, render_runtime_template,
   rspack_sources::{BoxSource, ConcatSource, RawSource, SourceExt},
   Chunk, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
@@ -125,7 +125,11 @@ impl RuntimeModule for ReadFileChunkLoadingRuntimeModule {
         "runtime/readfile_chunk_loading_with_hmr.js"
       )));
       source.add(RawSource::from(
-        include_str!("runtime/javascript_hot_module_replacement.js").replace("$key$", "readFileVm"),
+        render_runtime_template(
+          &compilation.options.output.environment,
+          include_str!("runtime/javascript_hot_module_replacement.js"),
+        )
+        .replace("$key$", "readFileVm"),
       ));
     }
 
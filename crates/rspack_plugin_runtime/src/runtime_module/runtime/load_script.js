@@ -41,7 +41,7 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
-		script.parentNode && script.parentNode.removeChild(script);
+		$REMOVE_SCRIPT$;
 		doneFns &&
 			doneFns.forEach(function (fn) {
 				return fn(event);
@@ -48,7 +48,7 @@ static GLOBALS_ON_REQUIRE: Lazy<Vec<RuntimeGlobals>> = Lazy::new(|| {
     RuntimeGlobals::SCRIPT_NONCE,
     // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
-    // RuntimeGlobals::WASM_INSTANCES,
+    RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
     RuntimeGlobals::SHARE_SCOPE_MAP,
     RuntimeGlobals::INITIALIZE_SHARING,
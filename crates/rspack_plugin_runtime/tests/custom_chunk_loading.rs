@@ -0,0 +1,81 @@
+use std::path::Path;
+
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  ChunkLoading, ChunkLoadingType, Compilation, PluginExt, RuntimeGlobals, RuntimeModule,
+};
+use rspack_identifier::Identifier;
+use rspack_plugin_runtime::{enable_chunk_loading_plugin, CustomChunkLoadingPlugin};
+use rspack_testing::MemoryCompiler;
+
+#[derive(Debug, Eq)]
+struct HostChunkLoadingRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for HostChunkLoadingRuntimeModule {
+  fn default() -> Self {
+    Self {
+      id: Identifier::from("webpack/runtime/host_chunk_loading"),
+    }
+  }
+}
+
+impl RuntimeModule for HostChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _compilation: &Compilation) -> BoxSource {
+    RawSource::from(format!(
+      "{}.host = function (chunkId, promises) {{ promises.push(host.loadChunk({}(chunkId))); }};",
+      RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+      RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME
+    ))
+    .boxed()
+  }
+}
+
+impl_runtime_module!(HostChunkLoadingRuntimeModule);
+
+/// Build the fixture loading its chunks with the `host` chunk loading type.
+async fn build(register: bool) -> (MemoryCompiler, rspack_error::Result<()>) {
+  let mut compiler = MemoryCompiler::from_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/custom-chunk-loading"),
+    |options, plugins| {
+      let chunk_loading_type = ChunkLoadingType::Custom("host".to_string());
+      options.output.chunk_loading = ChunkLoading::Enable(chunk_loading_type.clone());
+      enable_chunk_loading_plugin(chunk_loading_type, plugins);
+      if register {
+        plugins.push(
+          CustomChunkLoadingPlugin::new("host", || Box::<HostChunkLoadingRuntimeModule>::default())
+            .handle(
+              RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+              RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME,
+            )
+            .boxed(),
+        );
+      }
+    },
+  );
+  let result = compiler.build().await;
+  (compiler, result)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn registered_chunk_loading_type() {
+  let (compiler, result) = build(true).await;
+  result.expect("should build");
+  assert!(compiler.asset("main.js").contains("host.loadChunk"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unregistered_chunk_loading_type() {
+  let (_, result) = build(false).await;
+  let error = result.expect_err("should fail to build").to_string();
+  assert!(
+    error.contains("Unsupported chunk loading type host"),
+    "{error}"
+  );
+}
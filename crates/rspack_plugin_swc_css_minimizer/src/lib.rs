@@ -1,11 +1,95 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::Path;
+use std::sync::Mutex;
+
 use async_trait::async_trait;
 use rayon::prelude::{IntoParallelRefMutIterator, ParallelIterator};
-use rspack_core::{rspack_sources::MapOptions, Plugin};
-use rspack_error::Result;
-use rspack_plugin_css::swc_css_compiler::{SwcCssCompiler, SwcCssSourceMapGenConfig};
+use regex::Regex;
+use rspack_core::{
+  rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt},
+  AssetInfo, ChunkHashArgs, CompilationAsset, Plugin, PluginChunkHashHookOutput, PluginContext,
+  PluginProcessAssetsOutput, ProcessAssetsArgs,
+};
+use rspack_error::{internal_error, Result};
+use rspack_plugin_css::swc_css_compiler::{
+  SwcCssCompiler, SwcCssMinifyOptions, SwcCssSourceMapGenConfig,
+};
+use rspack_plugin_css::targets::resolve_targets;
+use rspack_regex::RspackRegex;
+use rspack_util::try_any_sync;
+
+#[derive(Debug, Clone, Default, Hash)]
+pub struct SwcCssMinimizerRspackPluginOptions {
+  pub extract_comments: Option<String>,
+  pub test: Option<SwcCssMinimizerRules>,
+  pub include: Option<SwcCssMinimizerRules>,
+  pub exclude: Option<SwcCssMinimizerRules>,
+  // Browserslist queries, falls back to the targets of `builtins.presetEnv`.
+  pub targets: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum SwcCssMinimizerRule {
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SwcCssMinimizerRule {
+  pub fn try_match(&self, data: &str) -> rspack_error::Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum SwcCssMinimizerRules {
+  String(String),
+  Regexp(RspackRegex),
+  Array(Vec<SwcCssMinimizerRule>),
+}
+
+impl SwcCssMinimizerRules {
+  pub fn try_match(&self, data: &str) -> rspack_error::Result<bool> {
+    match self {
+      Self::String(s) => Ok(data.starts_with(s)),
+      Self::Regexp(r) => Ok(r.test(data)),
+      Self::Array(l) => try_any_sync(l, |i| i.try_match(data)),
+    }
+  }
+}
+
+pub fn match_object(obj: &SwcCssMinimizerRspackPluginOptions, str: &str) -> Result<bool> {
+  if let Some(condition) = &obj.test {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.include {
+    if !condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  if let Some(condition) = &obj.exclude {
+    if condition.try_match(str)? {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+#[derive(Debug, Default)]
+pub struct SwcCssMinimizerRspackPlugin {
+  options: SwcCssMinimizerRspackPluginOptions,
+}
 
-#[derive(Debug)]
-pub struct SwcCssMinimizerRspackPlugin;
+impl SwcCssMinimizerRspackPlugin {
+  pub fn new(options: SwcCssMinimizerRspackPluginOptions) -> Self {
+    Self { options }
+  }
+}
 
 #[async_trait]
 impl Plugin for SwcCssMinimizerRspackPlugin {
@@ -13,45 +97,141 @@ impl Plugin for SwcCssMinimizerRspackPlugin {
     "rspack.SwcCssMinimizerRspackPlugin"
   }
 
-  // TODO: chunk hash
-
   async fn process_assets_stage_optimize_size(
     &self,
-    _ctx: rspack_core::PluginContext,
-    args: rspack_core::ProcessAssetsArgs<'_>,
-  ) -> rspack_core::PluginProcessAssetsOutput {
+    _ctx: PluginContext,
+    args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
     let compilation = args.compilation;
+    let minify_options = &self.options;
 
+    let devtool = &compilation.options.devtool;
+    let gen_source_map = devtool.source_map();
+    let map_options = MapOptions::new(!devtool.cheap());
     let gen_source_map_config = SwcCssSourceMapGenConfig {
-      enable: compilation.options.devtool.source_map(),
-      inline_sources_content: !compilation.options.devtool.no_sources(),
-      emit_columns: !compilation.options.devtool.cheap(),
+      enable: gen_source_map,
+      inline_sources_content: !devtool.no_sources(),
+      emit_columns: !devtool.cheap(),
+    };
+
+    let targets = minify_options
+      .targets
+      .clone()
+      .or_else(|| {
+        compilation
+          .options
+          .builtins
+          .preset_env
+          .as_ref()
+          .map(|preset_env| preset_env.targets.clone())
+      })
+      .filter(|targets| !targets.is_empty())
+      .map(resolve_targets)
+      .transpose()?;
+
+    let extract_comments_condition = minify_options
+      .extract_comments
+      .as_ref()
+      .map(|condition| {
+        let source = if condition.eq("true") {
+          // copied from css-minimizer-webpack-plugin
+          r"@preserve|@lic|@cc_on|^\**!"
+        } else {
+          // the source of a stringified RegExp, e.g. `/@license/i`
+          condition
+            .strip_prefix('/')
+            .and_then(|c| c.rfind('/').map(|end| &c[..end]))
+            .unwrap_or(condition)
+        };
+        Regex::new(source).map_err(|e| internal_error!("Invalid extractComments {condition}: {e}"))
+      })
+      .transpose()?;
+    let css_minify_options = SwcCssMinifyOptions {
+      extract_comments: extract_comments_condition.as_ref(),
+      targets,
     };
 
+    // collect all extracted comments info
+    let all_extracted_comments = Mutex::new(HashMap::new());
+
     compilation
       .assets_mut()
       .par_iter_mut()
-      .filter(|(filename, _)| filename.ends_with(".css"))
+      .filter(|(filename, original)| filename.ends_with(".css") && !original.get_info().minimized)
       .try_for_each(|(filename, original)| -> Result<()> {
-        if original.get_info().minimized {
+        if !match_object(minify_options, filename)? {
           return Ok(());
         }
-
         if let Some(original_source) = original.get_source() {
           let input = original_source.source().to_string();
-          let input_source_map = original_source.map(&MapOptions::default());
-          let minimized_source = SwcCssCompiler::default().minify(
+          let input_source_map = gen_source_map
+            .then(|| original_source.map(&map_options))
+            .flatten();
+          let output = SwcCssCompiler::default().minify(
             filename,
             input,
             input_source_map,
             gen_source_map_config.clone(),
+            &css_minify_options,
           )?;
-          original.set_source(Some(minimized_source));
+
+          let source = if !output.extracted_comments.is_empty() {
+            let comments_filename = filename.to_string() + ".LICENSE.txt";
+            let dir = Path::new(filename).parent().expect("should has parent");
+            let relative = Path::new(&comments_filename)
+              .strip_prefix(dir)
+              .expect("should has common prefix")
+              .to_string_lossy()
+              .to_string()
+              .replace('\\', "/");
+            let banner = format!("/*! For license information please see {relative} */");
+            all_extracted_comments
+              .lock()
+              .expect("all_extracted_comments lock failed")
+              .insert(comments_filename, output.extracted_comments.join("\n\n"));
+            ConcatSource::new([
+              RawSource::from(banner).boxed(),
+              RawSource::from("\n").boxed(),
+              output.source,
+            ])
+            .boxed()
+          } else {
+            output.source
+          };
+          original.set_source(Some(source));
         }
         original.get_info_mut().minimized = true;
         Ok(())
       })?;
 
+    // write all extracted comments to assets
+    all_extracted_comments
+      .into_inner()
+      .expect("all_extracted_comments lock failed")
+      .into_iter()
+      .for_each(|(comments_filename, comments)| {
+        compilation.emit_asset(
+          comments_filename,
+          CompilationAsset {
+            source: Some(RawSource::from(comments).boxed()),
+            info: AssetInfo {
+              minimized: true,
+              ..Default::default()
+            },
+          },
+        )
+      });
+
+    Ok(())
+  }
+
+  async fn chunk_hash(
+    &self,
+    _ctx: PluginContext,
+    args: &mut ChunkHashArgs<'_>,
+  ) -> PluginChunkHashHookOutput {
+    self.name().hash(args.hasher);
+    self.options.hash(args.hasher);
     Ok(())
   }
 }
@@ -0,0 +1,102 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex, OnceLock},
+};
+
+use rspack_core::{
+  ApplyContext, CompilerOptions, Content, Plugin, PluginContext, PluginReadResourceOutput,
+  ResourceData,
+};
+use rspack_error::Result;
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+use sugar_path::SugarPath;
+
+#[derive(Debug, Default)]
+struct VirtualModules {
+  /// The virtual files of the compiler and its context, set once the plugin is applied.
+  files: OnceLock<(Arc<MemoryFileSystem>, PathBuf)>,
+  /// Modules written before the plugin is applied, the paths may be relative to the context.
+  pending: Mutex<Vec<(PathBuf, String)>>,
+}
+
+/// Modules whose content lives in memory rather than on the disk, they are resolvable like
+/// files on the disk by the new resolver and read by [Plugin::read_resource].
+///
+/// Clones share the modules, so a clone can be kept to update them between rebuilds.
+#[derive(Debug, Default, Clone)]
+pub struct VirtualModulesPlugin {
+  inner: Arc<VirtualModules>,
+}
+
+impl VirtualModulesPlugin {
+  /// Create the plugin with pairs of paths and contents, relative paths are relative to the context.
+  pub fn new<P: Into<PathBuf>, C: Into<String>>(modules: impl IntoIterator<Item = (P, C)>) -> Self {
+    let plugin = Self::default();
+    for (path, content) in modules {
+      plugin.write_module(path, content);
+    }
+    plugin
+  }
+
+  /// Add or update a module. `Compiler::rebuild` picks up the change without reporting the path
+  /// as a changed file, and rebuilds the modules depending on it.
+  pub fn write_module<P: Into<PathBuf>, C: Into<String>>(&self, path: P, content: C) {
+    let (path, content) = (path.into(), content.into());
+    match self.inner.files.get() {
+      Some((files, context)) => write(files, context, &path, content),
+      None => self
+        .inner
+        .pending
+        .lock()
+        .expect("should lock the pending modules")
+        .push((path, content)),
+    }
+  }
+}
+
+fn write(files: &MemoryFileSystem, context: &Path, path: &Path, content: String) {
+  files.insert(context.join(path).normalize(), content);
+}
+
+#[async_trait::async_trait]
+impl Plugin for VirtualModulesPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.VirtualModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let context: &Path = options.context.as_ref();
+    let (files, context) = self
+      .inner
+      .files
+      .get_or_init(|| (ctx.context.virtual_files().clone(), context.to_path_buf()));
+    let pending = std::mem::take(
+      &mut *self
+        .inner
+        .pending
+        .lock()
+        .expect("should lock the pending modules"),
+    );
+    for (path, content) in pending {
+      write(files, context, &path, content);
+    }
+    Ok(())
+  }
+
+  async fn read_resource(&self, resource_data: &ResourceData) -> PluginReadResourceOutput {
+    let Some((files, _)) = self.inner.files.get() else {
+      return Ok(None);
+    };
+    let path = &resource_data.resource_path;
+    if !files.stat(path).is_ok_and(|metadata| metadata.is_file) {
+      return Ok(None);
+    }
+    Ok(Some(Content::from(ReadableFileSystem::read(
+      &**files, path,
+    )?)))
+  }
+}
@@ -0,0 +1,3 @@
+import message from "./virtual/message";
+
+console.log(message);
@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use rspack_plugin_virtual_modules::VirtualModulesPlugin;
+use rspack_testing::MemoryCompiler;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rebuild_virtual_modules() {
+  let virtual_modules =
+    VirtualModulesPlugin::new([("virtual/message.js", "export default 'first build';")]);
+  let mut compiler = MemoryCompiler::from_fixture(
+    &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rebuild"),
+    |options, plugins| {
+      options.experiments.rspack_future.new_resolver = true;
+      options.experiments.incremental_rebuild.make = Some(Default::default());
+      plugins.push(Box::new(virtual_modules.clone()));
+    },
+  );
+  compiler.build().await.expect("should build");
+  assert!(compiler.asset("main.js").contains("first build"));
+
+  virtual_modules.write_module("virtual/message.js", "export default 'rebuild';");
+  compiler.rebuild().await.expect("should rebuild");
+  let main_js = compiler.asset("main.js");
+  assert!(main_js.contains("rebuild"));
+  assert!(!main_js.contains("first build"));
+}
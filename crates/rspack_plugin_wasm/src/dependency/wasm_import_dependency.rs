@@ -11,7 +11,8 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: JsWord,
   request: String,
-  // only_direct_import: bool,
+  /// the signature contains an i64, which can't be passed between JS and WASM
+  pub only_direct_import: bool,
   /// the WASM AST node
   pub desc: WasmNode,
 
@@ -19,13 +20,13 @@ pub struct WasmImportDependency {
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(request: String, name: String, desc: WasmNode, only_direct_import: bool) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      only_direct_import,
       span: None,
     }
   }
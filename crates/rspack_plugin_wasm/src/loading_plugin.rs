@@ -3,13 +3,24 @@ use rspack_core::{
   RuntimeGlobals, RuntimeModuleExt, RuntimeRequirementsInTreeArgs, WasmLoadingType,
 };
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(
+  wasm_loading_type: WasmLoadingType,
+  plugins: &mut Vec<BoxPlugin>,
+) {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin.boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => {
+      plugins.push(FetchCompileWasmPlugin.boxed());
+      plugins.push(FetchCompileAsyncWasmPlugin.boxed());
+    }
+    WasmLoadingType::AsyncNode => {
+      plugins.push(ReadFileCompileWasmPlugin.boxed());
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(false).boxed());
+    }
+    WasmLoadingType::AsyncNodeModule => {
+      plugins.push(ReadFileCompileAsyncWasmPlugin::new(true).boxed());
+    }
   }
 }
 
@@ -92,3 +103,74 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[derive(Debug)]
+pub struct FetchCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    let runtime_requirements = args.runtime_requirements;
+    let runtime_requirements_mut = &mut args.runtime_requirements_mut;
+
+    if runtime_requirements.contains(RuntimeGlobals::WASM_INSTANCES) {
+      runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+      runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+      runtime_requirements_mut.insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+          true,
+          *args.chunk,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin;
+
+#[async_trait::async_trait]
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn runtime_requirements_in_tree(
+    &self,
+    _ctx: PluginContext,
+    args: &mut RuntimeRequirementsInTreeArgs,
+  ) -> PluginRuntimeRequirementsInTreeOutput {
+    let runtime_requirements = args.runtime_requirements;
+    let runtime_requirements_mut = &mut args.runtime_requirements_mut;
+
+    if runtime_requirements.contains(RuntimeGlobals::WASM_INSTANCES) {
+      runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+      runtime_requirements_mut.insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
+      args.compilation.add_runtime_module(
+        args.chunk,
+        WasmChunkLoadingRuntimeModule::new(
+          include_str!("runtime/read_file_compile_async_wasm.js").to_string(),
+          false,
+          *args.chunk,
+        )
+        .boxed(),
+      );
+    }
+
+    Ok(())
+  }
+}
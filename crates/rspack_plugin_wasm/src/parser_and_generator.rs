@@ -62,6 +62,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
                     module.into(),
                     name.into(),
                     ty,
+                    false,
                   )));
                 }
                 Err(err) => diagnostic.push(Diagnostic::error(
@@ -280,7 +281,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &Filename,
@@ -295,12 +296,12 @@ fn render_wasm_name(
   )
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,9 +1,14 @@
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey,
+  CodeGenerationDataFilename, Compilation, Dependency, ModuleDependency, ModuleType, PathData,
+  RuntimeGlobals, RuntimeModule, RuntimeModuleStage, RuntimeSpec, UsedName,
 };
 use rspack_identifier::Identifier;
+use wasmparser::TypeRef;
+
+use crate::dependency::WasmImportDependency;
 
 #[derive(Debug, Eq)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -104,3 +109,303 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     "#
   )
 }
+
+#[derive(Debug, Eq)]
+pub struct WasmChunkLoadingRuntimeModule {
+  generate_load_binary_code: String,
+  id: Identifier,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self {
+      generate_load_binary_code,
+      id: Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      supports_streaming,
+      chunk,
+    }
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> BoxSource {
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(&self.chunk)
+      .expect("chunk not found");
+
+    let mut declarations = vec![];
+    let mut import_objects = IndexMap::<&str, String>::new();
+    let mut wasm_module_map = IndexMap::<&str, Vec<&str>>::new();
+    let mut wasm_module_filenames = IndexMap::<&str, &str>::new();
+    for chunk_ukey in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      let async_chunk = compilation
+        .chunk_by_ukey
+        .get(&chunk_ukey)
+        .expect("chunk not found");
+      let mut modules = chunk_graph
+        .get_chunk_modules(&chunk_ukey, module_graph)
+        .into_iter()
+        .filter(|m| *m.module_type() == ModuleType::WasmSync)
+        .map(|m| (module_id(compilation, m), m))
+        .collect::<Vec<_>>();
+      if modules.is_empty() {
+        continue;
+      }
+      modules.sort_unstable_by_key(|(id, _)| *id);
+
+      for (id, module) in &modules {
+        if import_objects.contains_key(id) {
+          continue;
+        }
+        import_objects.insert(
+          *id,
+          generate_import_object(compilation, module, &async_chunk.runtime, &mut declarations),
+        );
+        if let Some(filename) = compilation
+          .code_generation_results
+          .get(&module.identifier(), Some(&async_chunk.runtime))
+          .data
+          .get::<CodeGenerationDataFilename>()
+        {
+          wasm_module_filenames.insert(*id, filename.inner());
+        }
+      }
+      wasm_module_map.insert(
+        async_chunk.expect_id(),
+        modules.into_iter().map(|(id, _)| id).collect(),
+      );
+    }
+
+    let import_objects = import_objects
+      .into_iter()
+      .map(|(id, import_object)| {
+        format!(
+          "{}: {import_object}",
+          serde_json::to_string(id).expect("should be ok")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+    let req = self
+      .generate_load_binary_code
+      .replace("$PATH", "wasmModuleFilenames[wasmModuleId]");
+
+    RawSource::from(get_wasm_chunk_loading(
+      &declarations.join("\n"),
+      &import_objects,
+      &serde_json::to_string(&wasm_module_map).expect("should be ok"),
+      &serde_json::to_string(&wasm_module_filenames).expect("should be ok"),
+      &req,
+      self.supports_streaming,
+    ))
+    .boxed()
+  }
+}
+
+impl_runtime_module!(WasmChunkLoadingRuntimeModule);
+
+fn module_id<'a>(compilation: &'a Compilation, module: &BoxModule) -> &'a str {
+  compilation
+    .chunk_graph
+    .get_module_id(module.identifier())
+    .as_deref()
+    .expect("should have module id")
+}
+
+/// Creates the function which returns the imports object of a wasm module, or a promise of it
+/// when the module imports from other wasm modules, which have to be instantiated first.
+fn generate_import_object(
+  compilation: &Compilation,
+  module: &BoxModule,
+  runtime: &RuntimeSpec,
+  declarations: &mut Vec<String>,
+) -> String {
+  let module_graph = &compilation.module_graph;
+
+  let mut wait_for_instances = IndexMap::<String, &str>::new();
+  let mut properties = IndexMap::<&str, Vec<String>>::new();
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.downcast_ref::<WasmImportDependency>())
+  {
+    let Some(imported_module) = module_graph.get_module(dep.id()) else {
+      continue;
+    };
+    let Some(UsedName::Str(used_name)) = module_graph
+      .get_exports_info(&imported_module.identifier())
+      .id
+      .get_used_name(
+        module_graph,
+        Some(runtime),
+        UsedName::Str(dep.name().into()),
+      )
+    else {
+      continue;
+    };
+    let used_name = serde_json::to_string(&used_name).expect("should convert to json string");
+    let imported_module_id = module_id(compilation, imported_module);
+
+    let value = if *imported_module.module_type() == ModuleType::WasmSync {
+      let instance_var = format!("m{}", wait_for_instances.len());
+      let value = format!("{instance_var}[{used_name}]");
+      wait_for_instances.insert(instance_var, imported_module_id);
+      value
+    } else if matches!(dep.desc, TypeRef::Func(_)) {
+      // the imported js module is executed after the instantiation, so read its exports lazily
+      let cache = format!("wasmImportedFuncCache{}", declarations.len());
+      declarations.push(format!("var {cache};"));
+      format!(
+        r#"function() {{
+  if({cache} === undefined) {cache} = {}[{}].exports;
+  return {cache}[{used_name}].apply({cache}, arguments);
+}}"#,
+        RuntimeGlobals::MODULE_CACHE,
+        serde_json::to_string(imported_module_id).expect("should be ok")
+      )
+    } else {
+      // reported as an error in `SyncWasmPlugin::finish_modules`
+      continue;
+    };
+    properties.entry(dep.request()).or_default().push(format!(
+      "{}: {value}",
+      serde_json::to_string(dep.name()).expect("should be ok")
+    ));
+  }
+
+  let import_object = properties
+    .into_iter()
+    .map(|(request, properties)| {
+      format!(
+        "{}: {{\n{}\n}}",
+        serde_json::to_string(request).expect("should be ok"),
+        properties.join(",\n")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(",\n");
+
+  if wait_for_instances.is_empty() {
+    format!("function() {{\nreturn {{\n{import_object}\n}};\n}}")
+  } else {
+    let promises = wait_for_instances
+      .values()
+      .map(|id| {
+        format!(
+          "installedWasmModules[{}]",
+          serde_json::to_string(id).expect("should be ok")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(", ");
+    let variables = wait_for_instances
+      .keys()
+      .enumerate()
+      .map(|(i, name)| format!("{name} = array[{i}]"))
+      .collect::<Vec<_>>()
+      .join(", ");
+    format!(
+      r#"function() {{
+return promiseResolve().then(function() {{ return Promise.all([{promises}]); }}).then(function(array) {{
+var {variables};
+return {{
+{import_object}
+}};
+}});
+}}"#
+    )
+  }
+}
+
+fn get_wasm_chunk_loading(
+  declarations: &str,
+  import_objects: &str,
+  wasm_module_map: &str,
+  wasm_module_filenames: &str,
+  req: &str,
+  supports_streaming: bool,
+) -> String {
+  let (streaming_with_import_object_promise, streaming) = if supports_streaming {
+    (
+      r#"if(typeof WebAssembly.compileStreaming === 'function') {
+          promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+            return WebAssembly.instantiate(items[0], items[1]);
+          });
+        } else "#,
+      r#"if(typeof WebAssembly.instantiateStreaming === 'function') {
+          promise = WebAssembly.instantiateStreaming(req, importObject);
+        } else "#,
+    )
+  } else {
+    ("", "")
+  };
+  format!(
+    r#"
+    // object to store loaded and loading wasm modules
+    var installedWasmModules = {{}};
+
+    function promiseResolve() {{ return Promise.resolve(); }}
+
+    {declarations}
+    var wasmImportObjects = {{
+    {import_objects}
+    }};
+
+    var wasmModuleMap = {wasm_module_map};
+    var wasmModuleFilenames = {wasm_module_filenames};
+
+    {wasm_instances} = {{}};
+    {ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+      var wasmModules = wasmModuleMap[chunkId] || [];
+      wasmModules.forEach(function(wasmModuleId) {{
+        var installedWasmModuleData = installedWasmModules[wasmModuleId];
+        // a Promise means "currently loading" or "already loaded".
+        if(installedWasmModuleData) {{
+          promises.push(installedWasmModuleData);
+          return;
+        }}
+        var importObject = wasmImportObjects[wasmModuleId]();
+        var req = {req};
+        var promise;
+        if(importObject && typeof importObject.then === 'function') {{
+          {streaming_with_import_object_promise}{{
+            var bytesPromise = req.then(function(x) {{ return x.arrayBuffer(); }});
+            promise = Promise.all([
+              bytesPromise.then(function(bytes) {{ return WebAssembly.compile(bytes); }}),
+              importObject
+            ]).then(function(items) {{
+              return WebAssembly.instantiate(items[0], items[1]);
+            }});
+          }}
+        }} else {{
+          {streaming}{{
+            var bytesPromise = req.then(function(x) {{ return x.arrayBuffer(); }});
+            promise = bytesPromise.then(function(bytes) {{
+              return WebAssembly.instantiate(bytes, importObject);
+            }});
+          }}
+        }}
+        promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+          return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+        }}));
+      }});
+    }};
+    "#,
+    wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+    ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+  )
+}
@@ -0,0 +1,241 @@
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, CodeGenerationDataFilename, GenerateContext, Module,
+  ModuleIdentifier, ParseContext, ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType,
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rustc_hash::FxHashMap as HashMap;
+use swc_core::atoms::Atom;
+use wasmparser::{ExternalKind, FuncType, Import, Parser, Payload, Type, TypeRef, ValType};
+
+use crate::dependency::{StaticExportsDependency, WasmImportDependency};
+use crate::{
+  hash_for_source, render_import_stmt, render_wasm_name, ModuleIdToFileName, WASM_SOURCE_TYPE,
+};
+
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+}
+
+fn has_i64(ty: &FuncType) -> bool {
+  ty.params()
+    .iter()
+    .chain(ty.results())
+    .any(|ty| *ty == ValType::I64)
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut exports = Vec::with_capacity(1);
+    let mut js_incompatible_exports = HashMap::default();
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    // signatures declared in the type section
+    let mut types: Vec<FuncType> = vec![];
+    // signature index of each function, imported functions come first in the function index space
+    let mut functions: Vec<u32> = vec![];
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => match payload {
+          Payload::TypeSection(s) => {
+            for ty in s {
+              match ty {
+                Ok(Type::Func(ty)) => types.push(ty),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Type Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ImportSection(s) => {
+            for import in s {
+              match import {
+                Ok(Import { module, name, ty }) => {
+                  let only_direct_import = if let TypeRef::Func(index) = ty {
+                    functions.push(index);
+                    types.get(index as usize).is_some_and(has_i64)
+                  } else {
+                    false
+                  };
+                  dependencies.push(Box::new(WasmImportDependency::new(
+                    module.into(),
+                    name.into(),
+                    ty,
+                    only_direct_import,
+                  )));
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Import Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::FunctionSection(s) => {
+            for index in s {
+              match index {
+                Ok(index) => functions.push(index),
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Function Parse Error".into(),
+                  err.to_string(),
+                )),
+              }
+            }
+          }
+          Payload::ExportSection(s) => {
+            for export in s {
+              match export {
+                Ok(export) => {
+                  if export.kind == ExternalKind::Func
+                    && functions
+                      .get(export.index as usize)
+                      .and_then(|index| types.get(*index as usize))
+                      .is_some_and(has_i64)
+                  {
+                    js_incompatible_exports.insert(export.name.to_string(), "i64".to_string());
+                  }
+                  exports.push(export.name.to_string());
+                }
+                Err(err) => diagnostic.push(Diagnostic::error(
+                  "Wasm Export Parse Error".into(),
+                  err.to_string(),
+                )),
+              };
+            }
+          }
+          _ => {}
+        },
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    if !js_incompatible_exports.is_empty() {
+      parse_context.build_info.js_incompatible_exports = Some(js_incompatible_exports);
+    }
+
+    dependencies.push(Box::new(StaticExportsDependency::new(
+      exports.into_iter().map(Atom::from).collect::<Vec<_>>(),
+      false,
+    )));
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        source,
+        analyze_result: Default::default(),
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: &SourceType) -> f64 {
+    match source_type {
+      SourceType::JavaScript => {
+        40.0
+          + module
+            .get_presentational_dependencies()
+            .map_or(0.0, |i| i.len() as f64 * 10.0)
+      }
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  #[allow(clippy::unwrap_in_result)]
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    // the runtime loads the wasm file by this filename
+    generate_context
+      .data
+      .insert(CodeGenerationDataFilename::new(
+        wasm_path_with_info.0.clone(),
+      ));
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+
+        let module_graph = &compilation.module_graph;
+        let chunk_graph = &compilation.chunk_graph;
+
+        // the instance is created while loading the chunk, the imported modules are only
+        // executed here to keep the evaluation order of esm
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        module
+          .get_dependencies()
+          .iter()
+          .filter_map(|id| module_graph.dependency_by_id(id))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+          .filter_map(|dep| module_graph.module_graph_module_by_dependency_id(dep.id()))
+          .for_each(|mgm| {
+            if !dep_modules.contains_key(&mgm.module_identifier) {
+              let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+              dep_modules.insert(mgm.module_identifier, (import_var, mgm.id(chunk_graph)));
+            }
+          });
+
+        let imports_code = dep_modules
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
+          .collect::<Vec<_>>()
+          .join("");
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+// export exports from WebAssembly module
+module.exports = wasmExports;
+// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+}
@@ -0,0 +1,243 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationArgs, CompilationParams, CompilerOptions,
+  Dependency, DependencyType, ExtendedReferencedExport, ModuleDependency, ModuleType,
+  OptimizeChunksArgs, ParserAndGenerator, Plugin, PluginCompilationHookOutput, PluginContext,
+  PluginRenderManifestHookOutput, RenderManifestArgs,
+};
+use rspack_error::{Diagnostic, Result};
+use wasmparser::TypeRef;
+
+use crate::dependency::WasmImportDependency;
+use crate::wasm_plugin::render_wasm_manifest;
+use crate::{ModuleIdToFileName, SyncWasmParserAndGenerator};
+
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+impl SyncWasmPlugin {
+  pub fn new() -> SyncWasmPlugin {
+    Self {
+      module_id_to_filename_without_ext: Default::default(),
+    }
+  }
+}
+
+fn readable_identifier(compilation: &Compilation, module: &BoxModule) -> String {
+  module
+    .readable_identifier(&compilation.options.context)
+    .to_string()
+}
+
+/// Reports the imports and exports of synchronous WebAssembly modules which can't be
+/// provided by or passed to JS.
+fn unsupported_features(compilation: &Compilation) -> Vec<Diagnostic> {
+  let module_graph = &compilation.module_graph;
+  let mut modules = module_graph
+    .modules()
+    .values()
+    .filter(|m| *m.module_type() == ModuleType::WasmSync)
+    .collect::<Vec<_>>();
+  modules.sort_by_key(|m| m.identifier());
+
+  let mut diagnostics = vec![];
+  for module in modules {
+    for dep in module
+      .get_dependencies()
+      .iter()
+      .filter_map(|id| module_graph.dependency_by_id(id))
+      .filter_map(|dep| dep.downcast_ref::<WasmImportDependency>())
+    {
+      let Some(imported_module) = module_graph.get_module(dep.id()) else {
+        continue;
+      };
+      if *imported_module.module_type() == ModuleType::WasmSync {
+        continue;
+      }
+      let request = dep.request();
+      let name = dep.name();
+      if dep.only_direct_import {
+        diagnostics.push(Diagnostic::error(
+          "Unsupported WebAssembly Feature".into(),
+          format!(
+            "Import \"{name}\" from \"{request}\" with i64 can only be used for direct wasm to wasm dependencies\nIt's used from {}",
+            readable_identifier(compilation, module)
+          ),
+        ));
+      } else if !matches!(dep.desc, TypeRef::Func(_)) {
+        diagnostics.push(Diagnostic::error(
+          "Unsupported WebAssembly Feature".into(),
+          format!(
+            "Import \"{name}\" from \"{request}\" is not a function, only functions can be imported from non-wasm modules\nIt's used from {}",
+            readable_identifier(compilation, module)
+          ),
+        ));
+      }
+    }
+
+    let Some(js_incompatible_exports) = module_graph
+      .module_graph_module_by_identifier(&module.identifier())
+      .and_then(|mgm| mgm.build_info.as_ref())
+      .and_then(|build_info| build_info.js_incompatible_exports.as_ref())
+    else {
+      continue;
+    };
+    let mut connections = module_graph
+      .get_incoming_connections(module)
+      .into_iter()
+      .collect::<Vec<_>>();
+    connections.sort_by_key(|c| (c.original_module_identifier, c.dependency_id));
+    for connection in connections {
+      let Some(origin_module) = connection
+        .original_module_identifier
+        .and_then(|id| module_graph.module_by_identifier(&id))
+      else {
+        continue;
+      };
+      if *origin_module.module_type() == ModuleType::WasmSync {
+        continue;
+      }
+      let Some(dep) = module_graph
+        .dependency_by_id(&connection.dependency_id)
+        .and_then(|dep| dep.as_module_dependency())
+      else {
+        continue;
+      };
+      for referenced_export in dep.get_referenced_exports(module_graph, None) {
+        let name = match referenced_export {
+          ExtendedReferencedExport::Array(names) => names.into_iter().next(),
+          ExtendedReferencedExport::Export(export) => export.name.into_iter().next(),
+        };
+        let Some(name) = name else {
+          continue;
+        };
+        if let Some(reason) = js_incompatible_exports.get(name.as_ref()) {
+          diagnostics.push(Diagnostic::error(
+            "Unsupported WebAssembly Feature".into(),
+            format!(
+              "Export \"{name}\" with {reason} can only be used for direct wasm to wasm dependencies\nIt's used from {}",
+              readable_identifier(compilation, origin_module)
+            ),
+          ));
+        }
+      }
+    }
+  }
+  diagnostics
+}
+
+/// Reports synchronous WebAssembly modules in initial chunks, they have to be instantiated
+/// while loading the chunk, which is only possible for async chunks.
+fn wasm_in_initial_chunk(compilation: &Compilation) -> Vec<Diagnostic> {
+  let module_graph = &compilation.module_graph;
+  let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+  chunks.sort_by_key(|c| c.ukey);
+
+  let mut reported = vec![];
+  let mut diagnostics = vec![];
+  for chunk in chunks {
+    if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+      continue;
+    }
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(&chunk.ukey, module_graph)
+    {
+      if *module.module_type() != ModuleType::WasmSync || reported.contains(&module.identifier()) {
+        continue;
+      }
+      reported.push(module.identifier());
+
+      let mut chain = vec![readable_identifier(compilation, module)];
+      let mut current = module;
+      while let Some(issuer) = module_graph.get_issuer(current) {
+        chain.push(readable_identifier(compilation, issuer));
+        current = issuer;
+      }
+      diagnostics.push(Diagnostic::error(
+        "WebAssembly In Initial Chunk Error".into(),
+        format!(
+          "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module:\n{}",
+          chain
+            .into_iter()
+            .map(|m| format!("* {m}"))
+            .collect::<Vec<_>>()
+            .join(" --> ")
+        ),
+      ));
+    }
+  }
+  diagnostics
+}
+
+#[async_trait::async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  async fn compilation(
+    &self,
+    args: CompilationArgs<'_>,
+    params: &CompilationParams,
+  ) -> PluginCompilationHookOutput {
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmImport,
+      params.normal_module_factory.clone(),
+    );
+    args.compilation.set_dependency_factory(
+      DependencyType::WasmExportImported,
+      params.normal_module_factory.clone(),
+    );
+    Ok(())
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    let builder = move || {
+      Box::new({
+        SyncWasmParserAndGenerator {
+          module_id_to_filename: module_id_to_filename_without_ext.clone(),
+        }
+      }) as Box<dyn ParserAndGenerator>
+    };
+
+    ctx
+      .context
+      .register_parser_and_generator_builder(ModuleType::WasmSync, Box::new(builder));
+
+    Ok(())
+  }
+
+  async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+    let diagnostics = unsupported_features(compilation);
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn optimize_chunk_modules(&self, args: OptimizeChunksArgs<'_>) -> Result<()> {
+    let diagnostics = wasm_in_initial_chunk(args.compilation);
+    args.compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    render_wasm_manifest(
+      &args,
+      &ModuleType::WasmSync,
+      &self.module_id_to_filename_without_ext,
+    )
+  }
+}
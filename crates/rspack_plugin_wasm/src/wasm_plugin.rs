@@ -78,38 +78,50 @@ impl Plugin for AsyncWasmPlugin {
     _ctx: PluginContext,
     args: RenderManifestArgs<'_>,
   ) -> PluginRenderManifestHookOutput {
-    let compilation = args.compilation;
-    let chunk = args.chunk();
-    let module_graph = &compilation.module_graph;
-
-    let ordered_modules = compilation
-      .chunk_graph
-      .get_chunk_modules(&args.chunk_ukey, module_graph);
-
-    let files = ordered_modules
-      .par_iter()
-      .filter(|m| *m.module_type() == ModuleType::WasmAsync)
-      .map(|m| {
-        let code_gen_result = compilation
-          .code_generation_results
-          .get(&m.identifier(), Some(&chunk.runtime));
-
-        let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-          let (output_path, asset_info) = self
-            .module_id_to_filename_without_ext
-            .get(&m.identifier())
-            .map(|s| s.clone())
-            .expect("should have wasm_filename");
-          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
-        });
-
-        Ok(result)
-      })
-      .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
-      .into_iter()
-      .flatten()
-      .collect::<Vec<RenderManifestEntry>>();
-
-    Ok(files)
+    render_wasm_manifest(
+      &args,
+      &ModuleType::WasmAsync,
+      &self.module_id_to_filename_without_ext,
+    )
   }
 }
+
+/// Emits the wasm files of the modules with `module_type` in the chunk.
+pub(crate) fn render_wasm_manifest(
+  args: &RenderManifestArgs<'_>,
+  module_type: &ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> PluginRenderManifestHookOutput {
+  let compilation = args.compilation;
+  let chunk = args.chunk();
+  let module_graph = &compilation.module_graph;
+
+  let ordered_modules = compilation
+    .chunk_graph
+    .get_chunk_modules(&args.chunk_ukey, module_graph);
+
+  let files = ordered_modules
+    .par_iter()
+    .filter(|m| m.module_type() == module_type)
+    .map(|m| {
+      let code_gen_result = compilation
+        .code_generation_results
+        .get(&m.identifier(), Some(&chunk.runtime));
+
+      let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
+        let (output_path, asset_info) = module_id_to_filename
+          .get(&m.identifier())
+          .map(|s| s.clone())
+          .expect("should have wasm_filename");
+        RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+      });
+
+      Ok(result)
+    })
+    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<RenderManifestEntry>>();
+
+  Ok(files)
+}
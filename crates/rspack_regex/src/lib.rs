@@ -22,6 +22,14 @@ impl Debug for RspackRegex {
   }
 }
 
+impl PartialEq for RspackRegex {
+  fn eq(&self, other: &Self) -> bool {
+    self.raw == other.raw
+  }
+}
+
+impl Eq for RspackRegex {}
+
 impl RspackRegex {
   pub fn test(&self, text: &str) -> bool {
     self.algo.test(text)
@@ -2,7 +2,7 @@ mod react;
 pub use react::{fold_react_refresh, react, RawReactOptions, ReactOptions};
 
 mod define;
-pub use define::{define, Define, RawDefine};
+pub use define::{Define, RawDefine};
 
 mod provide;
 pub use provide::{provide, Provide, RawProvide};
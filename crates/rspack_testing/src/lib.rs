@@ -1,8 +1,10 @@
 mod eval_raw;
 mod loader;
+mod memory_compiler;
 mod run_fixture;
 mod test_config;
 pub use eval_raw::{evaluate_js, evaluate_to_json};
+pub use memory_compiler::MemoryCompiler;
 pub use run_fixture::{
   apply_from_fixture, test_fixture, test_fixture_css, test_fixture_css_modules, test_fixture_html,
   test_fixture_insta, test_fixture_js, test_rebuild_fixture,
@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use rspack_core::{BoxPlugin, Compiler, CompilerOptions};
+use rspack_error::Result;
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+
+use crate::{apply_from_fixture, TestConfig};
+
+/// Compiles a fixture with the assets emitted to memory, for the cases asserting the output of
+/// several builds of the same compiler instead of snapshotting a single build.
+pub struct MemoryCompiler {
+  pub compiler: Compiler<MemoryFileSystem>,
+}
+
+impl MemoryCompiler {
+  /// Apply the config of the fixture like [apply_from_fixture], `configure` adjusts the options
+  /// and the plugins for the case.
+  pub fn from_fixture(
+    fixture_path: &Path,
+    configure: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+  ) -> Self {
+    let (options, plugins) = apply_from_fixture(fixture_path);
+    Self::new(options, plugins, configure)
+  }
+
+  /// Same as [MemoryCompiler::from_fixture], with `config` used as the [TestConfig] of the fixture.
+  pub fn from_config(
+    fixture_path: &Path,
+    config: serde_json::Value,
+    configure: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+  ) -> Self {
+    let config: TestConfig = serde_json::from_value(config).expect("should be valid test config");
+    let (options, plugins) = config.apply(fixture_path.to_path_buf());
+    Self::new(options, plugins, configure)
+  }
+
+  fn new(
+    mut options: CompilerOptions,
+    mut plugins: Vec<BoxPlugin>,
+    configure: impl FnOnce(&mut CompilerOptions, &mut Vec<BoxPlugin>),
+  ) -> Self {
+    configure(&mut options, &mut plugins);
+    Self {
+      compiler: Compiler::new(options, plugins, MemoryFileSystem::new()),
+    }
+  }
+
+  pub async fn build(&mut self) -> Result<()> {
+    self.compiler.build().await
+  }
+
+  /// Rebuild without any changed file, for the changes made through the plugins.
+  pub async fn rebuild(&mut self) -> Result<()> {
+    self
+      .compiler
+      .rebuild(Default::default(), Default::default())
+      .await
+  }
+
+  /// The source of an asset of the last build, panics if it's not emitted.
+  pub fn asset(&self, filename: &str) -> String {
+    self
+      .compiler
+      .compilation
+      .assets()
+      .get(filename)
+      .and_then(|asset| asset.get_source())
+      .map(|source| source.source().to_string())
+      .unwrap_or_else(|| panic!("should emit {filename}"))
+  }
+
+  /// A file written to the output file system, e.g. the records, panics if it's not written.
+  pub fn output_file(&self, path: &Path) -> Vec<u8> {
+    ReadableFileSystem::read(&self.compiler.output_filesystem, path)
+      .unwrap_or_else(|_| panic!("should write {}", path.display()))
+  }
+
+  /// Panics with the diagnostics if the last build has errors.
+  pub fn assert_no_errors(&self) {
+    let stats = self.compiler.compilation.get_stats();
+    if !stats.get_errors().is_empty() {
+      panic!(
+        "Failed to compile, errors: {}",
+        stats
+          .emit_diagnostics_string(true)
+          .expect("failed to emit diagnostics to string")
+      );
+    }
+  }
+}
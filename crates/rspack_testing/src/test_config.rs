@@ -107,6 +107,8 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub sync_web_assembly: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -196,6 +198,10 @@ pub struct Builtins {
 pub struct Css {
   #[serde(default)]
   pub modules: ModulesConfig,
+  #[serde(default)]
+  pub ignore_order: bool,
+  #[serde(default)]
+  pub targets: Vec<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -406,6 +412,11 @@ impl TestConfig {
         worker_wasm_loading: c::WasmLoading::Enable(c::WasmLoadingType::from("fetch")),
         worker_public_path: String::new(),
         script_type: String::from("false"),
+        environment: c::Environment {
+          dynamic_import: true,
+          module: true,
+          ..Default::default()
+        },
       },
       mode: c::Mode::from(self.mode),
       target: c::Target::new(&self.target).expect("Can't construct target"),
@@ -497,6 +508,8 @@ impl TestConfig {
           ),
           exports_only: self.builtins.css.modules.exports_only,
         },
+        ignore_order: self.builtins.css.ignore_order,
+        targets: self.builtins.css.targets,
       })
       .boxed(),
     );
@@ -529,20 +542,34 @@ impl TestConfig {
           columns: !options.devtool.cheap(),
           no_sources: options.devtool.no_sources(),
           public_path: None,
+          filename: None,
+          ignore_list: None,
+          debug_ids: false,
+          module_filename_template: None,
+          fallback_module_filename_template: None,
+          source_root: None,
+          file_context: None,
+          test: None,
+          include: None,
+          exclude: None,
         })
         .boxed(),
       );
     }
 
-    if self.optimization.module_ids == "named" {
-      plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed());
+    match self.optimization.module_ids.as_str() {
+      "natural" => plugins.push(rspack_ids::NaturalModuleIdsPlugin.boxed()),
+      "named" => plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceModuleIdsPlugin::new(true).boxed()),
+      "hashed" => plugins.push(rspack_ids::HashedModuleIdsPlugin::default().boxed()),
+      _ => plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed()),
     }
-    if self.optimization.chunk_ids == "named" {
-      plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed());
+    match self.optimization.chunk_ids.as_str() {
+      "natural" => plugins.push(rspack_ids::NaturalChunkIdsPlugin.boxed()),
+      "named" => plugins.push(rspack_ids::NamedChunkIdsPlugin::new(None, None).boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(true).boxed()),
+      "hashed" => plugins.push(rspack_ids::HashedChunkIdsPlugin::default().boxed()),
+      _ => plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed()),
     }
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
@@ -554,6 +581,10 @@ impl TestConfig {
       plugins.push(rspack_plugin_wasm::FetchCompileAsyncWasmPlugin {}.boxed());
       plugins.push(rspack_plugin_wasm::AsyncWasmPlugin::new().boxed());
     }
+    if self.experiments.sync_web_assembly {
+      plugins.push(rspack_plugin_wasm::FetchCompileWasmPlugin {}.boxed());
+      plugins.push(rspack_plugin_wasm::SyncWasmPlugin::new().boxed());
+    }
     plugins.push(rspack_plugin_externals::http_externals_rspack_plugin(
       true, false,
     ));
@@ -1,15 +1,136 @@
-import { BuiltinPluginName, create } from "./base";
-
-export type DefinePluginOptions = Record<string, string | boolean | undefined>;
-export const DefinePlugin = create(
-	BuiltinPluginName.DefinePlugin,
-	(define: DefinePluginOptions): Record<string, string> => {
-		const entries = Object.entries(define).map(([key, value]) => {
-			if (typeof value !== "string") {
-				value = value === undefined ? "undefined" : JSON.stringify(value);
+import { BuiltinPlugin, RawDefinePluginOptions } from "@rspack/binding";
+import {
+	BuiltinPluginName,
+	RspackBuiltinPlugin,
+	createBuiltinPlugin
+} from "./base";
+
+type CodeValuePrimitive =
+	| null
+	| undefined
+	| RegExp
+	| Function
+	| string
+	| number
+	| boolean
+	| bigint
+	| RuntimeValue;
+export type CodeValue =
+	| CodeValuePrimitive
+	| CodeValue[]
+	| { [key: string]: CodeValue };
+export type DefinePluginOptions = Record<string, CodeValue>;
+
+/**
+ * A value evaluated again at the start of every compilation, the modules using it
+ * are rebuilt once it's changed, e.g. in watch mode.
+ */
+export class RuntimeValue {
+	constructor(private fn: () => CodeValue) {}
+
+	exec(): CodeValue {
+		return this.fn();
+	}
+}
+
+export class DefinePlugin extends RspackBuiltinPlugin {
+	name = BuiltinPluginName.DefinePlugin;
+
+	static runtimeValue(fn: () => CodeValue): RuntimeValue {
+		return new RuntimeValue(fn);
+	}
+
+	constructor(private define: DefinePluginOptions) {
+		super();
+	}
+
+	raw(): BuiltinPlugin {
+		const definitions: Record<string, string> = {};
+		const runtimeDefinitions: Record<string, CodeValue> = {};
+		// the nested objects are also defined key by key, e.g.
+		// `{ "process.env": { NODE_ENV: "'production'" } }` defines both `process.env`
+		// and `process.env.NODE_ENV`
+		const walkDefinitions = (
+			define: Record<string, CodeValue>,
+			prefix: string
+		) => {
+			for (const [key, value] of Object.entries(define)) {
+				if (isPlainObject(value)) {
+					walkDefinitions(value, `${prefix}${key}.`);
+				}
+				if (hasRuntimeValue(value)) {
+					runtimeDefinitions[`${prefix}${key}`] = value;
+				} else {
+					definitions[`${prefix}${key}`] = toCode(value);
+				}
 			}
-			return [key, value];
-		});
-		return Object.fromEntries(entries);
+		};
+		walkDefinitions(this.define, "");
+		const rawOptions: RawDefinePluginOptions = { definitions };
+		if (Object.keys(runtimeDefinitions).length > 0) {
+			rawOptions.runtimeValues = () =>
+				Object.fromEntries(
+					Object.entries(runtimeDefinitions).map(([key, value]) => [
+						key,
+						toCode(value)
+					])
+				);
+		}
+		return createBuiltinPlugin(this.name, rawOptions);
+	}
+}
+
+const hasRuntimeValue = (value: CodeValue): boolean => {
+	if (value instanceof RuntimeValue) {
+		return true;
+	}
+	if (Array.isArray(value)) {
+		return value.some(hasRuntimeValue);
+	}
+	if (isPlainObject(value)) {
+		return Object.values(value).some(hasRuntimeValue);
+	}
+	return false;
+};
+
+const isPlainObject = (
+	value: CodeValue
+): value is { [key: string]: CodeValue } =>
+	typeof value === "object" &&
+	value !== null &&
+	!Array.isArray(value) &&
+	!(value instanceof RegExp) &&
+	!(value instanceof RuntimeValue);
+
+const toCode = (code: CodeValue): string => {
+	if (code === null) {
+		return "null";
+	}
+	if (code === undefined) {
+		return "undefined";
+	}
+	if (Object.is(code, -0)) {
+		return "-0";
+	}
+	if (code instanceof RuntimeValue) {
+		return toCode(code.exec());
+	}
+	if (code instanceof RegExp) {
+		return code.toString();
+	}
+	if (typeof code === "function") {
+		return `(${code.toString()})`;
+	}
+	if (typeof code === "bigint") {
+		return `${code}n`;
+	}
+	if (Array.isArray(code)) {
+		return `[${code.map(toCode).join(",")}]`;
+	}
+	if (typeof code === "object") {
+		return `{${Object.entries(code)
+			.map(([key, value]) => `${JSON.stringify(key)}:${toCode(value)}`)
+			.join(",")}}`;
 	}
-);
+	return `${code}`;
+};
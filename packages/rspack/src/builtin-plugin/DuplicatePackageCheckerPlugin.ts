@@ -0,0 +1,27 @@
+import { RawDuplicatePackageCheckerPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+export type DuplicatePackageCheckerPluginOptions = {
+	verbose?: boolean;
+	emitError?: boolean;
+	exclude?: string | RegExp | (string | RegExp)[];
+};
+
+export const DuplicatePackageCheckerPlugin = create(
+	BuiltinPluginName.DuplicatePackageCheckerPlugin,
+	(
+		options: DuplicatePackageCheckerPluginOptions = {}
+	): RawDuplicatePackageCheckerPluginOptions => {
+		const { exclude } = options;
+		return {
+			verbose: options.verbose,
+			emitError: options.emitError,
+			exclude:
+				exclude === undefined
+					? undefined
+					: Array.isArray(exclude)
+					? exclude
+					: [exclude]
+		};
+	}
+);
@@ -0,0 +1,53 @@
+import { z } from "zod";
+import { RawSourceMapDevToolPluginOptions } from "@rspack/binding";
+import { BuiltinPluginName, create } from "./base";
+
+const rule = z.string().or(z.instanceof(RegExp));
+const rules = rule.or(rule.array());
+
+const sourceMapDevToolPluginOptions = z.strictObject({
+	filename: z.string().or(z.literal(false)).or(z.null()).optional(),
+	append: z.boolean().optional(),
+	namespace: z.string().optional(),
+	columns: z.boolean().optional(),
+	noSources: z.boolean().optional(),
+	publicPath: z.string().optional(),
+	ignoreList: rules.optional(),
+	debugIds: z.boolean().optional(),
+	moduleFilenameTemplate: z.string().optional(),
+	fallbackModuleFilenameTemplate: z.string().optional(),
+	sourceRoot: z.string().optional(),
+	fileContext: z.string().optional(),
+	test: rules.optional(),
+	include: rules.optional(),
+	exclude: rules.optional()
+});
+export type SourceMapDevToolPluginOptions = z.infer<
+	typeof sourceMapDevToolPluginOptions
+>;
+
+export const SourceMapDevToolPlugin = create(
+	BuiltinPluginName.SourceMapDevToolPlugin,
+	(
+		options: SourceMapDevToolPluginOptions = {}
+	): RawSourceMapDevToolPluginOptions => {
+		return {
+			filename: options.filename || undefined,
+			append: options.append,
+			namespace: options.namespace,
+			columns: options.columns,
+			noSources: options.noSources,
+			publicPath: options.publicPath,
+			ignoreList: options.ignoreList,
+			debugIds: options.debugIds,
+			moduleFilenameTemplate: options.moduleFilenameTemplate,
+			fallbackModuleFilenameTemplate: options.fallbackModuleFilenameTemplate,
+			sourceRoot: options.sourceRoot,
+			fileContext: options.fileContext,
+			test: options.test,
+			include: options.include,
+			exclude: options.exclude
+		};
+	},
+	"compilation"
+);
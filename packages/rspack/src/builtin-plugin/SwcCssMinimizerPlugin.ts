@@ -1,6 +1,34 @@
+import { RawSwcCssMinimizerRspackPluginOptions } from "@rspack/binding";
 import { BuiltinPluginName, create } from "./base";
 
+type MinifyCondition = string | RegExp;
+type MinifyConditions = MinifyCondition | MinifyCondition[];
+export type SwcCssMinimizerRspackPluginOptions = {
+	extractComments?: boolean | RegExp;
+	test?: MinifyConditions;
+	exclude?: MinifyConditions;
+	include?: MinifyConditions;
+	/**
+	 * Browserslist queries, syntax produced by the minifier that is not supported
+	 * by these browsers will be lowered. Defaults to `builtins.presetEnv.targets`.
+	 */
+	targets?: string[];
+};
+
 export const SwcCssMinimizerRspackPlugin = create(
 	BuiltinPluginName.SwcCssMinimizerRspackPlugin,
-	(options?: any /* TODO: extend more options */) => undefined
+	(
+		options?: SwcCssMinimizerRspackPluginOptions
+	): RawSwcCssMinimizerRspackPluginOptions => {
+		return {
+			extractComments: options?.extractComments
+				? String(options.extractComments)
+				: undefined,
+			test: options?.test,
+			include: options?.include,
+			exclude: options?.exclude,
+			targets: options?.targets
+		};
+	},
+	"compilation"
 );
@@ -33,7 +33,9 @@ export enum BuiltinPluginName {
 	ContainerPlugin = "ContainerPlugin",
 	ContainerReferencePlugin = "ContainerReferencePlugin",
 	ProvideSharedPlugin = "ProvideSharedPlugin",
-	ConsumeSharedPlugin = "ConsumeSharedPlugin"
+	ConsumeSharedPlugin = "ConsumeSharedPlugin",
+	SourceMapDevToolPlugin = "SourceMapDevToolPlugin",
+	DuplicatePackageCheckerPlugin = "DuplicatePackageCheckerPlugin"
 }
 
 type AffectedHooks = keyof Compiler["hooks"];
@@ -20,11 +20,13 @@ export * from "./WebWorkerTemplatePlugin";
 export * from "./LimitChunkCountPlugin";
 export * from "./MergeDuplicateChunksPlugin";
 export * from "./SplitChunksPlugin";
+export * from "./SourceMapDevToolPlugin";
 
 export * from "./HtmlRspackPlugin";
 export * from "./CopyRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./SwcCssMinimizerPlugin";
+export * from "./DuplicatePackageCheckerPlugin";
 
 ///// DEPRECATED /////
 import {
@@ -49,11 +51,13 @@ import {
 import {
 	BannerPluginOptions,
 	DefinePlugin,
+	DefinePluginOptions,
 	ProvidePlugin,
 	ProgressPlugin,
 	BannerPlugin
 } from ".";
 import { loadConfig } from "browserslist";
+import { load as loadBrowserslist } from "../config/browserslistTargetHandler";
 import {
 	EmotionOptions,
 	PluginImportOptions,
@@ -67,6 +71,12 @@ import {
 
 type BuiltinsCssConfig = {
 	modules?: Partial<RawCssModulesConfig>;
+	ignoreOrder?: boolean;
+	/**
+	 * Browserslist queries the CSS is lowered and prefixed for.
+	 * Defaults to the browsers of a `browserslist` target, the CSS is left as is for other targets.
+	 */
+	targets?: string[];
 };
 
 type EmotionConfigImportMap = {
@@ -122,6 +132,21 @@ function resolvePresetEnv(
 	};
 }
 
+function resolveCssTargets(
+	targets: BuiltinsCssConfig["targets"],
+	target: RspackOptionsNormalized["target"],
+	context: string
+): string[] {
+	if (targets) {
+		return targets;
+	}
+	return (Array.isArray(target) ? target : [target]).flatMap(target => {
+		const match =
+			typeof target === "string" && /^browserslist(?::(.+))?$/.exec(target);
+		return match ? loadBrowserslist(match[1]?.trim() ?? "", context) ?? [] : [];
+	});
+}
+
 function resolveDecorator(
 	decorator: Builtins["decorator"]
 ): RawDecoratorOptions | undefined {
@@ -147,7 +172,7 @@ export interface Builtins {
 	treeShaking?: boolean | "module";
 	progress?: boolean | Partial<RawProgressPluginOptions>;
 	noEmitAssets?: boolean;
-	define?: Record<string, string | boolean | undefined>;
+	define?: DefinePluginOptions;
 	provide?: Record<string, string | string[]>;
 	html?: Array<HtmlRspackPluginOptions>;
 	decorator?: boolean | Partial<RawDecoratorOptions>;
@@ -334,7 +359,13 @@ export function deprecated_resolveBuiltins(
 							: "[path][name][ext]__[local]",
 						exportsOnly: false,
 						...builtins.css?.modules
-					}
+					},
+					ignoreOrder: builtins.css?.ignoreOrder ?? false,
+					targets: resolveCssTargets(
+						builtins.css?.targets,
+						options.target,
+						contextPath
+					)
 			  }
 			: undefined,
 		treeShaking: resolveTreeShaking(builtins.treeShaking, production),
@@ -17,7 +17,9 @@ import type {
 	RawRspackFuture,
 	RawLibraryName,
 	RawLibraryOptions,
-	RawModuleRuleUse
+	RawModuleRuleUse,
+	RawSnapshotPathMatcher,
+	RawResolveRestriction
 } from "@rspack/binding";
 import assert from "assert";
 import { Compiler } from "../Compiler";
@@ -33,9 +35,11 @@ import {
 } from "./adapterRuleUse";
 import {
 	CrossOriginLoading,
+	Environment,
 	LibraryOptions,
 	Node,
 	Optimization,
+	OptimizationHashedIds,
 	Resolve,
 	RuleSetCondition,
 	RuleSetLogicalConditions,
@@ -119,6 +123,8 @@ export const getRawOptions = (
 		experiments,
 		node: getRawNode(options.node),
 		profile: options.profile!,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: remove this
 		builtins: options.builtins as any
 	};
@@ -175,10 +181,29 @@ function getRawResolve(resolve: Resolve): RawOptions["resolve"] {
 					references: references == "auto" ? undefined : references
 			  }
 			: undefined,
+		restrictions: resolve.restrictions?.map(getRawResolveRestriction),
 		byDependency: getRawResolveByDependency(resolve.byDependency)
 	};
 }
 
+function getRawResolveRestriction(
+	restriction: string | RegExp
+): RawResolveRestriction {
+	if (typeof restriction === "string") {
+		return {
+			type: "string",
+			stringMatcher: restriction
+		};
+	}
+	return {
+		type: "regexp",
+		regexpMatcher: {
+			source: restriction.source,
+			flags: restriction.flags
+		}
+	};
+}
+
 function getRawCrossOriginLoading(
 	crossOriginLoading: CrossOriginLoading
 ): RawOptions["output"]["crossOriginLoading"] {
@@ -232,7 +257,21 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		workerWasmLoading:
 			workerWasmLoading === false ? "false" : workerWasmLoading,
 		workerPublicPath: output.workerPublicPath!,
-		scriptType: output.scriptType === false ? "false" : output.scriptType!
+		scriptType: output.scriptType === false ? "false" : output.scriptType!,
+		environment: getRawOutputEnvironment(output.environment!)
+	};
+}
+
+function getRawOutputEnvironment(
+	environment: Environment
+): RawOptions["output"]["environment"] {
+	return {
+		arrowFunction: !!environment.arrowFunction,
+		const: !!environment.const,
+		destructuring: !!environment.destructuring,
+		optionalChaining: !!environment.optionalChaining,
+		dynamicImport: !!environment.dynamicImport,
+		module: !!environment.module
 	};
 }
 
@@ -712,6 +751,7 @@ function getRawOptimization(
 	return {
 		chunkIds: optimization.chunkIds,
 		moduleIds: optimization.moduleIds,
+		hashedIds: getRawOptimizationHashedIds(optimization.hashedIds!),
 		removeAvailableModules: optimization.removeAvailableModules,
 		removeEmptyChunks: optimization.removeEmptyChunks,
 		sideEffects: String(optimization.sideEffects),
@@ -723,11 +763,30 @@ function getRawOptimization(
 	};
 }
 
+function getRawOptimizationHashedIds(
+	hashedIds: OptimizationHashedIds
+): RawOptions["optimization"]["hashedIds"] {
+	const { hashFunction, hashDigest, hashDigestLength } = hashedIds;
+	assert(
+		!isNil(hashFunction) && !isNil(hashDigest) && !isNil(hashDigestLength)
+	);
+	return {
+		hashFunction,
+		hashDigest,
+		hashDigestLength
+	};
+}
+
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
-	assert(!isNil(resolve) && !isNil(module));
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
+	assert(
+		!isNil(resolve) &&
+			!isNil(module) &&
+			!isNil(immutablePaths) &&
+			!isNil(managedPaths)
+	);
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
 	assert(
@@ -744,6 +803,26 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
+		},
+		immutablePaths: immutablePaths.map(getRawSnapshotPathMatcher),
+		managedPaths: managedPaths.map(getRawSnapshotPathMatcher)
+	};
+}
+
+function getRawSnapshotPathMatcher(
+	path: string | RegExp
+): RawSnapshotPathMatcher {
+	if (typeof path === "string") {
+		return {
+			type: "string",
+			stringMatcher: path
+		};
+	}
+	return {
+		type: "regexp",
+		regexpMatcher: {
+			source: path.source,
+			flags: path.flags
 		}
 	};
 }
@@ -755,6 +834,7 @@ function getRawExperiments(
 		lazyCompilation,
 		incrementalRebuild,
 		asyncWebAssembly,
+		syncWebAssembly,
 		newSplitChunks,
 		topLevelAwait,
 		css,
@@ -764,6 +844,7 @@ function getRawExperiments(
 		!isNil(lazyCompilation) &&
 			!isNil(incrementalRebuild) &&
 			!isNil(asyncWebAssembly) &&
+			!isNil(syncWebAssembly) &&
 			!isNil(newSplitChunks) &&
 			!isNil(topLevelAwait) &&
 			!isNil(css) &&
@@ -774,6 +855,7 @@ function getRawExperiments(
 		lazyCompilation,
 		incrementalRebuild: getRawIncrementalRebuild(incrementalRebuild),
 		asyncWebAssembly,
+		syncWebAssembly,
 		newSplitChunks,
 		topLevelAwait,
 		css,
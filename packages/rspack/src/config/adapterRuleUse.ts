@@ -230,13 +230,17 @@ type GetLoaderOptions = (
 ) => RuleSetLoaderWithOptions["options"];
 
 const getSassLoaderOptions: GetLoaderOptions = o => {
-	(o ??= {} as any).__exePath = require.resolve(
-		`sass-embedded-${process.platform}-${
-			process.arch
-		}/dart-sass-embedded/dart-sass-embedded${
-			process.platform === "win32" ? ".bat" : ""
-		}`
-	);
+	try {
+		(o ??= {} as any).__exePath = require.resolve(
+			`sass-embedded-${process.platform}-${
+				process.arch
+			}/dart-sass-embedded/dart-sass-embedded${
+				process.platform === "win32" ? ".bat" : ""
+			}`
+		);
+	} catch {
+		// Reported by the loader when it runs, the platform may be unsupported.
+	}
 	return o;
 };
 
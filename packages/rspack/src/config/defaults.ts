@@ -76,6 +76,8 @@ export const applyRspackOptionsDefaults = (
 	F(options, "devtool", () => false as const);
 	D(options, "watch", false);
 	D(options, "profile", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	const futureDefaults = options.experiments.futureDefaults ?? false;
 	F(options, "cache", () => development);
@@ -87,7 +89,7 @@ export const applyRspackOptionsDefaults = (
 	applySnapshotDefaults(options.snapshot, { production });
 
 	applyModuleDefaults(options.module, {
-		// syncWebAssembly: options.experiments.syncWebAssembly,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
 		disableTransformByDefault:
 			options.experiments.rspackFuture!.disableTransformByDefault!,
@@ -163,6 +165,7 @@ const applyExperimentsDefaults = (
 ) => {
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "newSplitChunks", true);
 	D(experiments, "css", true); // we not align with webpack about the default value for better DX
 	D(experiments, "topLevelAwait", true);
@@ -193,6 +196,8 @@ const applySnapshotDefaults = (
 	snapshot: SnapshotOptions,
 	{ production }: { production: boolean }
 ) => {
+	A(snapshot, "immutablePaths", () => []);
+	A(snapshot, "managedPaths", () => [/^(.+?[\\/]node_modules[\\/])/]);
 	F(snapshot, "module", () =>
 		production
 			? { timestamp: true, hash: true }
@@ -214,10 +219,12 @@ const applyJavascriptParserOptionsDefaults = (
 const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
+		syncWebAssembly,
 		asyncWebAssembly,
 		css,
 		disableTransformByDefault
 	}: {
+		syncWebAssembly: boolean;
 		asyncWebAssembly: boolean;
 		css: boolean;
 		disableTransformByDefault: boolean;
@@ -334,6 +341,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -457,6 +486,20 @@ const applyOutputDefaults = (
 	D(output, "filename", output.module ? "[name].mjs" : "[name].js");
 	F(output, "iife", () => !output.module);
 
+	const environment = output.environment!;
+	const optimistic = (v?: boolean) => v || v === undefined;
+	// syntax is only used by the runtime when the target is known to support it
+	F(environment, "arrowFunction", () => !!(tp && tp.arrowFunction));
+	F(environment, "const", () => !!(tp && tp.const));
+	F(environment, "destructuring", () => !!(tp && tp.destructuring));
+	F(environment, "optionalChaining", () => !!(tp && tp.optionalChaining));
+	F(
+		environment,
+		"dynamicImport",
+		() => !!(tp && optimistic(tp.dynamicImport))
+	);
+	F(environment, "module", () => !!(tp && optimistic(tp.module)));
+
 	F(output, "chunkFilename", () => {
 		const filename = output.filename!;
 		if (typeof filename !== "function") {
@@ -742,11 +785,24 @@ const applyOptimizationDefaults = (
 	D(optimization, "removeAvailableModules", true);
 	D(optimization, "removeEmptyChunks", true);
 	D(optimization, "mergeDuplicateChunks", true);
-	F(optimization, "moduleIds", (): "named" | "deterministic" => {
-		if (production) return "deterministic";
-		return "named";
-	});
-	F(optimization, "chunkIds", (): "named" | "deterministic" => "named");
+	F(
+		optimization,
+		"moduleIds",
+		(): NonNullable<Optimization["moduleIds"]> => {
+			if (production) return "deterministic";
+			return "named";
+		}
+	);
+	F(
+		optimization,
+		"chunkIds",
+		(): NonNullable<Optimization["chunkIds"]> => "named"
+	);
+	// the same as the options of webpack's HashedModuleIdsPlugin
+	const hashedIds = optimization.hashedIds!;
+	D(hashedIds, "hashFunction", "md4");
+	D(hashedIds, "hashDigest", "base64");
+	D(hashedIds, "hashDigestLength", 4);
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "mangleExports", production);
 	D(optimization, "providedExports", true);
@@ -36,6 +36,7 @@ import type {
 	WatchOptions,
 	DevServer,
 	Profile,
+	RecordsPath,
 	Builtins,
 	EntryRuntime,
 	ChunkLoading,
@@ -69,6 +70,7 @@ import type {
 	HashFunction,
 	HashSalt,
 	WorkerPublicPath,
+	Environment,
 	RuleSetRules,
 	ParserOptionsByModuleType,
 	GeneratorOptionsByModuleType,
@@ -214,7 +216,8 @@ export const getNormalizedRspackOptions = (
 				workerChunkLoading: output.workerChunkLoading,
 				workerWasmLoading: output.workerWasmLoading,
 				workerPublicPath: output.workerPublicPath,
-				scriptType: output.scriptType
+				scriptType: output.scriptType,
+				environment: cloneObject(output.environment)
 			};
 		}),
 		resolve: nestedConfig(config.resolve, resolve => ({
@@ -251,6 +254,8 @@ export const getNormalizedRspackOptions = (
 				}
 		),
 		snapshot: nestedConfig(config.snapshot, snapshot => ({
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p]),
 			resolve: optionalNestedConfig(snapshot.resolve, resolve => ({
 				timestamp: resolve.timestamp,
 				hash: resolve.hash
@@ -287,6 +292,7 @@ export const getNormalizedRspackOptions = (
 				runtimeChunk: getNormalizedOptimizationRuntimeChunk(
 					optimization.runtimeChunk
 				),
+				hashedIds: cloneObject(optimization.hashedIds),
 				splitChunks: nestedConfig(
 					optimization.splitChunks,
 					splitChunks =>
@@ -309,6 +315,14 @@ export const getNormalizedRspackOptions = (
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
 		profile: config.profile,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		builtins: nestedConfig(config.builtins, builtins => ({
 			...builtins
 		}))
@@ -478,6 +492,7 @@ export interface OutputNormalized {
 	workerWasmLoading?: WasmLoading;
 	workerPublicPath?: WorkerPublicPath;
 	scriptType?: "module" | "text/javascript" | false;
+	environment?: Environment;
 }
 
 export interface ModuleOptionsNormalized {
@@ -491,6 +506,7 @@ export interface ExperimentsNormalized {
 	lazyCompilation?: boolean;
 	incrementalRebuild?: false | IncrementalRebuildOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	newSplitChunks?: boolean;
 	topLevelAwait?: boolean;
@@ -538,5 +554,7 @@ export interface RspackOptionsNormalized {
 	devServer?: DevServer;
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	profile?: Profile;
+	recordsInputPath?: RecordsPath;
+	recordsOutputPath?: RecordsPath;
 	builtins: Builtins;
 }
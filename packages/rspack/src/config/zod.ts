@@ -285,6 +285,16 @@ export type HashSalt = z.infer<typeof hashSalt>;
 const sourceMapFilename = z.string();
 export type SourceMapFilename = z.infer<typeof sourceMapFilename>;
 
+const environment = z.strictObject({
+	arrowFunction: z.boolean().optional(),
+	const: z.boolean().optional(),
+	destructuring: z.boolean().optional(),
+	optionalChaining: z.boolean().optional(),
+	dynamicImport: z.boolean().optional(),
+	module: z.boolean().optional()
+});
+export type Environment = z.infer<typeof environment>;
+
 const output = z.strictObject({
 	path: path.optional(),
 	clean: clean.optional(),
@@ -329,7 +339,8 @@ const output = z.strictObject({
 	workerChunkLoading: chunkLoading.optional(),
 	workerWasmLoading: wasmLoading.optional(),
 	workerPublicPath: workerPublicPath.optional(),
-	scriptType: scriptType.optional()
+	scriptType: scriptType.optional(),
+	environment: environment.optional()
 });
 export type Output = z.infer<typeof output>;
 //#endregion
@@ -369,7 +380,9 @@ const baseResolveOptions = z.strictObject({
 	tsConfig: resolveTsconfig.optional(),
 	fullySpecified: z.boolean().optional(),
 	exportsFields: z.array(z.string()).optional(),
-	extensionAlias: z.record(z.string().or(z.array(z.string()))).optional()
+	extensionAlias: z.record(z.string().or(z.array(z.string()))).optional(),
+	roots: z.array(z.string()).optional(),
+	restrictions: z.array(z.string().or(z.instanceof(RegExp))).optional()
 });
 
 export type ResolveOptions = z.infer<typeof baseResolveOptions> & {
@@ -826,7 +839,12 @@ export type Node = z.infer<typeof node>;
 //#endregion
 
 //#region Snapshot
+const snapshotPaths = z.array(z.string().or(z.instanceof(RegExp)));
+export type SnapshotPaths = z.infer<typeof snapshotPaths>;
+
 const snapshotOptions = z.strictObject({
+	immutablePaths: snapshotPaths.optional(),
+	managedPaths: snapshotPaths.optional(),
 	module: z
 		.strictObject({
 			hash: z.boolean().optional(),
@@ -1000,9 +1018,21 @@ export type OptimizationSplitChunksOptions = z.infer<
 	typeof optimizationSplitChunksOptions
 >;
 
+const optimizationHashedIds = z.strictObject({
+	hashFunction: z.enum(["md4", "xxhash64"]).optional(),
+	hashDigest: z.enum(["hex", "base64"]).optional(),
+	hashDigestLength: z.number().optional()
+});
+export type OptimizationHashedIds = z.infer<typeof optimizationHashedIds>;
+
 const optimization = z.strictObject({
-	moduleIds: z.enum(["named", "deterministic"]).optional(),
-	chunkIds: z.enum(["named", "deterministic"]).optional(),
+	moduleIds: z
+		.enum(["natural", "named", "deterministic", "size", "hashed"])
+		.optional(),
+	chunkIds: z
+		.enum(["natural", "named", "deterministic", "size", "hashed"])
+		.optional(),
+	hashedIds: optimizationHashedIds.optional(),
 	minimize: z.boolean().optional(),
 	minimizer: z.literal("...").or(plugin).array().optional(),
 	mergeDuplicateChunks: z.boolean().optional(),
@@ -1070,6 +1100,7 @@ const experiments = z.strictObject({
 			return true;
 		}),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
 	newSplitChunks: z
@@ -1142,6 +1173,11 @@ const profile = z.boolean();
 export type Profile = z.infer<typeof profile>;
 //#endregion
 
+//#region Records
+const recordsPath = z.literal(false).or(z.string());
+export type RecordsPath = z.infer<typeof recordsPath>;
+//#endregion
+
 //#region Builtins (deprecated)
 const builtins = z.custom<oldBuiltins.Builtins>();
 export type Builtins = z.infer<typeof builtins>;
@@ -1175,7 +1211,10 @@ export const rspackOptions = z.strictObject({
 	devServer: devServer.optional(),
 	builtins: builtins.optional(),
 	module: moduleOptions.optional(),
-	profile: profile.optional()
+	profile: profile.optional(),
+	recordsPath: recordsPath.optional(),
+	recordsInputPath: recordsPath.optional(),
+	recordsOutputPath: recordsPath.optional()
 });
 export type RspackOptions = z.infer<typeof rspackOptions>;
 export type Configuration = RspackOptions;
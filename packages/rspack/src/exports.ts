@@ -78,6 +78,9 @@ export type { BannerPluginArgument } from "./builtin-plugin";
 export { ProvidePlugin } from "./builtin-plugin";
 export type { ProvidePluginOptions } from "./builtin-plugin";
 
+export { SourceMapDevToolPlugin } from "./builtin-plugin";
+export type { SourceMapDevToolPluginOptions } from "./builtin-plugin";
+
 export { DefinePlugin } from "./builtin-plugin";
 export type { DefinePluginOptions } from "./builtin-plugin";
 
@@ -183,6 +186,10 @@ export { SwcJsMinimizerRspackPlugin } from "./builtin-plugin";
 export type { SwcJsMinimizerRspackPluginOptions } from "./builtin-plugin";
 
 export { SwcCssMinimizerRspackPlugin } from "./builtin-plugin";
+export type { SwcCssMinimizerRspackPluginOptions } from "./builtin-plugin";
 
 export { CopyRspackPlugin } from "./builtin-plugin";
 export type { CopyRspackPluginOptions } from "./builtin-plugin";
+
+export { DuplicatePackageCheckerPlugin } from "./builtin-plugin";
+export type { DuplicatePackageCheckerPluginOptions } from "./builtin-plugin";
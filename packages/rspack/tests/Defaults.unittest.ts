@@ -217,16 +217,43 @@ describe("snapshots", () => {
 		+       "development",
 	`)
 	);
-	/**
-	 * not support yet
-	 */
 	test("sync wasm", { experiments: { syncWebAssembly: true } }, e =>
 		e.toMatchInlineSnapshot(`
 		- Expected
 		+ Received
 
 		@@ ... @@
+		-     "syncWebAssembly": false,
 		+     "syncWebAssembly": true,
+		@@ ... @@
+		+       },
+		+       Object {
+		+         "rules": Array [
+		+           Object {
+		+             "descriptionData": Object {
+		+               "type": "module",
+		+             },
+		+             "resolve": Object {
+		+               "fullySpecified": true,
+		+             },
+		+           },
+		+         ],
+		+         "test": /\\.wasm$/i,
+		+         "type": "webassembly/sync",
+		+       },
+		+       Object {
+		+         "mimetype": "application/wasm",
+		+         "rules": Array [
+		+           Object {
+		+             "descriptionData": Object {
+		+               "type": "module",
+		+             },
+		+             "resolve": Object {
+		+               "fullySpecified": true,
+		+             },
+		+           },
+		+         ],
+		+         "type": "webassembly/sync",
 	`)
 	);
 	/**
@@ -315,6 +342,7 @@ describe("snapshots", () => {
 			-     "asyncWebAssembly": false,
 			+     "asyncWebAssembly": true,
 			@@ ... @@
+			-     "syncWebAssembly": false,
 			+     "syncWebAssembly": true,
 			@@ ... @@
 			+       },
@@ -869,7 +897,16 @@ describe("snapshots", () => {
 	`)
 	);
 	test("records", { recordsPath: "some-path" }, e =>
-		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
+		e.toMatchInlineSnapshot(`
+		- Expected
+		+ Received
+
+		@@ ... @@
+		-   "recordsInputPath": false,
+		-   "recordsOutputPath": false,
+		+   "recordsInputPath": "some-path",
+		+   "recordsOutputPath": "some-path",
+	`)
 	);
 	test("ecmaVersion", { output: { ecmaVersion: 2020 } }, e =>
 		e.toMatchInlineSnapshot(`Compared values have no visual difference.`)
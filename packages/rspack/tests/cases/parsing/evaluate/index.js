@@ -0,0 +1,41 @@
+it("should evaluate nullish coalescing", function () {
+	if (undefined ?? false) require("fail");
+	if (null ?? "") require("fail");
+	expect(null ?? "fallback").toBe("fallback");
+	expect("value" ?? "fallback").toBe("value");
+});
+
+it("should evaluate optional chaining", function () {
+	if (undefined?.value) require("fail");
+});
+
+it("should evaluate typeof of the values known at compile time", function () {
+	if (typeof 1 !== "number") require("fail");
+	if (typeof "" !== "string") require("fail");
+	if (typeof null !== "object") require("fail");
+	if (typeof undefined !== "undefined") require("fail");
+});
+
+it("should evaluate the methods of strings", function () {
+	if ("abc".replace("b", "x") !== "axc") require("fail");
+	if ("abcdef".slice(1, 3) !== "bc") require("fail");
+	if ("abcdef".slice(-2) !== "ef") require("fail");
+	if ("abcdef".substring(3, 1) !== "bc") require("fail");
+	if ("abcdef".substr(1, 2) !== "bc") require("fail");
+	if ("abc".concat("d", 1) !== "abcd1") require("fail");
+});
+
+it("should not evaluate the methods of strings differently", function () {
+	expect("abc".replace("b", "$&$&") === "abbc" ? "kept" : "folded").toBe(
+		"kept"
+	);
+	expect("a\u{1F600}b".slice(1, 3) === "\u{1F600}" ? "kept" : "folded").toBe(
+		"kept"
+	);
+	expect("a\u{1F600}b".substring(3) === "b" ? "kept" : "folded").toBe("kept");
+});
+
+it("should not collect the dependencies in the branches never taken", function () {
+	const value = false ? require("fail") : "value";
+	expect(value).toBe("value");
+});
@@ -30,19 +30,19 @@ it("should answer typeof require.resolve correctly", function () {
 	expect(typeof require.resolve).toBe("function");
 });
 
-// it("should not parse filtered stuff", function () {
-// 	if (typeof require != "function") require("fail");
-// 	if (typeof require !== "function") require("fail");
-// 	if (!(typeof require == "function")) require("fail");
-// 	if (!(typeof require === "function")) require("fail");
-// 	if (typeof require == "undefined") require = require("fail");
-// 	if (typeof require === "undefined") require = require("fail");
-// 	if (typeof require.resolve !== "function") require("fail");
-// 	if (typeof module == "undefined") module = require("fail");
-// 	if (typeof module === "undefined") module = require("fail");
-// 	if (typeof module != "object") module = require("fail");
-// 	if (typeof exports == "undefined") exports = require("fail");
-// 	if (typeof require.include !== "function") require.include("fail");
-// 	if (typeof require.ensure !== "function")
-// 		require.ensure(["fail"], function () {});
-// });
+it("should not parse filtered stuff", function () {
+	if (typeof require != "function") require("fail");
+	if (typeof require !== "function") require("fail");
+	if (!(typeof require == "function")) require("fail");
+	if (!(typeof require === "function")) require("fail");
+	// if (typeof require == "undefined") require = require("fail");
+	// if (typeof require === "undefined") require = require("fail");
+	// if (typeof require.resolve !== "function") require("fail");
+	// if (typeof module == "undefined") module = require("fail");
+	// if (typeof module === "undefined") module = require("fail");
+	// if (typeof module != "object") module = require("fail");
+	// if (typeof exports == "undefined") exports = require("fail");
+	// if (typeof require.include !== "function") require.include("fail");
+	// if (typeof require.ensure !== "function")
+	// 	require.ensure(["fail"], function () {});
+});
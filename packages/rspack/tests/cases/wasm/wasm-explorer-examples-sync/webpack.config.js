@@ -0,0 +1,8 @@
+module.exports = {
+	output: {
+		wasmLoading: "async-node"
+	},
+	experiments: {
+		syncWebAssembly: true
+	}
+};
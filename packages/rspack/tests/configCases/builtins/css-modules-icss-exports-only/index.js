@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should export the values of ICSS and @value with exportsOnly", () => {
+	const style = require("./index.css");
+	expect(fs.existsSync(path.resolve(__dirname, "./main.css"))).toBe(false);
+	expect(style.themePrimary).toBe("red");
+	expect(style.accent).toBe("blue");
+	expect(style.gap).toBe("4px");
+	expect(style.size).toBe("12px");
+	expect(style.button).toMatch(/button/);
+});
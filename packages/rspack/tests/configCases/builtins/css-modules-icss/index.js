@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should export the values of ICSS and @value", () => {
+	const style = require("./index.css");
+	expect(style.themePrimary).toBe("red");
+	expect(style.accent).toBe("blue");
+	expect(style.gap).toBe("4px");
+	expect(style.size).toBe("12px");
+	expect(style.brandColor).toBeUndefined();
+	expect(style.button).toMatch(/button/);
+});
+
+it("should replace the values in the css", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(/color:\s*red/);
+	expect(css).toMatch(/background:\s*blue/);
+	expect(css).toMatch(/border-color:\s*red/);
+	expect(css).toMatch(/margin:\s*4px/);
+	expect(css).not.toMatch(/@value|:import|:export/);
+});
@@ -0,0 +1,58 @@
+const assert = require("assert").strict;
+const pluginName = "check-conflicting-order";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const warnings = stats
+				.toJson()
+				.warnings.map(warning => warning.message)
+				.filter(message => message.includes("Conflicting order"));
+			assert.equal(warnings.length, 3);
+			assert.match(
+				warnings[0],
+				/Following module has been added:\n \* .*e1\.css\ndespite it was not able to fulfill desired ordering with these modules:\n \* .*e2\.css\n   - couldn't fulfill desired order of chunk group\(s\) entry2/
+			);
+			assert.match(
+				warnings[1],
+				/Following module has been added:\n \* .*e4\.css\ndespite it was not able to fulfill desired ordering with these modules:\n \* .*e3\.css\n   - couldn't fulfill desired order of chunk group\(s\) entry3\n   - while fulfilling desired order of chunk group\(s\) entry4/
+			);
+			assert.match(
+				warnings[2],
+				/Following module has been added:\n \* .*e2\.css\ndespite it was not able to fulfill desired ordering with these modules:\n \* .*e3\.css\n   - couldn't fulfill desired order of chunk group\(s\) entry3\n   - while fulfilling desired order of chunk group\(s\) entry4/
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./test.js",
+		entry1: "./index.js",
+		entry2: "./index2.js",
+		entry3: "./index3.js",
+		entry4: "./index4.js"
+	},
+	experiments: {
+		css: true
+	},
+	builtins: {
+		css: {
+			ignoreOrder: false
+		}
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					chunks: "all",
+					test: /\.css$/,
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,11 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should concatenate the css modules in order", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "styles.css"), "utf-8");
+	const order = Array.from(
+		css.matchAll(/content:\s*["'](e\d)["']/g),
+		m => m[1]
+	);
+	expect(order).toEqual(["e2", "e1", "e3"]);
+});
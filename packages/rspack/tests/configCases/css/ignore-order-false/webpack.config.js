@@ -0,0 +1,49 @@
+const assert = require("assert").strict;
+const pluginName = "check-conflicting-order";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const warnings = stats
+				.toJson()
+				.warnings.map(warning => warning.message)
+				.filter(message => message.includes("Conflicting order"));
+			assert.equal(warnings.length, 1);
+			assert.match(
+				warnings[0],
+				/Following module has been added:\n \* .*e2\.css\ndespite it was not able to fulfill desired ordering with these modules:\n \* .*e1\.css\n   - couldn't fulfill desired order of chunk group\(s\) entry2\n   - while fulfilling desired order of chunk group\(s\) entry1/
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./test.js",
+		entry1: "./index.js",
+		entry2: "./index2.js",
+		entry3: "./index3.js"
+	},
+	experiments: {
+		css: true
+	},
+	builtins: {
+		css: {
+			ignoreOrder: false
+		}
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					chunks: "all",
+					test: /\.css$/,
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,44 @@
+const assert = require("assert").strict;
+const pluginName = "check-conflicting-order";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const warnings = stats
+				.toJson()
+				.warnings.map(warning => warning.message)
+				.filter(message => message.includes("Conflicting order"));
+			assert.deepEqual(warnings, []);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./test.js",
+		entry1: "./index.js",
+		entry2: "./index2.js"
+	},
+	experiments: {
+		css: true
+	},
+	builtins: {
+		css: {
+			ignoreOrder: true
+		}
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					chunks: "all",
+					test: /\.css$/,
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/append can't be false when no filename is provided/]
+];
@@ -0,0 +1 @@
+it("should not compile", () => {});
@@ -0,0 +1,7 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [new SourceMapDevToolPlugin({ append: false })]
+};
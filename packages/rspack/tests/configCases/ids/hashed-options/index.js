@@ -0,0 +1,14 @@
+import fs from "fs";
+import value from "./module";
+
+it("should assign hashed ids with the options to modules", function () {
+	expect(value).toBe(1);
+	expect(String(module.id)).toMatch(/^[0-9a-f]{8,}$/);
+});
+
+it("should assign hashed ids with the options to chunks", async function () {
+	const { default: chunkValue } = await import("./chunk");
+	expect(chunkValue).toBe(2);
+	const files = fs.readdirSync(__dirname);
+	expect(files.some(file => /^[0-9a-f]{8,}\.js$/.test(file))).toBe(true);
+});
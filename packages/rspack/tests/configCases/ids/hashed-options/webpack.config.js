@@ -0,0 +1,13 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	optimization: {
+		moduleIds: "hashed",
+		chunkIds: "hashed",
+		hashedIds: {
+			hashFunction: "xxhash64",
+			hashDigest: "hex",
+			hashDigestLength: 8
+		}
+	}
+};
@@ -0,0 +1,28 @@
+import fs from "fs";
+import value from "./module";
+
+it("should assign hashed ids to modules", function () {
+	expect(value).toBe(1);
+	expect(String(module.id)).toMatch(/^[0-9a-zA-Z+/]{4,}$/);
+});
+
+it("should assign hashed ids to chunks", async function () {
+	const { default: chunkValue } = await import("./chunk");
+	expect(chunkValue).toBe(2);
+	const values = await Promise.all([
+		import("./chunk-a"),
+		import("./chunk-b"),
+		import("./chunk-c")
+	]);
+	expect(values.map(m => m.default)).toEqual(["a", "b", "c"]);
+});
+
+it("should emit the hashed chunk ids into the filenames", function () {
+	const chunkFiles = fs
+		.readdirSync(__dirname)
+		.filter(file => file.startsWith("chunk-"));
+	expect(chunkFiles).toHaveLength(4);
+	for (const file of chunkFiles) {
+		expect(file).toMatch(/^chunk-[0-9a-zA-Z_-]{4,}\.js$/);
+	}
+});
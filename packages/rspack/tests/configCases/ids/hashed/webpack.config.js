@@ -0,0 +1,11 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		chunkFilename: "chunk-[id].js"
+	},
+	optimization: {
+		moduleIds: "hashed",
+		chunkIds: "hashed"
+	}
+};
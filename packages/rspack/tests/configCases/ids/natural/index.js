@@ -0,0 +1,19 @@
+import value, { id as moduleId } from "./module";
+import sharedId from "./shared";
+import fs from "fs";
+
+it("should assign natural ids to modules in pre-order", async function () {
+	expect(value).toBe(1);
+	const { default: chunkValue, id: chunkModuleId } = await import("./chunk");
+	expect(chunkValue).toBe(2);
+	// index.js -> module.js -> shared.js -> fs -> chunk.js
+	const ids = [module.id, moduleId, sharedId, chunkModuleId].map(Number);
+	expect(ids[0]).toBe(0);
+	expect(ids).toEqual([...ids].sort((a, b) => a - b));
+});
+
+it("should assign natural ids to chunks by the name", function () {
+	// the unnamed async chunk goes before the `main` chunk
+	const files = fs.readdirSync(__dirname);
+	expect(files).toContain("0.js");
+});
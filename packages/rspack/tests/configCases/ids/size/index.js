@@ -0,0 +1,22 @@
+import value, { id as moduleId } from "./module";
+import sharedId from "./shared";
+import fs from "fs";
+
+it("should assign size ids to modules by occurrences", async function () {
+	expect(value).toBe(1);
+	const { default: chunkValue, id: chunkModuleId } = await import("./chunk");
+	expect(chunkValue).toBe(2);
+	const ids = [module.id, moduleId, sharedId, chunkModuleId].map(Number);
+	// shared.js is imported by both the modules of the initial chunk
+	expect(ids[2]).toBe(0);
+	// module.js is referenced by more connections than the entry module
+	expect(ids[1]).toBeLessThan(ids[0]);
+	// chunk.js is not in any initial chunk
+	expect(ids[3]).toBe(Math.max(...ids));
+});
+
+it("should assign size ids to chunks by the occurrences", function () {
+	// the async chunk is loaded by an initial chunk, while the `main` chunk is not
+	const files = fs.readdirSync(__dirname);
+	expect(files).toContain("0.js");
+});
@@ -0,0 +1,4 @@
+import "./shared";
+
+export default 1;
+export const id = module.id;
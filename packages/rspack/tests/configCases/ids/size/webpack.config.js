@@ -0,0 +1,8 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	optimization: {
+		moduleIds: "size",
+		chunkIds: "size"
+	}
+};
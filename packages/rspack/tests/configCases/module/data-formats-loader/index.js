@@ -0,0 +1,9 @@
+import json5 from "./data.json5";
+import yaml from "./data.yaml";
+import toml from "./data.toml";
+
+it("should parse the output of the loaders as javascript", () => {
+	expect(json5).toBe('{ name: "json5" }\n');
+	expect(yaml).toBe("name: yaml\n");
+	expect(toml).toBe('name = "toml"\n');
+});
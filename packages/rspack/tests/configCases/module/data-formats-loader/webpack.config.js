@@ -0,0 +1,18 @@
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /\.(json5|ya?ml|toml)$/i,
+				use: [
+					{
+						loader: "./raw-loader.js"
+					}
+				]
+			}
+		]
+	}
+};
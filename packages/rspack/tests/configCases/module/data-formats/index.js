@@ -0,0 +1,18 @@
+import json5 from "./data.json5";
+import yaml, { nested } from "./data.yaml";
+import yml from "./data.yml";
+import toml from "./data.toml";
+
+it("should parse json5 modules with the type set by a rule", () => {
+	expect(json5).toEqual({ name: "json5", list: [1, 2, 3] });
+});
+
+it("should parse yaml modules with the type set by a rule", () => {
+	expect(yaml.name).toBe("yaml");
+	expect(nested).toEqual({ list: [1, 2] });
+	expect(yml).toEqual({ name: "yml" });
+});
+
+it("should parse toml modules with the type set by a rule", () => {
+	expect(toml).toEqual({ name: "toml", table: { enabled: true } });
+});
@@ -0,0 +1,21 @@
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.json5$/i,
+				type: "json5"
+			},
+			{
+				test: /\.ya?ml$/i,
+				type: "yaml"
+			},
+			{
+				test: /\.toml$/i,
+				type: "toml"
+			}
+		]
+	}
+};
@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+
+it("runtime should be rendered for the environment", async () => {
+	const content = await fs.promises.readFile(
+		path.resolve(__dirname, "a.js"),
+		"utf-8"
+	);
+
+	// jsonp chunk loading
+	expect(content).toContain(
+		"__webpack_require__.f.j = (chunkId, promises) => {"
+	);
+	expect(content).toContain(
+		"var webpackJsonpCallback = (parentChunkLoadingFunction, data) => {"
+	);
+	expect(content).toContain("var [chunkIds, moreModules, runtime] = data;");
+	expect(content).toContain("const chunkLoadingGlobal = ");
+	// css loading
+	expect(content).toContain(
+		"var loadStylesheet = (chunkId, url, done, hmr) => {"
+	);
+	expect(content).toContain("var [chunkIds] = data;");
+	// hot module replacement
+	expect(content).toContain("dispose: (callback) => {");
+	expect(content).not.toContain("function (parentChunkLoadingFunction");
+});
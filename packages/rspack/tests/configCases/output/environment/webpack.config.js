@@ -0,0 +1,27 @@
+const { HotModuleReplacementPlugin } = require("../../../../");
+
+/** @type {import("../../../../src/index").RspackOptions[]} */
+module.exports = [
+	{
+		entry: "./a.js",
+		target: "web",
+		output: {
+			filename: "a.js",
+			environment: {
+				arrowFunction: true,
+				const: true,
+				destructuring: true
+			}
+		},
+		experiments: {
+			css: true
+		},
+		plugins: [new HotModuleReplacementPlugin()]
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "index.js"
+		}
+	}
+];
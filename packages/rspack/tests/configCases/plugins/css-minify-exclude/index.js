@@ -0,0 +1,12 @@
+const fs = require("fs");
+const path = require("path");
+
+it("[css-minify-exclude]: a.css should be minified", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "a.css"), "utf-8");
+	expect(content).not.toMatch("\n");
+});
+
+it("[css-minify-exclude]: b.css should not be minified", () => {
+	const content = fs.readFileSync(path.resolve(__dirname, "b.css"), "utf-8");
+	expect(content).toMatch("\n");
+});
@@ -0,0 +1,23 @@
+const rspack = require("@rspack/core");
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b",
+		main: "./index"
+	},
+	experiments: {
+		css: true
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcCssMinimizerRspackPlugin({
+				include: [/\.css$/],
+				exclude: [/b\.css/]
+			})
+		]
+	}
+};
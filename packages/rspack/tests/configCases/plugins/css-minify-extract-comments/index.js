@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+import "./index.css";
+
+it("should minify css and extract comments", () => {
+	const content = fs.readFileSync(
+		path.resolve(__dirname, "main.css.LICENSE.txt"),
+		"utf-8"
+	);
+	expect(content).toMatch("Legal Comment");
+	expect(content).toMatch("@license MIT");
+	expect(content).not.toMatch("Normal Comment");
+	const css = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(css).toMatch(
+		"/*! For license information please see main.css.LICENSE.txt */"
+	);
+	expect(css).not.toMatch("Legal Comment");
+	expect(css).not.toMatch("Normal Comment");
+});
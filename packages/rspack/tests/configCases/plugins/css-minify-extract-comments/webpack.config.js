@@ -0,0 +1,17 @@
+const rspack = require("@rspack/core");
+/**
+ * @type {import("@rspack/core").Configuration}
+ */
+module.exports = {
+	experiments: {
+		css: true
+	},
+	optimization: {
+		minimize: true,
+		minimizer: [
+			new rspack.SwcCssMinimizerRspackPlugin({
+				extractComments: true
+			})
+		]
+	}
+};
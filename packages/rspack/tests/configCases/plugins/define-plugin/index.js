@@ -0,0 +1,29 @@
+it("should define the nested keys", () => {
+	expect(process.env.NODE_ENV).toBe("production");
+	expect(process.env.DEBUG).toBe(false);
+	expect(process.env.OTHER).toBeUndefined();
+	expect(process.env).toEqual({ NODE_ENV: "production", DEBUG: false });
+	expect(OBJECT.SUB.NUMBER).toBe(1);
+	expect(OBJECT.SUB.FUNCTION(1)).toBe(2);
+	expect(typeof OBJECT).toBe("object");
+	expect(typeof OBJECT.SUB.FUNCTION).toBe("function");
+	expect(import.meta.env.MODE).toBe("production");
+});
+
+it("should not replace the shadowed names and the assignment targets", () => {
+	const OBJECT = { SUB: 0 };
+	expect(OBJECT.SUB).toBe(0);
+	let { NODE_ENV } = { NODE_ENV: "test" };
+	expect(NODE_ENV).toBe("test");
+	const { DEBUG } = process.env;
+	expect(DEBUG).toBe(false);
+});
+
+it("should skip the branches which are never taken", () => {
+	if (process.env.NODE_ENV !== "production") require("fail");
+	const value = process.env.DEBUG ? require("fail") : 1;
+	expect(value).toBe(1);
+	if (typeof OBJECT.SUB.NUMBER !== "number") require("fail");
+	if (import.meta.env.MODE !== "production") require("fail");
+	process.env.DEBUG && require("fail");
+});
@@ -0,0 +1,22 @@
+const { DefinePlugin } = require("@rspack/core");
+
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	plugins: [
+		new DefinePlugin({
+			"process.env": {
+				NODE_ENV: JSON.stringify("production"),
+				DEBUG: false
+			},
+			"import.meta.env.MODE": JSON.stringify("production"),
+			OBJECT: {
+				SUB: {
+					NUMBER: 1,
+					FUNCTION: function (a) {
+						return a + 1;
+					}
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,36 @@
+import dep from "dep";
+import excluded from "excluded";
+import lib from "lib";
+import major from "major";
+
+it("should bundle both versions of the packages", () => {
+	expect(dep).toBe("dep@1.0.0");
+	expect(excluded).toBe("excluded@1.0.0");
+	expect(major).toBe("major@10.0.0");
+	expect(lib).toEqual(["dep@2.0.0", "excluded@2.0.0", "major@9.0.0"]);
+});
+
+it("should warn about the duplicated packages which are not excluded", () => {
+	const warnings = __STATS__.warnings.filter(warning =>
+		/Multiple versions of/.test(warning.message)
+	);
+	expect(warnings).toHaveLength(2);
+	const { message } = warnings.find(warning =>
+		warning.message.includes('"dep"')
+	);
+	expect(message).toContain('Multiple versions of "dep" found in the module graph');
+	expect(message).toContain("dep@1.0.0 from ./node_modules/dep");
+	expect(message).toContain("dep@2.0.0 from ./node_modules/lib/node_modules/dep");
+	expect(message).toMatch(/<- .*node_modules\/lib\/index\.js/);
+	expect(message).not.toContain("excluded");
+});
+
+it("should list the versions in semver order", () => {
+	const { message } = __STATS__.warnings.find(warning =>
+		warning.message.includes('Multiple versions of "major"')
+	);
+	expect(message.indexOf("major@9.0.0")).toBeGreaterThan(-1);
+	expect(message.indexOf("major@9.0.0")).toBeLessThan(
+		message.indexOf("major@10.0.0")
+	);
+});
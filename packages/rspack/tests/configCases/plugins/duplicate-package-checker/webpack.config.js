@@ -0,0 +1,11 @@
+const { DuplicatePackageCheckerPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new DuplicatePackageCheckerPlugin({
+			verbose: true,
+			exclude: [/^excl/]
+		})
+	]
+};
@@ -0,0 +1,3 @@
+it("should skip the roots whose result is not allowed by the restrictions", () => {
+	expect(require("/value")).toBe("b");
+});
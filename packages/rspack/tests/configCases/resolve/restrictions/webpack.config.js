@@ -0,0 +1,8 @@
+const path = require("path");
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		roots: [path.resolve(__dirname, "a"), path.resolve(__dirname, "b")],
+		restrictions: [/(index|b[\\/]value)\.js$/]
+	}
+};
@@ -0,0 +1,4 @@
+it("should resolve server-relative requests in the roots", () => {
+	expect(require("/value")).toBe("a");
+	expect(require("/only-b")).toBe("b");
+});
@@ -0,0 +1,7 @@
+const path = require("path");
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		roots: [path.resolve(__dirname, "a"), path.resolve(__dirname, "b")]
+	}
+};
@@ -4,4 +4,6 @@ it("basic", () => {
 	const map = JSON.parse(source);
 	expect(map.sources).toContain("./index.js");
 	expect(map.file).toEqual("main.js");
+	expect(map.ignoreList).toBeUndefined();
+	expect(map.x_google_ignoreList).toBeUndefined();
 });
@@ -0,0 +1,36 @@
+import vendor from "vendor";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the source map to the configured filename", () => {
+	expect(vendor).toBe("vendor");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(/\/\/# sourceMappingURL=.*maps\/main\.js\.map/);
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "maps/main.js.map"), "utf-8")
+	);
+	expect(map.file).toBe("main.js");
+	expect(map.sources).toContain("./index.js");
+});
+
+it("should add the sources in node_modules to the ignore list", () => {
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "maps/main.js.map"), "utf-8")
+	);
+	const ignored = map.ignoreList.map(index => map.sources[index]);
+	expect(ignored).toEqual(["./node_modules/vendor/index.js"]);
+	expect(map.x_google_ignoreList).toEqual(map.ignoreList);
+});
+
+it("should embed the same debug id in the asset and the source map", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	const map = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "maps/main.js.map"), "utf-8")
+	);
+	const [, debugId] = /\/\/# debugId=(.+)/.exec(source);
+	expect(debugId).toMatch(
+		/^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/
+	);
+	expect(map.debugId).toBe(debugId);
+});
@@ -0,0 +1,13 @@
+const { SourceMapDevToolPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: false,
+	plugins: [
+		new SourceMapDevToolPlugin({
+			filename: "maps/[file].map",
+			ignoreList: /[\\/]node_modules[\\/]/,
+			debugIds: true
+		})
+	]
+};
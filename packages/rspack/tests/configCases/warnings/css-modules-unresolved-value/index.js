@@ -0,0 +1,4 @@
+it("should warn about the @value not exported by the imported module", () => {
+	const style = require("./index.css");
+	expect(style.missing).toBeUndefined();
+});
@@ -0,0 +1,43 @@
+const fs = require("fs");
+const path = require("path");
+
+export function registerServiceWorker() {
+	return navigator.serviceWorker.register(
+		/* webpackChunkName: "service-worker" */ new URL("./sw.js", import.meta.url)
+	);
+}
+
+export function addAudioWorklet(context) {
+	return context.audioWorklet.addModule(
+		/* webpackChunkName: "audio-processor" */ new URL(
+			"./processor.js",
+			import.meta.url
+		)
+	);
+}
+
+export function addPaintWorklet() {
+	return CSS.paintWorklet.addModule(new URL("./painter.js", import.meta.url));
+}
+
+export function createSharedWorker() {
+	return new SharedWorker(new URL("./shared.js", import.meta.url), {
+		name: "shared-worker"
+	});
+}
+
+it("should create entry chunks for service workers, worklets and shared workers", () => {
+	expect(fs.existsSync(path.resolve(__dirname, "service-worker.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "audio-processor.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "painter_js.js"))).toBe(true);
+	expect(fs.existsSync(path.resolve(__dirname, "shared-worker.js"))).toBe(true);
+});
+
+it("should not load chunks with importScripts in worklets", () => {
+	const processor = fs.readFileSync(
+		path.resolve(__dirname, "audio-processor.js"),
+		"utf-8"
+	);
+	expect(processor).not.toContain("importScripts");
+	expect(processor).toContain("registerProcessor");
+});
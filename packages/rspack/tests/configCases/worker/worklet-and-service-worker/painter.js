@@ -0,0 +1,6 @@
+registerPaint(
+	"painter",
+	class {
+		paint() {}
+	}
+);
@@ -0,0 +1,7 @@
+class Processor extends AudioWorkletProcessor {
+	process() {
+		return true;
+	}
+}
+
+registerProcessor("processor", Processor);
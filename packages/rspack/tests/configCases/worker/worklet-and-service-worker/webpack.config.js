@@ -0,0 +1,4 @@
+/**@type {import('@rspack/cli').Configuration}*/
+module.exports = {
+	context: __dirname
+};
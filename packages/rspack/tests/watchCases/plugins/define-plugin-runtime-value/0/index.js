@@ -0,0 +1,10 @@
+import step from "./step";
+import constant from "./constant";
+import changing from "./changing-file";
+
+it("should evaluate the runtime values again in every compilation", function () {
+	expect(changing).toBe(WATCH_STEP);
+	// `step.js` itself is never changed, it's rebuilt for the changed `STEP`
+	expect(step).toBe(WATCH_STEP);
+	expect(constant).toBe("constant");
+});
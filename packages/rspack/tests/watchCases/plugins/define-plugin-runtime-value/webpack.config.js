@@ -0,0 +1,14 @@
+const { DefinePlugin } = require("@rspack/core");
+const currentWatchStep = require("../../../helpers/currentWatchStep");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new DefinePlugin({
+			STEP: DefinePlugin.runtimeValue(() =>
+				JSON.stringify(currentWatchStep.step)
+			),
+			CONSTANT: JSON.stringify("constant")
+		})
+	]
+};